@@ -34,6 +34,9 @@ pub use rgba::*;
 mod hex_color_runtime;
 pub use hex_color_runtime::*;
 
+mod tween;
+pub use tween::*;
+
 // ----------------------------------------------------------------------------
 // Color conversion:
 
@@ -0,0 +1,87 @@
+use emath::easing::Curve;
+
+use crate::{Color32, Rgba};
+
+/// Interpolate from `a` to `b`, with `easing` applied to `t` first.
+///
+/// `t` is clamped to `[0, 1]` before easing, and `t=0` and `t=1` return `a` and `b` exactly.
+///
+/// If `in_gamma` is `false` the interpolation is done in linear space (via [`Rgba`]),
+/// which is what you want for a perceptually even fade.
+/// If `in_gamma` is `true` the sRGB values are interpolated directly,
+/// like [`Color32::lerp_to_gamma`].
+///
+/// All channels, including alpha, use the same eased `t`.
+/// Colors are premultiplied, so a fade from [`Color32::TRANSPARENT`] never darkens the target color.
+///
+/// ```
+/// # use ecolor::{tween, Color32};
+/// let mid = tween(Color32::BLACK, Color32::WHITE, 0.5, emath::easing::linear, false);
+/// assert_eq!(mid, Color32::from_gray(188));
+/// ```
+#[allow(clippy::needless_pass_by_value)]
+pub fn tween(a: Color32, b: Color32, t: f32, easing: impl Curve, in_gamma: bool) -> Color32 {
+    if t <= 0.0 {
+        a
+    } else if 1.0 <= t {
+        b
+    } else if in_gamma {
+        a.lerp_to_gamma(b, easing.apply(t))
+    } else {
+        tween_rgba(Rgba::from(a), Rgba::from(b), t, easing).into()
+    }
+}
+
+/// Interpolate from `a` to `b` in linear space, with `easing` applied to `t` first.
+///
+/// Unlike [`tween`], `t` is not clamped, so overshooting easings may produce out-of-range values.
+#[allow(clippy::needless_pass_by_value)]
+pub fn tween_rgba(a: Rgba, b: Rgba, t: f32, easing: impl Curve) -> Rgba {
+    emath::lerp(a..=b, easing.apply(t))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use emath::easing;
+
+    #[test]
+    fn test_tween_endpoints() {
+        let a = Color32::from_rgba_unmultiplied(10, 200, 30, 40);
+        let b = Color32::from_rgb(250, 5, 128);
+        for in_gamma in [false, true] {
+            for easing in [easing::linear, easing::back_out, easing::bounce_in_out] {
+                assert_eq!(tween(a, b, 0.0, easing, in_gamma), a);
+                assert_eq!(tween(a, b, 1.0, easing, in_gamma), b);
+            }
+        }
+
+        let (a, b) = (Rgba::from(a), Rgba::from(b));
+        assert_eq!(tween_rgba(a, b, 0.0, easing::cubic_in), a);
+        assert_eq!(tween_rgba(a, b, 1.0, easing::cubic_in), b);
+    }
+
+    #[test]
+    fn test_tween_midpoint() {
+        let (black, white) = (Color32::BLACK, Color32::WHITE);
+        assert_eq!(
+            tween(black, white, 0.5, easing::linear, false),
+            Color32::from_gray(188)
+        );
+        assert_eq!(
+            tween(black, white, 0.5, easing::linear, true),
+            Color32::from_gray(128)
+        );
+    }
+
+    #[test]
+    fn test_tween_alpha() {
+        // Alpha only depends on the alpha of the endpoints, not on their color:
+        for target in [Color32::RED, Color32::WHITE, Color32::BLACK] {
+            for in_gamma in [false, true] {
+                let mid = tween(Color32::TRANSPARENT, target, 0.5, easing::linear, in_gamma);
+                assert_eq!(mid.a(), 128, "{target:?} {in_gamma}");
+            }
+        }
+    }
+}
@@ -1,12 +1,20 @@
+use std::collections::VecDeque;
+
 use crate::{
-    emath::{remap_clamp, NumExt as _},
-    Id, IdMap, InputState,
+    emath::{
+        easing::{BoxedCurve, Curve},
+        remap_clamp_with_easing, NumExt as _,
+    },
+    Id, IdMap, InputState, ViewportId, ViewportIdMap,
 };
 
 #[derive(Clone, Default)]
 pub(crate) struct AnimationManager {
     bools: IdMap<BoolAnim>,
     values: IdMap<ValueAnim>,
+
+    /// Only used with [`crate::Options::smooth_animation_clock`].
+    clocks: ViewportIdMap<AnimationClock>,
 }
 
 #[derive(Clone, Debug)]
@@ -23,9 +31,49 @@ struct ValueAnim {
 
     /// when did `value` last toggle?
     toggle_time: f64,
+
+    /// The easing used since the last toggle.
+    easing: BoxedCurve,
+}
+
+/// The time used to advance animations.
+#[derive(Clone, Copy, Debug)]
+struct AnimationTime {
+    time: f64,
+
+    /// Used to cap how much an animation may advance in one frame.
+    stable_dt: f32,
+
+    /// Used to extrapolate to when the frame will be shown.
+    predicted_dt: f32,
 }
 
 impl AnimationManager {
+    /// Call once per frame and viewport, after the input has been updated.
+    pub fn begin_frame(&mut self, viewport_id: ViewportId, input: &InputState, smooth_clock: bool) {
+        if smooth_clock {
+            self.clocks.entry(viewport_id).or_default().step(input.time);
+        } else {
+            self.clocks.remove(&viewport_id);
+        }
+    }
+
+    fn time(&self, input: &InputState) -> AnimationTime {
+        if let Some(clock) = self.clocks.get(&input.raw.viewport_id) {
+            AnimationTime {
+                time: clock.time,
+                stable_dt: clock.dt,
+                predicted_dt: clock.dt,
+            }
+        } else {
+            AnimationTime {
+                time: input.time,
+                stable_dt: input.stable_dt,
+                predicted_dt: input.predicted_dt,
+            }
+        }
+    }
+
     /// See [`crate::Context::animate_bool`] for documentation
     pub fn animate_bool(
         &mut self,
@@ -34,6 +82,7 @@ impl AnimationManager {
         id: Id,
         value: bool,
     ) -> f32 {
+        let now = self.time(input);
         let (start, end) = if value { (0.0, 1.0) } else { (1.0, 0.0) };
         match self.bools.get_mut(&id) {
             None => {
@@ -41,7 +90,7 @@ impl AnimationManager {
                     id,
                     BoolAnim {
                         last_value: end,
-                        last_tick: input.time - input.stable_dt as f64,
+                        last_tick: now.time - now.stable_dt as f64,
                     },
                 );
                 end
@@ -51,8 +100,8 @@ impl AnimationManager {
                     last_value,
                     last_tick,
                 } = anim;
-                let current_time = input.time;
-                let elapsed = ((current_time - *last_tick) as f32).at_most(input.stable_dt);
+                let current_time = now.time;
+                let elapsed = ((current_time - *last_tick) as f32).at_most(now.stable_dt);
                 let new_value = *last_value + (end - start) * elapsed / animation_time;
                 *last_value = if new_value.is_finite() {
                     new_value.clamp(0.0, 1.0)
@@ -65,13 +114,27 @@ impl AnimationManager {
         }
     }
 
+    /// Jump the bool animation `id` to `value`, e.g. to replay it from the start.
+    pub fn reset_bool(&mut self, input: &InputState, id: Id, value: f32) {
+        let now = self.time(input);
+        self.bools.insert(
+            id,
+            BoolAnim {
+                last_value: value,
+                last_tick: now.time - now.stable_dt as f64,
+            },
+        );
+    }
+
     pub fn animate_value(
         &mut self,
         input: &InputState,
         animation_time: f32,
         id: Id,
         value: f32,
+        easing: impl Curve + Send + Sync + 'static,
     ) -> f32 {
+        let now = self.time(input);
         match self.values.get_mut(&id) {
             None => {
                 self.values.insert(
@@ -80,24 +143,27 @@ impl AnimationManager {
                         from_value: value,
                         to_value: value,
                         toggle_time: -f64::INFINITY, // long time ago
+                        easing: BoxedCurve::new(easing),
                     },
                 );
                 value
             }
             Some(anim) => {
-                let time_since_toggle = (input.time - anim.toggle_time) as f32;
+                let time_since_toggle = (now.time - anim.toggle_time) as f32;
                 // On the frame we toggle we don't want to return the old value,
                 // so we extrapolate forwards:
-                let time_since_toggle = time_since_toggle + input.predicted_dt;
-                let current_value = remap_clamp(
+                let time_since_toggle = time_since_toggle + now.predicted_dt;
+                let current_value = remap_clamp_with_easing(
                     time_since_toggle,
                     0.0..=animation_time,
                     anim.from_value..=anim.to_value,
+                    |t| anim.easing.apply(t),
                 );
                 if anim.to_value != value {
                     anim.from_value = current_value; //start new animation from current position of playing animation
                     anim.to_value = value;
-                    anim.toggle_time = input.time;
+                    anim.toggle_time = now.time;
+                    anim.easing = BoxedCurve::new(easing);
                 }
                 if animation_time == 0.0 {
                     anim.from_value = value;
@@ -108,3 +174,97 @@ impl AnimationManager {
         }
     }
 }
+
+// ----------------------------------------------------------------------------
+
+/// A clock that advances evenly, even when frame times are uneven.
+///
+/// Each step advances by the real frame time, clamped to within [`Self::MAX_DEVIATION`]
+/// of the average of recent frame times.
+/// If this drifts more than [`Self::MAX_DIVERGENCE`] from the real time, it snaps back.
+#[derive(Clone, Debug, Default)]
+struct AnimationClock {
+    /// `None` before the first step.
+    last_wall_time: Option<f64>,
+    recent_dts: VecDeque<f32>,
+
+    time: f64,
+    dt: f32,
+}
+
+impl AnimationClock {
+    const NUM_RECENT_DTS: usize = 8;
+
+    /// Longer frames (e.g. after being idle) count as this long when averaging.
+    const MAX_DT: f32 = 0.1;
+
+    const MAX_DEVIATION: f32 = 0.3;
+
+    /// In seconds.
+    const MAX_DIVERGENCE: f64 = 0.1;
+
+    fn step(&mut self, wall_time: f64) {
+        let Some(last_wall_time) = self.last_wall_time.replace(wall_time) else {
+            self.time = wall_time;
+            self.dt = 1.0 / 60.0;
+            return;
+        };
+
+        let wall_dt = (wall_time - last_wall_time) as f32;
+        if self.recent_dts.len() == Self::NUM_RECENT_DTS {
+            self.recent_dts.pop_front();
+        }
+        self.recent_dts.push_back(wall_dt.clamp(0.0, Self::MAX_DT));
+
+        // The average (rather than the median) keeps up with alternating frame times:
+        let average = self.recent_dts.iter().sum::<f32>() / self.recent_dts.len() as f32;
+        self.dt = wall_dt.clamp(
+            (1.0 - Self::MAX_DEVIATION) * average,
+            (1.0 + Self::MAX_DEVIATION) * average,
+        );
+        self.time += self.dt as f64;
+
+        if Self::MAX_DIVERGENCE < (self.time - wall_time).abs() {
+            self.time = wall_time;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn variance(values: &[f32]) -> f32 {
+        let mean = values.iter().sum::<f32>() / values.len() as f32;
+        values.iter().map(|v| (v - mean).powi(2)).sum::<f32>() / values.len() as f32
+    }
+
+    #[test]
+    fn test_animation_clock_smooths_uneven_frames() {
+        let mut clock = AnimationClock::default();
+        let mut wall_time = 0.0;
+        let mut wall_dts = vec![];
+        let mut smooth_dts = vec![];
+        for i in 0..200 {
+            let wall_dt = if i % 2 == 0 { 0.008 } else { 0.025 };
+            wall_time += wall_dt;
+            let last_time = clock.time;
+            clock.step(wall_time);
+            if 20 < i {
+                wall_dts.push(wall_dt as f32);
+                smooth_dts.push((clock.time - last_time) as f32);
+            }
+            assert!((clock.time - wall_time).abs() <= AnimationClock::MAX_DIVERGENCE);
+        }
+        assert!(variance(&smooth_dts) < 0.5 * variance(&wall_dts));
+    }
+
+    #[test]
+    fn test_animation_clock_resyncs() {
+        let mut clock = AnimationClock::default();
+        clock.step(0.0);
+        clock.step(0.016);
+        clock.step(5.0); // after being idle
+        assert_eq!(clock.time, 5.0);
+    }
+}
@@ -190,6 +190,10 @@ impl Area {
         self.movable && self.enabled
     }
 
+    pub fn is_interactable(&self) -> bool {
+        self.interactable
+    }
+
     /// If false, clicks goes straight through to what is behind us.
     ///
     /// Can be used for semi-invisible areas that the user should be able to click through.
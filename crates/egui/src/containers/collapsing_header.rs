@@ -1,6 +1,7 @@
 use std::hash::Hash;
 
 use crate::*;
+use emath::easing::Curve as _;
 use epaint::Shape;
 
 #[derive(Clone, Copy, Debug)]
@@ -22,13 +23,19 @@ pub(crate) struct InnerState {
 pub struct CollapsingState {
     id: Id,
     state: InnerState,
+
+    /// Not persisted.
+    open_animation: Option<WindowAnimation>,
 }
 
 impl CollapsingState {
     pub fn load(ctx: &Context, id: Id) -> Option<Self> {
         ctx.data_mut(|d| {
-            d.get_persisted::<InnerState>(id)
-                .map(|state| Self { id, state })
+            d.get_persisted::<InnerState>(id).map(|state| Self {
+                id,
+                state,
+                open_animation: None,
+            })
         })
     }
 
@@ -51,9 +58,20 @@ impl CollapsingState {
                 open: default_open,
                 open_height: None,
             },
+            open_animation: None,
         })
     }
 
+    /// Animate the body as it opens and closes, instead of only growing it.
+    ///
+    /// The body ignores input until it is fully open.
+    /// If [`Style::reduce_motion`] is set, the body only fades.
+    #[inline]
+    pub fn open_animation(mut self, open_animation: WindowAnimation) -> Self {
+        self.open_animation = Some(open_animation);
+        self
+    }
+
     pub fn is_open(&self) -> bool {
         self.state.open
     }
@@ -71,8 +89,12 @@ impl CollapsingState {
     pub fn openness(&self, ctx: &Context) -> f32 {
         if ctx.memory(|mem| mem.everything_is_visible()) {
             1.0
+        } else if let Some(animation) = &self.open_animation {
+            let duration = animation.duration_or_default(ctx);
+            let t = ctx.animate_bool_with_time(self.id, self.state.open, duration);
+            animation.easing.apply(t.clamp(0.0, 1.0))
         } else {
-            ctx.animate_bool_responsive(self.id, self.state.open)
+            ctx.animate_bool_with_style_easing(self.id, self.state.open)
         }
     }
 
@@ -210,9 +232,30 @@ impl CollapsingState {
                 clip_rect.max.y = clip_rect.max.y.min(child_ui.max_rect().top() + max_height);
                 child_ui.set_clip_rect(clip_rect);
 
+                let open_animation = self
+                    .open_animation
+                    .clone()
+                    .map(|animation| animation.for_style(child_ui.style()));
+                if open_animation.is_some() {
+                    child_ui.multiply_opacity(openness.clamp(0.0, 1.0));
+                    child_ui.ignore_interaction();
+                }
+
+                let layer_id = child_ui.layer_id();
+                let first_shape = child_ui
+                    .ctx()
+                    .graphics_mut(|g| g.entry(layer_id).next_idx());
                 let ret = add_body(child_ui);
 
                 let mut min_rect = child_ui.min_rect();
+                if let Some(animation) = open_animation {
+                    let transform = animation.transform(min_rect, openness);
+                    child_ui.ctx().graphics_mut(|g| {
+                        let paint_list = g.entry(layer_id);
+                        let end = paint_list.next_idx();
+                        paint_list.transform_range(first_shape, end, transform);
+                    });
+                }
                 self.state.open_height = Some(min_rect.height());
                 self.store(child_ui.ctx()); // remember the height
 
@@ -375,6 +418,7 @@ pub struct CollapsingHeader {
     selected: bool,
     show_background: bool,
     icon: Option<IconPainter>,
+    open_animation: Option<WindowAnimation>,
 }
 
 impl CollapsingHeader {
@@ -397,6 +441,7 @@ impl CollapsingHeader {
             selected: false,
             show_background: false,
             icon: None,
+            open_animation: None,
         }
     }
 
@@ -472,6 +517,25 @@ impl CollapsingHeader {
         self.icon = Some(Box::new(icon_fn));
         self
     }
+
+    /// Animate the body as it opens and closes, e.g. by sliding it in.
+    ///
+    /// By default the body only grows and shrinks.
+    /// See [`CollapsingState::open_animation`].
+    ///
+    /// ```
+    /// # egui::__run_test_ui(|ui| {
+    /// use egui::{vec2, WindowAnimation};
+    /// egui::CollapsingHeader::new("Details")
+    ///     .open_animation(WindowAnimation::slide(vec2(-32.0, 0.0)))
+    ///     .show(ui, |ui| { ui.label("Hi!"); });
+    /// # });
+    /// ```
+    #[inline]
+    pub fn open_animation(mut self, open_animation: WindowAnimation) -> Self {
+        self.open_animation = Some(open_animation);
+        self
+    }
 }
 
 struct Prepared {
@@ -496,6 +560,7 @@ impl CollapsingHeader {
             selectable,
             selected,
             show_background,
+            open_animation,
         } = self;
 
         // TODO(emilk): horizontal layout, with icon and text as labels. Insert background behind using Frame.
@@ -530,6 +595,7 @@ impl CollapsingHeader {
         );
 
         let mut state = CollapsingState::load_with_default_open(ui.ctx(), id, default_open);
+        state.open_animation = open_animation;
         if let Some(open) = open {
             if open != state.is_open() {
                 state.toggle(ui);
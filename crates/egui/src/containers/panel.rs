@@ -18,7 +18,7 @@
 use crate::*;
 
 fn animate_expansion(ctx: &Context, id: Id, is_expanded: bool) -> f32 {
-    ctx.animate_bool_responsive(id, is_expanded)
+    ctx.animate_bool_with_style_easing(id, is_expanded)
 }
 
 /// State regarding panels.
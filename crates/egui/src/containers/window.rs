@@ -3,6 +3,10 @@
 use std::sync::Arc;
 
 use crate::collapsing_header::CollapsingState;
+use crate::emath::{
+    easing::{BoxedCurve, Curve},
+    TSTransform,
+};
 use crate::*;
 use epaint::*;
 
@@ -41,6 +45,7 @@ pub struct Window<'open> {
     default_open: bool,
     with_title_bar: bool,
     fade_out: bool,
+    open_animation: Option<WindowAnimation>,
 }
 
 impl<'open> Window<'open> {
@@ -64,6 +69,7 @@ impl<'open> Window<'open> {
             default_open: true,
             with_title_bar: true,
             fade_out: true,
+            open_animation: None,
         }
     }
 
@@ -137,6 +143,30 @@ impl<'open> Window<'open> {
         self
     }
 
+    /// Animate the window as it opens and closes, e.g. sliding in from the side.
+    ///
+    /// This replaces the default fade in and fade out
+    /// (closing only animates if you use [`Self::open`] to close the window).
+    /// The window does not respond to input until it is fully open.
+    ///
+    /// If [`Style::reduce_motion`] is set, the window only fades.
+    ///
+    /// ```
+    /// # egui::__run_test_ctx(|ctx| {
+    /// use egui::{vec2, WindowAnimation};
+    /// egui::Window::new("Settings")
+    ///     .open_animation(WindowAnimation::slide(vec2(64.0, 0.0)).duration(0.25))
+    ///     .show(ctx, |ui| {
+    ///         ui.label("Hello World!");
+    ///     });
+    /// # });
+    /// ```
+    #[inline]
+    pub fn open_animation(mut self, open_animation: WindowAnimation) -> Self {
+        self.open_animation = Some(open_animation);
+        self
+    }
+
     /// Usage: `Window::new(…).mutate(|w| w.resize = w.resize.auto_expand_width(true))`
     // TODO(emilk): I'm not sure this is a good interface for this.
     #[inline]
@@ -429,6 +459,7 @@ impl<'open> Window<'open> {
             default_open,
             with_title_bar,
             fade_out,
+            open_animation,
         } = self;
 
         let header_color =
@@ -442,15 +473,48 @@ impl<'open> Window<'open> {
 
         let is_explicitly_closed = matches!(open, Some(false));
         let is_open = !is_explicitly_closed || ctx.memory(|mem| mem.everything_is_visible());
-        let opacity = ctx.animate_bool_with_easing(
-            area.id.with("fade-out"),
-            is_open,
-            emath::easing::cubic_out,
-        );
+
+        let open_animation = open_animation.map(|animation| animation.for_style(&ctx.style()));
+
+        let opacity = if let Some(animation) = &open_animation {
+            // Linear here; the easing is applied below.
+            // This is also the progress of the open and close animations,
+            // so reopening a window while it closes continues from where it is.
+            let id = area.id.with("fade-out");
+            let duration = animation.duration_or_default(ctx);
+            let visible_last_frame =
+                ctx.memory(|mem| mem.areas().visible_last_frame(&area.layer()));
+            if is_open && !visible_last_frame && !ctx.memory(|mem| mem.everything_is_visible()) {
+                // Play the open animation from the start, also the first time the window is shown:
+                ctx.reset_animate_bool(id, 0.0);
+            }
+            ctx.animate_bool_with_time(id, is_open, duration)
+        } else {
+            ctx.animate_bool_with_easing(
+                area.id.with("fade-out"),
+                is_open,
+                emath::easing::cubic_out,
+            )
+        };
         if opacity <= 0.0 {
+            if open_animation.is_some() {
+                set_animation_transform(ctx, area.id, area.layer(), None);
+            }
             return None;
         }
 
+        // How far along the open (or close) animation we are, from 0 to 1:
+        let animation_t = open_animation.map(|animation| (animation, opacity));
+
+        let area = if let Some((_, t)) = &animation_t {
+            // The open animation replaces the default fade-in,
+            // and we ignore input until the window is fully open.
+            area.fade_in(false)
+                .interactable(area.is_interactable() && 1.0 <= *t)
+        } else {
+            area
+        };
+
         let area_id = area.id;
         let area_layer_id = area.layer();
         let resize_id = area_id.with("resize");
@@ -506,7 +570,12 @@ impl<'open> Window<'open> {
         );
 
         let mut area_content_ui = area.content_ui(ctx);
-        if is_open {
+        if let Some((animation, t)) = animation_t {
+            let eased = animation.easing.apply(t.clamp(0.0, 1.0));
+            area_content_ui.multiply_opacity(eased);
+            let transform = (t < 1.0).then(|| animation.transform(area.state().rect(), eased));
+            set_animation_transform(ctx, area_id, area_layer_id, transform);
+        } else if is_open {
             // `Area` already takes care of fade-in animations,
             // so we only need to handle fade-out animations here.
         } else if fade_out {
@@ -630,6 +699,144 @@ impl<'open> Window<'open> {
     }
 }
 
+/// How a [`Window`] animates as it opens and closes, see [`Window::open_animation`].
+///
+/// Also used for the body of a [`CollapsingHeader`], see [`CollapsingHeader::open_animation`].
+///
+/// The contents always fade in and out; [`Self::kind`] decides what else happens.
+#[derive(Clone, Debug)]
+pub struct WindowAnimation {
+    /// What the animation looks like.
+    pub kind: WindowAnimationKind,
+
+    /// Duration in seconds.
+    ///
+    /// Default: [`Style::animation_time`].
+    pub duration: Option<f32>,
+
+    /// One of the functions in [`emath::easing`], or any other [`emath::easing::Curve`].
+    ///
+    /// Closing plays the animation in reverse.
+    ///
+    /// Default: [`emath::easing::cubic_out`].
+    pub easing: BoxedCurve,
+}
+
+impl Default for WindowAnimation {
+    fn default() -> Self {
+        Self::fade()
+    }
+}
+
+impl WindowAnimation {
+    /// Only fade in and out.
+    pub fn fade() -> Self {
+        Self {
+            kind: WindowAnimationKind::Fade,
+            duration: None,
+            easing: BoxedCurve::new(emath::easing::cubic_out),
+        }
+    }
+
+    /// Slide in from `offset` points away from the final position.
+    ///
+    /// For instance, `vec2(64.0, 0.0)` slides in from the right.
+    pub fn slide(offset: Vec2) -> Self {
+        Self {
+            kind: WindowAnimationKind::Slide(offset),
+            ..Self::fade()
+        }
+    }
+
+    /// Grow from `scale` (e.g. `0.8`) to full size, around the center of the window.
+    pub fn scale(scale: f32) -> Self {
+        Self {
+            kind: WindowAnimationKind::Scale(scale),
+            ..Self::fade()
+        }
+    }
+
+    /// Duration in seconds.
+    #[inline]
+    pub fn duration(mut self, duration: f32) -> Self {
+        self.duration = Some(duration);
+        self
+    }
+
+    /// One of the functions in [`emath::easing`], or any other [`emath::easing::Curve`].
+    #[inline]
+    pub fn easing(mut self, easing: impl Curve + Send + Sync + 'static) -> Self {
+        self.easing = BoxedCurve::new(easing);
+        self
+    }
+
+    /// Only fade if [`Style::reduce_motion`] is set.
+    pub(crate) fn for_style(self, style: &Style) -> Self {
+        if style.reduce_motion {
+            Self {
+                kind: WindowAnimationKind::Fade,
+                ..self
+            }
+        } else {
+            self
+        }
+    }
+
+    pub(crate) fn duration_or_default(&self, ctx: &Context) -> f32 {
+        self.duration.unwrap_or_else(|| ctx.style().animation_time)
+    }
+
+    /// The layer transform at the eased progress `t`, for contents covering `rect`.
+    pub(crate) fn transform(&self, rect: Rect, t: f32) -> TSTransform {
+        match self.kind {
+            WindowAnimationKind::Fade => TSTransform::IDENTITY,
+            WindowAnimationKind::Slide(offset) => TSTransform::from_translation(offset * (1.0 - t)),
+            WindowAnimationKind::Scale(scale) => {
+                let scale = lerp(scale..=1.0, t);
+                let center = rect.center().to_vec2();
+                TSTransform::from_translation(center)
+                    * TSTransform::from_scaling(scale)
+                    * TSTransform::from_translation(-center)
+            }
+        }
+    }
+}
+
+/// Set the layer transform of a window while its [`WindowAnimation`] runs,
+/// and reset it once when the animation is over.
+///
+/// Otherwise the layer transform is left alone, so we don't overwrite one set by the app.
+fn set_animation_transform(
+    ctx: &Context,
+    area_id: Id,
+    layer_id: LayerId,
+    transform: Option<TSTransform>,
+) {
+    let is_set_id = area_id.with("animation-transform");
+    if let Some(transform) = transform {
+        ctx.set_transform_layer(layer_id, transform);
+        ctx.data_mut(|data| data.insert_temp(is_set_id, true));
+    } else if ctx
+        .data_mut(|data| data.remove_temp::<bool>(is_set_id))
+        .is_some()
+    {
+        ctx.set_transform_layer(layer_id, TSTransform::IDENTITY);
+    }
+}
+
+/// See [`WindowAnimation`].
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum WindowAnimationKind {
+    /// Only fade in and out.
+    Fade,
+
+    /// Slide in from this offset (in points), and back out to it when closing.
+    Slide(Vec2),
+
+    /// Grow from this scale to full size, and shrink back to it when closing.
+    Scale(f32),
+}
+
 fn paint_resize_corner(
     ui: &Ui,
     possible: &PossibleInteractions,
@@ -1207,3 +1414,62 @@ fn close_button(ui: &mut Ui, rect: Rect) -> Response {
         .line_segment([rect.right_top(), rect.left_bottom()], stroke);
     response
 }
+
+#[test]
+fn test_window_animation_reopen() {
+    let ctx = Context::default();
+    let layer_id = LayerId::new(Order::Middle, Id::new("animated"));
+    let dt = 0.1;
+    let mut frame = 0;
+    let mut run = |open: bool| {
+        let input = RawInput {
+            time: Some(frame as f64 * dt as f64),
+            predicted_dt: dt,
+            ..Default::default()
+        };
+        frame += 1;
+        let _ = ctx.run(input, |ctx| {
+            let mut open = open;
+            Window::new("animated")
+                .open(&mut open)
+                .open_animation(
+                    WindowAnimation::slide(vec2(100.0, 0.0))
+                        .duration(1.0)
+                        .easing(emath::easing::linear),
+                )
+                .show(ctx, |ui| ui.label("Hello"));
+        });
+        ctx.memory(|mem| mem.layer_transforms.get(&layer_id).copied())
+    };
+    let offset = |transform: Option<TSTransform>| transform.map_or(0.0, |t| t.translation.x);
+
+    // Opening slides in from the right:
+    let first = offset(run(true));
+    assert!(80.0 < first && first <= 100.0, "{first}");
+    for _ in 0..12 {
+        run(true);
+    }
+    assert_eq!(
+        run(true),
+        None,
+        "The transform is reset once the window is open"
+    );
+
+    // A transform set by the app is left alone once the animation is over:
+    let zoom = TSTransform::from_scaling(2.0);
+    ctx.set_transform_layer(layer_id, zoom);
+    assert_eq!(run(true), Some(zoom));
+    ctx.set_transform_layer(layer_id, TSTransform::IDENTITY);
+
+    // Reopening while closing continues from where the window was, without jumps:
+    let mut previous = 0.0;
+    for open in [false; 4].into_iter().chain([true; 12]) {
+        let current = offset(run(open));
+        assert!(
+            (current - previous).abs() <= 100.0 * dt + 1e-3,
+            "{previous} -> {current}"
+        );
+        previous = current;
+    }
+    assert_eq!(previous, 0.0);
+}
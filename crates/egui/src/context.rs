@@ -451,6 +451,12 @@ impl ContextImpl {
 
         let screen_rect = viewport.input.screen_rect;
 
+        self.animation_manager.begin_frame(
+            viewport_id,
+            &viewport.input,
+            self.memory.options.smooth_animation_clock,
+        );
+
         viewport.frame_state.begin_frame(screen_rect);
 
         {
@@ -2469,11 +2475,31 @@ impl Context {
 
     /// Like [`Self::animate_bool`] but allows you to control the easing function.
     #[track_caller] // To track repaint cause
-    pub fn animate_bool_with_easing(&self, id: Id, value: bool, easing: fn(f32) -> f32) -> f32 {
+    pub fn animate_bool_with_easing(
+        &self,
+        id: Id,
+        value: bool,
+        easing: impl emath::easing::Curve,
+    ) -> f32 {
         let animation_time = self.style().animation_time;
         self.animate_bool_with_time_and_easing(id, value, animation_time, easing)
     }
 
+    /// Like [`Self::animate_bool`], but uses [`crate::Style::animation_easing_open`]
+    /// when `value` is `true` and [`crate::Style::animation_easing_close`] when it is `false`.
+    ///
+    /// Used for e.g. [`crate::CollapsingHeader`].
+    #[track_caller] // To track repaint cause
+    pub fn animate_bool_with_style_easing(&self, id: Id, value: bool) -> f32 {
+        let style = self.style();
+        let easing = if value {
+            style.animation_easing_open
+        } else {
+            style.animation_easing_close
+        };
+        self.animate_bool_with_easing(id, value, easing)
+    }
+
     /// Like [`Self::animate_bool`] but allows you to control the animation time.
     #[track_caller] // To track repaint cause
     pub fn animate_bool_with_time(&self, id: Id, target_value: bool, animation_time: f32) -> f32 {
@@ -2498,7 +2524,7 @@ impl Context {
         id: Id,
         target_value: bool,
         animation_time: f32,
-        easing: fn(f32) -> f32,
+        easing: impl emath::easing::Curve,
     ) -> f32 {
         let animated_value = self.write(|ctx| {
             ctx.animation_manager.animate_bool(
@@ -2515,24 +2541,59 @@ impl Context {
         }
 
         if target_value {
-            easing(animated_value)
+            emath::easing::apply_clamped(easing, animated_value)
         } else {
-            1.0 - easing(1.0 - animated_value)
+            1.0 - emath::easing::apply_clamped(easing, 1.0 - animated_value)
         }
     }
 
+    /// Jump the animation of [`Self::animate_bool_with_time`] for `id` to `value`.
+    pub(crate) fn reset_animate_bool(&self, id: Id, value: f32) {
+        self.write(|ctx| {
+            ctx.animation_manager.reset_bool(
+                &ctx.viewports.entry(ctx.viewport_id()).or_default().input,
+                id,
+                value,
+            );
+        });
+    }
+
     /// Smoothly animate an `f32` value.
     ///
     /// At the first call the value is written to memory.
     /// When it is called with a new value, it linearly interpolates to it in the given time.
     #[track_caller] // To track repaint cause
     pub fn animate_value_with_time(&self, id: Id, target_value: f32, animation_time: f32) -> f32 {
+        self.animate_value_with_time_and_easing(
+            id,
+            target_value,
+            animation_time,
+            emath::easing::linear,
+        )
+    }
+
+    /// Like [`Self::animate_value_with_time`] but allows you to control the easing,
+    /// e.g. [`emath::easing::cubic_out`] or any other [`emath::easing::Curve`].
+    ///
+    /// The easing is remembered when the target value changes, and used until it changes again.
+    /// If the target changes mid-animation, the new animation starts from the current value,
+    /// so there is no jump. Passing a different easing without changing the target has no effect
+    /// on the animation in progress.
+    #[track_caller] // To track repaint cause
+    pub fn animate_value_with_time_and_easing(
+        &self,
+        id: Id,
+        target_value: f32,
+        animation_time: f32,
+        easing: impl emath::easing::Curve + Send + Sync + 'static,
+    ) -> f32 {
         let animated_value = self.write(|ctx| {
             ctx.animation_manager.animate_value(
                 &ctx.viewports.entry(ctx.viewport_id()).or_default().input,
                 animation_time,
                 id,
                 target_value,
+                easing,
             )
         });
         let animation_in_progress = animated_value != target_value;
@@ -3445,3 +3506,104 @@ fn context_impl_send_sync() {
     fn assert_send_sync<T: Send + Sync>() {}
     assert_send_sync::<Context>();
 }
+
+#[test]
+fn test_animate_value_with_easing() {
+    use emath::easing::{cubic_in, cubic_out};
+
+    let ctx = Context::default();
+    let id = Id::new("value");
+    let dt = 0.1;
+    let animation_time = 1.0;
+    let mut frame = 0;
+    let mut run = |target: f32, easing: fn(f32) -> f32| {
+        let input = RawInput {
+            time: Some(frame as f64 * dt as f64),
+            predicted_dt: dt,
+            ..Default::default()
+        };
+        frame += 1;
+        let mut value = 0.0;
+        let _ = ctx.run(input, |ctx| {
+            value = ctx.animate_value_with_time_and_easing(id, target, animation_time, easing);
+        });
+        value
+    };
+
+    assert_eq!(run(0.0, cubic_out), 0.0);
+
+    // The frame the target changes still returns the old value:
+    assert_eq!(run(10.0, cubic_out), 0.0);
+
+    let mut values = vec![];
+    for _ in 0..4 {
+        // Changing the easing mid-flight does not affect the running animation:
+        values.push(run(10.0, cubic_in));
+    }
+    for (i, value) in values.iter().enumerate() {
+        // The animation extrapolates one frame ahead:
+        let t = (i + 2) as f32 * dt / animation_time;
+        let expected = 10.0 * cubic_out(t);
+        assert!(
+            (value - expected).abs() < 1e-4,
+            "frame {i}: {value} != {expected}"
+        );
+    }
+
+    // Retargeting starts from where we are, without a jump:
+    let before = *values.last().unwrap();
+    let after = run(0.0, cubic_out);
+    assert!((after - before).abs() < 2.0, "{before} -> {after}");
+    assert!(run(0.0, cubic_out) < after);
+
+    for _ in 0..20 {
+        run(0.0, cubic_out);
+    }
+    assert_eq!(run(0.0, cubic_out), 0.0);
+}
+
+#[test]
+fn test_animate_bool_with_style_easing() {
+    use emath::easing::{linear, quadratic_in, quadratic_out};
+
+    let ctx = Context::default();
+    ctx.style_mut(|style| {
+        style.animation_time = 1.0;
+        style.animation_easing_open = quadratic_in;
+        style.animation_easing_close = quadratic_out;
+    });
+
+    let dt = 0.1;
+    let mut frame = 0;
+    let mut run = |open: bool| {
+        let input = RawInput {
+            time: Some(frame as f64 * dt as f64),
+            predicted_dt: dt,
+            ..Default::default()
+        };
+        frame += 1;
+        let mut values = (0.0, 0.0);
+        let _ = ctx.run(input, |ctx| {
+            values = (
+                ctx.animate_bool_with_style_easing(Id::new("styled"), open),
+                ctx.animate_bool_with_easing(Id::new("linear"), open, linear),
+            );
+        });
+        values
+    };
+
+    assert_eq!(run(false), (0.0, 0.0));
+    for _ in 0..4 {
+        let (styled, linear) = run(true);
+        assert!(0.0 < linear && linear < 1.0);
+        assert_eq!(styled, quadratic_in(linear));
+    }
+    for _ in 0..12 {
+        run(true);
+    }
+    for _ in 0..4 {
+        let (styled, linear) = run(false);
+        assert!(0.0 < linear && linear < 1.0);
+        assert_eq!(styled, 1.0 - quadratic_out(1.0 - linear));
+    }
+}
@@ -158,6 +158,20 @@ impl PaintList {
         self.0[idx.0].shape = Shape::Noop;
     }
 
+    /// The index the next added [`Shape`] will get.
+    #[inline(always)]
+    pub fn next_idx(&self) -> ShapeIdx {
+        ShapeIdx(self.0.len())
+    }
+
+    /// Transform each [`Shape`] and clip rectangle in the range `start..end` by this much, in-place.
+    pub fn transform_range(&mut self, start: ShapeIdx, end: ShapeIdx, transform: TSTransform) {
+        for ClippedShape { clip_rect, shape } in &mut self.0[start.0..end.0] {
+            *clip_rect = transform.mul_rect(*clip_rect);
+            shape.transform(transform);
+        }
+    }
+
     /// Transform each [`Shape`] and clip rectangle by this much, in-place
     pub fn transform(&mut self, transform: TSTransform) {
         for ClippedShape { clip_rect, shape } in &mut self.0 {
@@ -248,6 +248,14 @@ pub struct Options {
     ///
     /// Default is `false`.
     pub reduce_texture_memory: bool,
+
+    /// Advance animations by a smoothed frame time, so that they move evenly even when frame times are uneven.
+    ///
+    /// The tradeoff is that animations may finish a few frames later (in wall-clock time) than they otherwise would.
+    /// The animation clock snaps back to real time if it drifts too far (e.g. after being idle).
+    ///
+    /// Default is `false`.
+    pub smooth_animation_clock: bool,
 }
 
 impl Default for Options {
@@ -274,6 +282,7 @@ impl Default for Options {
             line_scroll_speed,
             scroll_zoom_speed: 1.0 / 200.0,
             reduce_texture_memory: false,
+            smooth_animation_clock: false,
         }
     }
 }
@@ -294,6 +303,7 @@ impl Options {
             line_scroll_speed,
             scroll_zoom_speed,
             reduce_texture_memory,
+            smooth_animation_clock,
         } = self;
 
         use crate::Widget as _;
@@ -314,6 +324,9 @@ impl Options {
                 ui.checkbox(warn_on_id_clash, "Warn if two widgets have the same Id");
 
                 ui.checkbox(reduce_texture_memory, "Reduce texture memory");
+
+                ui.checkbox(smooth_animation_clock, "Smooth animation clock")
+                    .on_hover_text("Animate evenly even when frame times are uneven");
             });
 
         use crate::containers::*;
@@ -264,6 +264,21 @@ pub struct Style {
     /// How many seconds a typical animation should last.
     pub animation_time: f32,
 
+    /// Easing for things opening, e.g. [`crate::CollapsingHeader`]s, collapsing [`crate::Window`]s and animated panels.
+    ///
+    /// See [`emath::easing`].
+    #[cfg_attr(feature = "serde", serde(skip))]
+    pub animation_easing_open: fn(f32) -> f32,
+
+    /// Easing for things closing. See [`Self::animation_easing_open`].
+    #[cfg_attr(feature = "serde", serde(skip))]
+    pub animation_easing_close: fn(f32) -> f32,
+
+    /// Prefer subtle animations: e.g. windows only fade in and out, instead of sliding or scaling.
+    ///
+    /// See [`crate::WindowAnimation`].
+    pub reduce_motion: bool,
+
     /// Options to help debug why egui behaves strangely.
     ///
     /// Only available in debug builds.
@@ -1124,6 +1139,9 @@ impl Default for Style {
             interaction: Interaction::default(),
             visuals: Visuals::default(),
             animation_time: 1.0 / 12.0,
+            animation_easing_open: emath::easing::cubic_out,
+            animation_easing_close: emath::easing::cubic_out,
+            reduce_motion: false,
             #[cfg(debug_assertions)]
             debug: Default::default(),
             explanation_tooltips: false,
@@ -1420,6 +1438,9 @@ impl Style {
             interaction,
             visuals,
             animation_time,
+            animation_easing_open: _,  // can't change callbacks in the UI
+            animation_easing_close: _, // can't change callbacks in the UI
+            reduce_motion,
             #[cfg(debug_assertions)]
             debug,
             explanation_tooltips,
@@ -1482,6 +1503,11 @@ impl Style {
                     .suffix(" s"),
             );
             ui.end_row();
+
+            ui.label("Reduce motion");
+            ui.checkbox(reduce_motion, "")
+                .on_hover_text("Only fade windows in and out, instead of sliding or scaling them");
+            ui.end_row();
         });
 
         ui.collapsing("🔠 Text Styles", |ui| text_styles_ui(ui, text_styles));
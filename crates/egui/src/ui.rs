@@ -359,6 +359,11 @@ impl Ui {
         }
     }
 
+    /// Deny all future interaction, but without the grayed out look of [`Self::disable`].
+    pub(crate) fn ignore_interaction(&mut self) {
+        self.enabled = false;
+    }
+
     /// Calling `set_enabled(false)` will cause the [`Ui`] to deny all future interaction
     /// and all the widgets will draw with a gray look.
     ///
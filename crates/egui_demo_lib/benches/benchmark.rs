@@ -142,5 +142,118 @@ pub fn criterion_benchmark(c: &mut Criterion) {
     }
 }
 
-criterion_group!(benches, criterion_benchmark);
+pub fn easing_benchmark(c: &mut Criterion) {
+    use egui::emath::easing;
+
+    let input: Vec<f32> = (0..1000).map(|i| i as f32 / 999.0).collect();
+    let mut output = vec![0.0; input.len()];
+
+    c.bench_function("easing_fn_pointer", |b| {
+        let easing: fn(f32) -> f32 = std::hint::black_box(easing::cubic_in_out);
+        b.iter(|| {
+            for (out, &t) in output.iter_mut().zip(&input) {
+                *out = easing(t);
+            }
+        });
+    });
+
+    c.bench_function("easing_apply_slice", |b| {
+        b.iter(|| easing::apply_slice(easing::cubic_in_out, &input, &mut output));
+    });
+
+    c.bench_function("easing_exponential_in_out", |b| {
+        let easing: fn(f32) -> f32 = std::hint::black_box(easing::exponential_in_out);
+        b.iter(|| easing::apply_slice(easing, &input, &mut output));
+    });
+
+    c.bench_function("easing_table_exponential_in_out", |b| {
+        let table = easing::EasingTable::new(easing::exponential_in_out, 256);
+        b.iter(|| easing::apply_slice(|t| table.apply(t), &input, &mut output));
+    });
+}
+
+pub fn plot_benchmark(c: &mut Criterion) {
+    use egui_plot::{DownsampleMode, Line, Plot, PlotPoints, StreamingLine};
+
+    let points: Vec<[f64; 2]> = (0..200_000)
+        .map(|i| {
+            let x = i as f64 * 1e-4;
+            [x, (x * 50.0).sin() + (x * 1234.5).sin() * 0.1]
+        })
+        .collect();
+
+    for (name, mode) in [
+        ("plot_line_200k", DownsampleMode::None),
+        ("plot_line_200k_min_max", DownsampleMode::MinMax),
+    ] {
+        let ctx = egui::Context::default();
+        c.bench_function(name, |b| {
+            b.iter(|| {
+                let full_output = ctx.run(egui::RawInput::default(), |ctx| {
+                    egui::CentralPanel::default().show(ctx, |ui| {
+                        Plot::new("plot").show(ui, |plot_ui| {
+                            let line = Line::new(PlotPoints::new(points.clone()));
+                            plot_ui.line(line.downsample(mode));
+                        });
+                    });
+                });
+                ctx.tessellate(full_output.shapes, full_output.pixels_per_point)
+            });
+        });
+    }
+
+    // Streaming: one new point per frame into a full ring buffer of 100k points.
+    {
+        let mut line = StreamingLine::new(100_000);
+        for &[x, y] in &points[..100_000] {
+            line.push(x, y);
+        }
+        let mut next = points[100_000..].iter().cycle().enumerate();
+
+        c.bench_function("plot_streaming_push", |b| {
+            b.iter(|| {
+                let (i, &[x, y]) = next.next().unwrap();
+                // Keep x increasing while cycling through the points.
+                line.push(x + 20.0 * (i / 100_000) as f64, y);
+            });
+        });
+
+        // Steady state of the buffer: it was allocated up front and is never reallocated,
+        // the points just move through it.
+        line.clear();
+        line.push(0.0, 0.0);
+        let buffer = line.points().as_ptr();
+        for (i, &[x, y]) in points.iter().enumerate() {
+            line.push(x + 20.0 * i as f64, y);
+            let range = line.points().as_ptr_range();
+            assert!(buffer <= range.start);
+            assert!(range.end <= buffer.wrapping_add(2 * line.max_len()));
+        }
+
+        // A whole frame. Pushing doesn't allocate, but the visible points are still
+        // tessellated from scratch every frame, like a `Line`.
+        let ctx = egui::Context::default();
+        c.bench_function("plot_streaming_100k", |b| {
+            b.iter(|| {
+                let (i, &[x, y]) = next.next().unwrap();
+                line.push(x + 1e7 + 20.0 * (i / 100_000) as f64, y);
+                let full_output = ctx.run(egui::RawInput::default(), |ctx| {
+                    egui::CentralPanel::default().show(ctx, |ui| {
+                        Plot::new("plot").show(ui, |plot_ui| {
+                            plot_ui.line_streaming(&line);
+                        });
+                    });
+                });
+                ctx.tessellate(full_output.shapes, full_output.pixels_per_point)
+            });
+        });
+    }
+}
+
+criterion_group!(
+    benches,
+    criterion_benchmark,
+    easing_benchmark,
+    plot_benchmark
+);
 criterion_main!(benches);
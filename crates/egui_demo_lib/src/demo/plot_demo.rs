@@ -5,8 +5,8 @@ use egui::*;
 
 use egui_plot::{
     Arrows, AxisHints, Bar, BarChart, BoxElem, BoxPlot, BoxSpread, CoordinatesFormatter, Corner,
-    GridInput, GridMark, HLine, Legend, Line, LineStyle, MarkerShape, Plot, PlotImage, PlotPoint,
-    PlotPoints, PlotResponse, Points, Polygon, Text, VLine,
+    Gauge, GridInput, GridMark, HLine, Legend, Line, LineStyle, MarkerShape, Plot, PlotImage,
+    PlotPoint, PlotPoints, PlotResponse, Points, Polygon, Text, VLine,
 };
 
 // ----------------------------------------------------------------------------
@@ -860,6 +860,7 @@ enum Chart {
     GaussBars,
     StackedBars,
     BoxPlot,
+    Gauge,
 }
 
 impl Default for Chart {
@@ -875,6 +876,7 @@ struct ChartsDemo {
     allow_zoom: Vec2b,
     allow_drag: Vec2b,
     allow_scroll: Vec2b,
+    gauge_value: f64,
 }
 
 impl Default for ChartsDemo {
@@ -885,6 +887,7 @@ impl Default for ChartsDemo {
             allow_zoom: true.into(),
             allow_drag: true.into(),
             allow_scroll: true.into(),
+            gauge_value: 60.0,
         }
     }
 }
@@ -898,6 +901,7 @@ impl ChartsDemo {
                     ui.selectable_value(&mut self.chart, Chart::GaussBars, "Histogram");
                     ui.selectable_value(&mut self.chart, Chart::StackedBars, "Stacked Bar Chart");
                     ui.selectable_value(&mut self.chart, Chart::BoxPlot, "Box Plot");
+                    ui.selectable_value(&mut self.chart, Chart::Gauge, "Gauge");
                 });
                 ui.label("Orientation:");
                 ui.horizontal(|ui| {
@@ -931,6 +935,7 @@ impl ChartsDemo {
             Chart::GaussBars => self.bar_gauss(ui),
             Chart::StackedBars => self.bar_stacked(ui),
             Chart::BoxPlot => self.box_plot(ui),
+            Chart::Gauge => self.gauge(ui),
         }
     }
 
@@ -1072,6 +1077,25 @@ impl ChartsDemo {
             })
             .response
     }
+
+    fn gauge(&mut self, ui: &mut Ui) -> Response {
+        ui.add(Slider::new(&mut self.gauge_value, 0.0..=120.0).text("Speed"));
+
+        let gauge = Gauge::new([0.0, 0.0], 1.0, 0.0..=120.0, self.gauge_value)
+            .ticks(7)
+            .tick_formatter(Box::new(|value| format!("{value:.0}")))
+            .needle(true)
+            .animate(0.5, emath::easing::cubic_out)
+            .name("Speed");
+
+        Plot::new("Gauge Demo")
+            .data_aspect(1.0)
+            .allow_zoom(self.allow_zoom)
+            .allow_drag(self.allow_drag)
+            .allow_scroll(self.allow_scroll)
+            .show(ui, |plot_ui| plot_ui.gauge(gauge))
+            .response
+    }
 }
 
 fn is_approx_zero(val: f64) -> bool {
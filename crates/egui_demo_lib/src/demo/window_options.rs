@@ -15,6 +15,30 @@ pub struct WindowOptions {
     anchored: bool,
     anchor: egui::Align2,
     anchor_offset: egui::Vec2,
+
+    open_animation: OpenAnimation,
+    animation_duration: f32,
+}
+
+#[derive(Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+enum OpenAnimation {
+    Default,
+    Fade,
+    Slide,
+    Scale,
+}
+
+impl OpenAnimation {
+    fn to_window_animation(self, duration: f32) -> Option<egui::WindowAnimation> {
+        let animation = match self {
+            Self::Default => return None,
+            Self::Fade => egui::WindowAnimation::fade(),
+            Self::Slide => egui::WindowAnimation::slide(egui::vec2(64.0, 0.0)),
+            Self::Scale => egui::WindowAnimation::scale(0.8).easing(egui::emath::easing::back_out),
+        };
+        Some(animation.duration(duration))
+    }
 }
 
 impl Default for WindowOptions {
@@ -31,6 +55,8 @@ impl Default for WindowOptions {
             anchored: false,
             anchor: egui::Align2::RIGHT_TOP,
             anchor_offset: egui::Vec2::ZERO,
+            open_animation: OpenAnimation::Default,
+            animation_duration: 0.25,
         }
     }
 }
@@ -53,6 +79,8 @@ impl crate::Demo for WindowOptions {
             anchored,
             anchor,
             anchor_offset,
+            open_animation,
+            animation_duration,
         } = self.clone();
 
         let enabled = ctx.input(|i| i.time) - disabled_time > 2.0;
@@ -75,6 +103,9 @@ impl crate::Demo for WindowOptions {
         if anchored {
             window = window.anchor(anchor, anchor_offset);
         }
+        if let Some(animation) = open_animation.to_window_animation(animation_duration) {
+            window = window.open_animation(animation);
+        }
         window.show(ctx, |ui| self.ui(ui));
     }
 }
@@ -93,6 +124,8 @@ impl crate::View for WindowOptions {
             anchored,
             anchor,
             anchor_offset,
+            open_animation,
+            animation_duration,
         } = self;
         ui.horizontal(|ui| {
             ui.label("title:");
@@ -137,6 +170,30 @@ impl crate::View for WindowOptions {
                     });
                 });
             });
+            ui.group(|ui| {
+                ui.vertical(|ui| {
+                    ui.label("Open animation:");
+                    ui.radio_value(open_animation, OpenAnimation::Default, "Default");
+                    ui.radio_value(open_animation, OpenAnimation::Fade, "Fade");
+                    ui.radio_value(open_animation, OpenAnimation::Slide, "Slide");
+                    ui.radio_value(open_animation, OpenAnimation::Scale, "Scale");
+                    ui.add_enabled(
+                        *open_animation != OpenAnimation::Default,
+                        egui::DragValue::new(animation_duration)
+                            .range(0.0..=2.0)
+                            .speed(0.01)
+                            .suffix(" s"),
+                    );
+                });
+            });
+        });
+
+        let mut header = egui::CollapsingHeader::new("Collapsing header");
+        if let Some(animation) = open_animation.to_window_animation(*animation_duration) {
+            header = header.open_animation(animation);
+        }
+        header.show(ui, |ui| {
+            ui.label("The body of a collapsing header can use the same open animation.");
         });
 
         ui.separator();
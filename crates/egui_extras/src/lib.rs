@@ -12,6 +12,7 @@
 #[cfg(feature = "chrono")]
 mod datepicker;
 
+pub mod markdown;
 pub mod syntax_highlighting;
 
 #[doc(hidden)]
@@ -0,0 +1,490 @@
+//! A small subset of Markdown, for help panels and the like.
+//!
+//! Supported syntax:
+//! * `# Headings`, levels 1 to 6
+//! * `**bold**`, `*italics*` and `_italics_`
+//! * `` `inline code` ``
+//! * `[links](https://www.example.com)`
+//! * `- bullet` and `* bullet` lists, nested by indenting two spaces per level
+//!
+//! Paragraphs are separated by empty lines.
+//! Anything else is shown as plain text: the parser never fails.
+//!
+//! ```
+//! # egui::__run_test_ui(|ui| {
+//! egui_extras::markdown::MarkdownView::new("# Help\nPress **Ctrl+S** to save.").show(ui);
+//! # });
+//! ```
+
+use std::sync::Arc;
+
+use egui::{
+    emath::easing::{BoxedCurve, Curve},
+    Id, RichText, Ui,
+};
+
+/// A top-level piece of Markdown.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Block {
+    /// `# Heading`, with a `level` from 1 to 6.
+    Heading { level: u8, spans: Vec<Span> },
+
+    /// Consecutive lines of text.
+    Paragraph(Vec<Span>),
+
+    /// `- item`, with an `indent` level starting at zero.
+    BulletItem { indent: usize, spans: Vec<Span> },
+}
+
+/// A piece of inline text.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Span {
+    Text {
+        text: String,
+        strong: bool,
+        italics: bool,
+    },
+
+    /// `` `code` ``
+    Code(String),
+
+    /// `[text](url)`
+    Link { text: String, url: String },
+}
+
+/// Parse the supported subset of Markdown, see the [module docs](self).
+pub fn parse(source: &str) -> Vec<Block> {
+    let mut blocks = vec![];
+    let mut paragraph = String::new();
+
+    for line in source.lines() {
+        let trimmed = line.trim_start();
+        let heading = parse_heading(trimmed);
+        let bullet = trimmed
+            .strip_prefix("- ")
+            .or_else(|| trimmed.strip_prefix("* "));
+
+        if trimmed.is_empty() || heading.is_some() || bullet.is_some() {
+            flush_paragraph(&mut blocks, &mut paragraph);
+        }
+
+        if let Some((level, text)) = heading {
+            blocks.push(Block::Heading {
+                level,
+                spans: parse_spans(text),
+            });
+        } else if let Some(text) = bullet {
+            let indent = (line.len() - trimmed.len()) / 2;
+            blocks.push(Block::BulletItem {
+                indent,
+                spans: parse_spans(text.trim()),
+            });
+        } else if !trimmed.is_empty() {
+            if !paragraph.is_empty() {
+                paragraph.push(' ');
+            }
+            paragraph.push_str(trimmed.trim_end());
+        }
+    }
+    flush_paragraph(&mut blocks, &mut paragraph);
+
+    blocks
+}
+
+fn flush_paragraph(blocks: &mut Vec<Block>, paragraph: &mut String) {
+    if !paragraph.is_empty() {
+        blocks.push(Block::Paragraph(parse_spans(paragraph)));
+        paragraph.clear();
+    }
+}
+
+fn parse_heading(line: &str) -> Option<(u8, &str)> {
+    let level = line.bytes().take_while(|&b| b == b'#').count();
+    if (1..=6).contains(&level) {
+        let text = line[level..].strip_prefix(' ')?;
+        Some((level as u8, text.trim()))
+    } else {
+        None
+    }
+}
+
+fn parse_spans(text: &str) -> Vec<Span> {
+    let mut spans = vec![];
+    let mut current = String::new();
+    let mut strong = false;
+    let mut italics: Option<char> = None; // the delimiter that opened the italics
+    let mut prev: Option<char> = None;
+    let mut rest = text;
+
+    while let Some(c) = rest.chars().next() {
+        let after = &rest[c.len_utf8()..];
+
+        if c == '`' {
+            if let Some(end) = after.find('`') {
+                flush_text(&mut spans, &mut current, strong, italics.is_some());
+                spans.push(Span::Code(after[..end].to_owned()));
+                rest = &after[end + 1..];
+                prev = Some('`');
+                continue;
+            }
+        } else if let Some(after) = rest.strip_prefix("**") {
+            if strong || after.contains("**") {
+                flush_text(&mut spans, &mut current, strong, italics.is_some());
+                strong = !strong;
+                rest = after;
+                prev = Some('*');
+                continue;
+            }
+        } else if c == '*' || c == '_' {
+            // `snake_case` is not italics:
+            let at_word_start = !prev.map_or(false, char::is_alphanumeric);
+            let toggles = match italics {
+                Some(delimiter) => delimiter == c,
+                None => (c == '*' || at_word_start) && after.contains(c),
+            };
+            if toggles {
+                flush_text(&mut spans, &mut current, strong, italics.is_some());
+                italics = if italics.is_some() { None } else { Some(c) };
+                rest = after;
+                prev = Some(c);
+                continue;
+            }
+        } else if c == '[' {
+            if let Some((link_text, url, len)) = parse_link(rest) {
+                flush_text(&mut spans, &mut current, strong, italics.is_some());
+                spans.push(Span::Link {
+                    text: link_text.to_owned(),
+                    url: url.to_owned(),
+                });
+                rest = &rest[len..];
+                prev = Some(')');
+                continue;
+            }
+        }
+
+        current.push(c);
+        rest = after;
+        prev = Some(c);
+    }
+    flush_text(&mut spans, &mut current, strong, italics.is_some());
+
+    spans
+}
+
+fn flush_text(spans: &mut Vec<Span>, current: &mut String, strong: bool, italics: bool) {
+    if !current.is_empty() {
+        spans.push(Span::Text {
+            text: std::mem::take(current),
+            strong,
+            italics,
+        });
+    }
+}
+
+/// Parses `[text](url)` at the start of `s`, returning the text, url, and the length in bytes.
+fn parse_link(s: &str) -> Option<(&str, &str, usize)> {
+    let close = s.find(']')?;
+    let text = &s[1..close];
+    let after = s[close + 1..].strip_prefix('(')?;
+    let url_end = after.find(')')?;
+    let url = &after[..url_end];
+    if text.is_empty() || url.is_empty() || url.contains(char::is_whitespace) {
+        return None;
+    }
+    Some((text, url, close + 2 + url_end + 1))
+}
+
+// ----------------------------------------------------------------------------
+
+/// Shows Markdown as egui widgets, see the [module docs](self) for what is supported.
+///
+/// Parsing is cached, so this can be shown every frame.
+#[must_use = "You should call .show()"]
+pub struct MarkdownView<'a> {
+    source: &'a str,
+    reveal: Option<(f32, BoxedCurve)>,
+}
+
+impl<'a> MarkdownView<'a> {
+    /// Show the Markdown in `source`.
+    ///
+    /// Only a small subset is supported: `#` to `######` headings, **bold**, *italics*, `inline code`, links,
+    /// bullet lists, and paragraphs separated by empty lines.
+    /// Anything else, e.g. tables, numbered lists, images or code blocks, is shown as plain text.
+    pub fn new(source: &'a str) -> Self {
+        Self {
+            source,
+            reveal: None,
+        }
+    }
+
+    /// Fade and slide in the blocks one after the other when the view first appears.
+    ///
+    /// Each block takes `seconds_per_block` to appear, eased with one of the functions in [`egui::emath::easing`],
+    /// or any other [`egui::emath::easing::Curve`].
+    #[inline]
+    pub fn reveal(
+        mut self,
+        seconds_per_block: f32,
+        easing: impl Curve + Send + Sync + 'static,
+    ) -> Self {
+        self.reveal = Some((seconds_per_block, BoxedCurve::new(easing)));
+        self
+    }
+
+    /// Add the blocks to `ui`, top to bottom, wrapping to its available width.
+    ///
+    /// Links open in the browser when clicked.
+    /// The returned [`egui::Response`] covers the whole view.
+    pub fn show(self, ui: &mut Ui) -> egui::Response {
+        let Self { source, reveal } = self;
+
+        let blocks = parse_cached(ui.ctx(), source);
+        let reveal_age = reveal
+            .as_ref()
+            .map(|_| reveal_age(ui, ui.id().with(source)));
+
+        ui.vertical(|ui| {
+            for (i, block) in blocks.iter().enumerate() {
+                let (Some((seconds_per_block, easing)), Some(age)) = (&reveal, reveal_age) else {
+                    block_ui(ui, block);
+                    continue;
+                };
+
+                let t = if *seconds_per_block <= 0.0 {
+                    1.0
+                } else {
+                    age / seconds_per_block - i as f32
+                };
+                let t = easing.apply(t.clamp(0.0, 1.0));
+                if t < 1.0 {
+                    ui.ctx().request_repaint();
+                }
+
+                let slide = 16.0 * (1.0 - t);
+                egui::Frame::none()
+                    .outer_margin(egui::Margin {
+                        left: slide,
+                        ..Default::default()
+                    })
+                    .show(ui, |ui| {
+                        ui.multiply_opacity(t);
+                        block_ui(ui, block);
+                    });
+            }
+        })
+        .response
+    }
+}
+
+fn parse_cached(ctx: &egui::Context, source: &str) -> Arc<Vec<Block>> {
+    #[derive(Default)]
+    struct Parser;
+
+    impl egui::util::cache::ComputerMut<&str, Arc<Vec<Block>>> for Parser {
+        fn compute(&mut self, source: &str) -> Arc<Vec<Block>> {
+            Arc::new(parse(source))
+        }
+    }
+
+    type ParseCache = egui::util::cache::FrameCache<Arc<Vec<Block>>, Parser>;
+
+    ctx.memory_mut(|mem| mem.caches.cache::<ParseCache>().get(source))
+}
+
+/// Seconds since the view appeared (again).
+fn reveal_age(ui: &Ui, id: Id) -> f32 {
+    let ctx = ui.ctx();
+    let now = ctx.input(|i| i.time);
+    let frame_nr = ctx.frame_nr();
+    let start_time = ctx.data_mut(|data| {
+        let (start_time, last_frame_nr) = data.get_temp_mut_or_insert_with(id, || (now, frame_nr));
+        if *last_frame_nr + 1 < frame_nr {
+            *start_time = now; // We were not shown last frame, so start over.
+        }
+        *last_frame_nr = frame_nr;
+        *start_time
+    });
+    (now - start_time) as f32
+}
+
+fn block_ui(ui: &mut Ui, block: &Block) {
+    match block {
+        Block::Heading { level, spans } => {
+            ui.horizontal_wrapped(|ui| {
+                ui.spacing_mut().item_spacing.x = 0.0;
+                for span in spans {
+                    span_ui(ui, span, Some(*level));
+                }
+            });
+        }
+        Block::Paragraph(spans) => {
+            ui.horizontal_wrapped(|ui| {
+                ui.spacing_mut().item_spacing.x = 0.0;
+                for span in spans {
+                    span_ui(ui, span, None);
+                }
+            });
+        }
+        Block::BulletItem { indent, spans } => {
+            ui.horizontal(|ui| {
+                ui.add_space(*indent as f32 * ui.spacing().indent);
+                ui.label("•");
+                ui.horizontal_wrapped(|ui| {
+                    ui.spacing_mut().item_spacing.x = 0.0;
+                    for span in spans {
+                        span_ui(ui, span, None);
+                    }
+                });
+            });
+        }
+    }
+}
+
+fn span_ui(ui: &mut Ui, span: &Span, heading_level: Option<u8>) {
+    match span {
+        Span::Text {
+            text,
+            strong,
+            italics,
+        } => {
+            let mut rich_text = RichText::new(text);
+            match heading_level {
+                Some(1 | 2) => rich_text = rich_text.heading(),
+                Some(_) => rich_text = rich_text.strong(),
+                None => {}
+            }
+            if *strong {
+                rich_text = rich_text.strong();
+            }
+            if *italics {
+                rich_text = rich_text.italics();
+            }
+            ui.label(rich_text);
+        }
+        Span::Code(code) => {
+            ui.code(code);
+        }
+        Span::Link { text, url } => {
+            ui.hyperlink_to(text, url);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn text(text: &str) -> Span {
+        Span::Text {
+            text: text.to_owned(),
+            strong: false,
+            italics: false,
+        }
+    }
+
+    #[test]
+    fn test_parse_fixture() {
+        let source = "\
+# Getting started
+Press **Ctrl+S** to save,
+or *read* the [manual](https://example.com/manual).
+
+## Tips
+- Use `cargo run`
+  - nested _item_
+* another item
+";
+        let expected = vec![
+            Block::Heading {
+                level: 1,
+                spans: vec![text("Getting started")],
+            },
+            Block::Paragraph(vec![
+                text("Press "),
+                Span::Text {
+                    text: "Ctrl+S".to_owned(),
+                    strong: true,
+                    italics: false,
+                },
+                text(" to save, or "),
+                Span::Text {
+                    text: "read".to_owned(),
+                    strong: false,
+                    italics: true,
+                },
+                text(" the "),
+                Span::Link {
+                    text: "manual".to_owned(),
+                    url: "https://example.com/manual".to_owned(),
+                },
+                text("."),
+            ]),
+            Block::Heading {
+                level: 2,
+                spans: vec![text("Tips")],
+            },
+            Block::BulletItem {
+                indent: 0,
+                spans: vec![text("Use "), Span::Code("cargo run".to_owned())],
+            },
+            Block::BulletItem {
+                indent: 1,
+                spans: vec![
+                    text("nested "),
+                    Span::Text {
+                        text: "item".to_owned(),
+                        strong: false,
+                        italics: true,
+                    },
+                ],
+            },
+            Block::BulletItem {
+                indent: 0,
+                spans: vec![text("another item")],
+            },
+        ];
+        assert_eq!(parse(source), expected);
+    }
+
+    #[test]
+    fn test_unknown_syntax_is_plain_text() {
+        for source in [
+            "snake_case_name",
+            "2 * 3 = 6",
+            "unclosed `code",
+            "unclosed **bold",
+            "[not a link] (x)",
+            "[empty]()",
+            "#hashtag",
+            "####### seven",
+            "> quote",
+            "| table |",
+        ] {
+            assert_eq!(
+                parse(source),
+                vec![Block::Paragraph(vec![text(source)])],
+                "{source:?}"
+            );
+        }
+    }
+
+    #[test]
+    fn test_parse_never_panics() {
+        for source in [
+            "",
+            "*",
+            "**",
+            "`",
+            "[",
+            "[]",
+            "[a](",
+            "#",
+            "- ",
+            "ü*ö*`ä",
+            "_ü_\n\n\n",
+        ] {
+            parse(source);
+        }
+    }
+}
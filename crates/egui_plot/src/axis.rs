@@ -2,8 +2,11 @@ use std::{fmt::Debug, ops::RangeInclusive, sync::Arc};
 
 use egui::{
     emath::{remap_clamp, Rot2},
-    epaint::TextShape,
-    Pos2, Rangef, Rect, Response, Sense, TextStyle, TextWrapMode, Ui, Vec2, WidgetText,
+    epaint::{
+        text::{LayoutJob, TextWrapping},
+        TextShape,
+    },
+    Color32, Pos2, Rangef, Rect, Response, Sense, TextStyle, TextWrapMode, Ui, Vec2, WidgetText,
 };
 
 use super::{transform::PlotTransform, GridMark};
@@ -104,11 +107,17 @@ pub struct AxisHints<'a> {
     pub(super) min_thickness: f32,
     pub(super) placement: Placement,
     pub(super) label_spacing: Rangef,
+    pub(super) truncate_labels: bool,
+    pub(super) label_rotation: f32,
+    pub(super) rotate_crowded_labels: bool,
 }
 
 // TODO(JohannesProgrammiert): this just a guess. It might cease to work if a user changes font size.
 const LINE_HEIGHT: f32 = 12.0;
 
+/// Degrees to slant crowded labels by, see [`AxisHints::rotate_crowded_labels`].
+const CROWDED_LABEL_ROTATION: f32 = 45.0;
+
 impl<'a> AxisHints<'a> {
     /// Initializes a default axis configuration for the X axis.
     pub fn new_x() -> Self {
@@ -134,6 +143,9 @@ impl<'a> AxisHints<'a> {
                 Axis::X => Rangef::new(60.0, 80.0), // labels can get pretty wide
                 Axis::Y => Rangef::new(20.0, 30.0), // text isn't very high
             },
+            truncate_labels: false,
+            label_rotation: 0.0,
+            rotate_crowded_labels: false,
         }
     }
 
@@ -183,6 +195,14 @@ impl<'a> AxisHints<'a> {
     ///
     /// For X-axis, use [`VPlacement`].
     /// For Y-axis, use [`HPlacement`].
+    ///
+    /// The plot leaves room for the axis on that side, and the tick labels face the plot.
+    /// ```
+    /// # use egui_plot::{AxisHints, HPlacement, Plot, VPlacement};
+    /// let plot = Plot::new("sidebar")
+    ///     .custom_x_axes(vec![AxisHints::new_x().placement(VPlacement::Top)])
+    ///     .custom_y_axes(vec![AxisHints::new_y().placement(HPlacement::Right)]);
+    /// ```
     #[inline]
     pub fn placement(mut self, placement: impl Into<Placement>) -> Self {
         self.placement = placement.into();
@@ -201,6 +221,38 @@ impl<'a> AxisHints<'a> {
         self
     }
 
+    /// Truncate tick labels that are wider than the spacing between ticks, instead of hiding them.
+    ///
+    /// Only affects the X axis. Default: `false`.
+    #[inline]
+    pub fn truncate_labels(mut self, truncate: bool) -> Self {
+        self.truncate_labels = truncate;
+        self
+    }
+
+    /// Slant the tick labels by this many degrees, e.g. `45.0`, so long labels fit closer together.
+    ///
+    /// The labels rise to the right, and their end is at the tick for a bottom axis,
+    /// their start for a top axis. The axis grows to fit them.
+    /// Labels are hidden if they would overlap their neighbors.
+    ///
+    /// Only affects the X axis, and is clamped to `0..=90`. Default: `0.0`.
+    /// Rotated labels are not truncated by [`Self::truncate_labels`].
+    #[inline]
+    pub fn label_rotation(mut self, degrees: f32) -> Self {
+        self.label_rotation = degrees.clamp(0.0, 90.0);
+        self
+    }
+
+    /// Slant the tick labels by 45° whenever the widest of them doesn't fit between its ticks.
+    ///
+    /// Only affects the X axis, and only if [`Self::label_rotation`] is zero. Default: `false`.
+    #[inline]
+    pub fn rotate_crowded_labels(mut self, rotate: bool) -> Self {
+        self.rotate_crowded_labels = rotate;
+        self
+    }
+
     pub(super) fn thickness(&self, axis: Axis) -> f32 {
         match axis {
             Axis::X => self.min_thickness.max(if self.label.is_empty() {
@@ -252,6 +304,7 @@ impl<'a> AxisWidget<'a> {
         }
 
         let visuals = ui.style().visuals.clone();
+        let has_label = !self.hints.label.is_empty();
 
         {
             let text = self.hints.label;
@@ -314,6 +367,29 @@ impl<'a> AxisWidget<'a> {
         };
 
         let label_spacing = self.hints.label_spacing;
+        let rotation = match axis {
+            Axis::X if self.hints.label_rotation == 0.0 && self.hints.rotate_crowded_labels => {
+                let crowded = self.steps.iter().any(|step| {
+                    let text = (self.hints.formatter)(*step, &self.range);
+                    let spacing_in_points =
+                        (transform.dpos_dvalue()[0] * step.step_size).abs() as f32;
+                    !text.is_empty()
+                        && label_spacing.min < spacing_in_points
+                        && spacing_in_points
+                            < ui.painter()
+                                .layout_no_wrap(text, font_id.clone(), Color32::PLACEHOLDER)
+                                .size()
+                                .x
+                });
+                if crowded {
+                    CROWDED_LABEL_ROTATION.to_radians()
+                } else {
+                    0.0
+                }
+            }
+            Axis::X => self.hints.label_rotation.to_radians(),
+            Axis::Y => 0.0,
+        };
 
         let mut thickness: f32 = 0.0;
 
@@ -333,15 +409,55 @@ impl<'a> AxisWidget<'a> {
                 let strength = remap_clamp(spacing_in_points, label_spacing, 0.0..=1.0);
 
                 let text_color = super::color_from_strength(ui, strength);
-                let galley = ui
-                    .painter()
-                    .layout_no_wrap(text, font_id.clone(), text_color);
+                let galley = if self.hints.truncate_labels && axis == Axis::X && rotation == 0.0 {
+                    let mut job = LayoutJob::simple_singleline(text, font_id.clone(), text_color);
+                    job.wrap = TextWrapping::truncate_at_width(spacing_in_points);
+                    ui.painter().layout_job(job)
+                } else {
+                    ui.painter()
+                        .layout_no_wrap(text, font_id.clone(), text_color)
+                };
 
-                if spacing_in_points < galley.size()[axis as usize] {
+                if rotation > 0.0 {
+                    // Neighboring labels are parallel, so they are apart if either their
+                    // distance across or along the text is large enough.
+                    let size = galley.size();
+                    if spacing_in_points * rotation.sin() < size.y
+                        && spacing_in_points * rotation.cos() < size.x
+                    {
+                        continue;
+                    }
+                } else if spacing_in_points < galley.size()[axis as usize] {
                     continue; // the galley won't fit (likely too wide on the X axis).
                 }
 
                 match axis {
+                    Axis::X if rotation > 0.0 => {
+                        let size = galley.size();
+                        let (sin, cos) = rotation.sin_cos();
+                        thickness = thickness.max(size.x * sin + size.y * cos);
+
+                        let projected_point = super::PlotPoint::new(step.value, 0.0);
+                        let tick_x = transform.position_from_point(&projected_point).x;
+
+                        // Put the middle of the end (or start) of the text at the tick, with
+                        // the rotated text touching the plot.
+                        let angle = -rotation;
+                        let along = Rot2::from_angle(angle) * Vec2::X;
+                        let across = Rot2::from_angle(angle) * Vec2::Y;
+                        let (anchor, along_offset) = match VPlacement::from(self.hints.placement) {
+                            VPlacement::Bottom => (
+                                Pos2::new(tick_x, self.rect.min.y + size.y / 2.0 * cos),
+                                size.x,
+                            ),
+                            VPlacement::Top => {
+                                (Pos2::new(tick_x, self.rect.max.y - size.y / 2.0 * cos), 0.0)
+                            }
+                        };
+                        let pos = anchor - along * along_offset - across * (size.y / 2.0);
+                        ui.painter()
+                            .add(TextShape::new(pos, galley, text_color).with_angle(angle));
+                    }
                     Axis::X => {
                         thickness = thickness.max(galley.size().y);
 
@@ -393,6 +509,11 @@ impl<'a> AxisWidget<'a> {
             }
         }
 
+        if rotation > 0.0 && has_label {
+            // Keep the axis label below the slanted tick labels.
+            thickness += 2.0 * LINE_HEIGHT;
+        }
+
         (response, thickness)
     }
 }
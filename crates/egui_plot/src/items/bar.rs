@@ -149,7 +149,11 @@ impl Bar {
         let text: Option<String> = parent
             .element_formatter
             .as_ref()
-            .map(|fmt| fmt(self, parent));
+            .map(|fmt| fmt(self, parent))
+            .or_else(|| {
+                let formatter = parent.hover_formatter.as_ref()?;
+                Some(formatter(&self.point_at(self.argument, self.value)))
+            });
 
         add_rulers_and_text(self, plot, text, shapes, cursors);
     }
@@ -192,6 +196,15 @@ impl RectElement for Bar {
             Orientation::Vertical => scale[1],
         };
         let decimals = ((-scale.abs().log10()).ceil().at_least(0.0) as usize).at_most(6);
-        crate::format_number(self.value, decimals)
+        if let Some(base) = self.base_offset {
+            // A stacked bar, also show the total of the stack up to here.
+            format!(
+                "{} (total {})",
+                crate::format_number(self.value, decimals),
+                crate::format_number(base + self.value, decimals)
+            )
+        } else {
+            crate::format_number(self.value, decimals)
+        }
     }
 }
@@ -0,0 +1,287 @@
+use crate::{Bar, BarChart};
+
+/// The most bins a [`Histogram`] has. Narrower bins are widened to stay below this.
+const MAX_BINS: usize = 10_000;
+
+/// How [`Histogram::from_samples`] divides the range of the samples into bins.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub enum BinSpec {
+    /// This many bins of equal width, spanning from the smallest to the largest sample.
+    Count(usize),
+
+    /// Bins of this width, aligned to multiples of the width.
+    Width(f64),
+
+    /// Pick the bin width with the Freedman–Diaconis rule, `2 * IQR / n^(1/3)`.
+    ///
+    /// If the interquartile range is zero, e.g. if most samples are equal,
+    /// this uses Sturges' rule, `log2(n) + 1` bins, instead.
+    #[default]
+    Auto,
+}
+
+/// Bins raw samples into a [`BarChart`].
+///
+/// Each bin includes its lower edge, and the last bin also its upper edge.
+/// Samples that are not finite are ignored.
+///
+/// ```
+/// # use egui_plot::{BarChart, BinSpec, Histogram};
+/// let samples = [1.0, 2.0, 2.5, 3.0, 7.0];
+/// let histogram = Histogram::from_samples(&samples, BinSpec::Width(2.0));
+/// assert_eq!(histogram.edges(), &[0.0, 2.0, 4.0, 6.0, 8.0]);
+/// assert_eq!(histogram.counts(), &[1, 3, 0, 1]);
+/// let chart: BarChart = histogram.into();
+/// ```
+#[derive(Clone, Debug, PartialEq)]
+pub struct Histogram {
+    edges: Vec<f64>,
+    counts: Vec<usize>,
+    density: bool,
+    cumulative: bool,
+}
+
+impl Histogram {
+    /// Count the `samples` in the bins given by `bins`.
+    ///
+    /// If all samples are equal, there is a single bin of width one around them.
+    /// There are at most 10 000 bins: narrower bins are widened to fit,
+    /// e.g. if [`BinSpec::Auto`] meets a far outlier.
+    pub fn from_samples(samples: &[f64], bins: BinSpec) -> Self {
+        let mut sorted: Vec<f64> = samples.iter().copied().filter(|x| x.is_finite()).collect();
+        sorted.sort_by(f64::total_cmp);
+        let (Some(&min), Some(&max)) = (sorted.first(), sorted.last()) else {
+            return Self {
+                edges: Vec::new(),
+                counts: Vec::new(),
+                density: false,
+                cumulative: false,
+            };
+        };
+        let span = max - min;
+
+        let (start, width, num_bins) = if span == 0.0 {
+            // A single bin around the only value.
+            (min - 0.5, 1.0, 1)
+        } else {
+            let width = match bins {
+                BinSpec::Count(count) => span / count.max(1) as f64,
+                BinSpec::Width(width) if width > 0.0 && width.is_finite() => width,
+                BinSpec::Width(_) | BinSpec::Auto => {
+                    let n = sorted.len() as f64;
+                    let iqr = quantile(&sorted, 0.75) - quantile(&sorted, 0.25);
+                    if iqr > 0.0 {
+                        2.0 * iqr / n.cbrt()
+                    } else {
+                        span / (n.log2().ceil() + 1.0)
+                    }
+                }
+            };
+            // Aligning the start to the width may add one more bin.
+            let width = width.max(span / (MAX_BINS - 1) as f64);
+            let start = match bins {
+                BinSpec::Width(_) => (min / width).floor() * width,
+                BinSpec::Count(_) | BinSpec::Auto => min,
+            };
+            let num_bins = (((max - start) / width).floor() as usize + 1).max(1);
+            // The largest sample is in the last bin, not in a bin of its own.
+            let num_bins = if start + (num_bins - 1) as f64 * width >= max && num_bins > 1 {
+                num_bins - 1
+            } else {
+                num_bins
+            };
+            (start, width, num_bins)
+        };
+
+        let edges = (0..=num_bins).map(|i| start + i as f64 * width).collect();
+        let mut counts = vec![0; num_bins];
+        for x in sorted {
+            let bin = (((x - start) / width).floor().max(0.0) as usize).min(num_bins - 1);
+            counts[bin] += 1;
+        }
+
+        Self {
+            edges,
+            counts,
+            density: false,
+            cumulative: false,
+        }
+    }
+
+    /// Normalize the bars so their total area is one, i.e. the height is `count / (total * width)`.
+    ///
+    /// Combined with [`Self::cumulative`] the bars show the fraction of the samples instead.
+    /// Default: `false`.
+    #[inline]
+    pub fn density(mut self, density: bool) -> Self {
+        self.density = density;
+        self
+    }
+
+    /// Make each bar include the samples of all bins before it. Default: `false`.
+    #[inline]
+    pub fn cumulative(mut self, cumulative: bool) -> Self {
+        self.cumulative = cumulative;
+        self
+    }
+
+    /// The edges of the bins, one more than there are bins.
+    #[inline]
+    pub fn edges(&self) -> &[f64] {
+        &self.edges
+    }
+
+    /// The number of samples in each bin.
+    #[inline]
+    pub fn counts(&self) -> &[usize] {
+        &self.counts
+    }
+
+    /// The heights of the bars, depending on [`Self::density`] and [`Self::cumulative`].
+    pub fn values(&self) -> Vec<f64> {
+        let total: usize = self.counts.iter().sum();
+        let mut sum = 0.0;
+        self.counts
+            .iter()
+            .zip(self.edges.windows(2))
+            .map(|(&count, edge)| {
+                let value = match (self.density, self.cumulative) {
+                    (false, _) => count as f64,
+                    (true, false) => count as f64 / (total as f64 * (edge[1] - edge[0])),
+                    (true, true) => count as f64 / total as f64,
+                };
+                if self.cumulative {
+                    sum += value;
+                    sum
+                } else {
+                    value
+                }
+            })
+            .collect()
+    }
+}
+
+impl From<Histogram> for BarChart {
+    /// Hovering a bar shows its bin range and count.
+    fn from(histogram: Histogram) -> Self {
+        let bars = histogram
+            .values()
+            .into_iter()
+            .zip(histogram.edges.windows(2))
+            .zip(&histogram.counts)
+            .enumerate()
+            .map(|(i, ((value, edge), count))| {
+                let close = if i + 1 == histogram.counts.len() {
+                    ']'
+                } else {
+                    ')'
+                };
+                let mut name = format!("[{}, {}{close}\ncount: {count}", edge[0], edge[1]);
+                if histogram.density || histogram.cumulative {
+                    name += &format!("\nvalue: {value:.3}");
+                }
+                Bar::new((edge[0] + edge[1]) / 2.0, value)
+                    .width(edge[1] - edge[0])
+                    .name(name)
+            })
+            .collect();
+        Self::new(bars).element_formatter(Box::new(|bar, _| bar.name.clone()))
+    }
+}
+
+/// The `q`-quantile of sorted, non-empty `values`, interpolating linearly.
+pub(super) fn quantile(values: &[f64], q: f64) -> f64 {
+    let position = q * (values.len() - 1) as f64;
+    let (below, above) = (position.floor() as usize, position.ceil() as usize);
+    let t = position - below as f64;
+    values[below] * (1.0 - t) + values[above] * t
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_histogram() {
+        let samples = [0.5, 1.0, 1.5, 2.0, 2.2, 2.9, 3.0, 4.0, f64::NAN];
+
+        let histogram = Histogram::from_samples(&samples, BinSpec::Count(4));
+        assert_eq!(histogram.edges(), &[0.5, 1.375, 2.25, 3.125, 4.0]);
+        assert_eq!(histogram.counts(), &[2, 3, 2, 1]);
+
+        let histogram = Histogram::from_samples(&samples, BinSpec::Width(1.0));
+        assert_eq!(histogram.edges(), &[0.0, 1.0, 2.0, 3.0, 4.0]);
+        // The largest sample is in the last bin:
+        assert_eq!(histogram.counts(), &[1, 2, 3, 2]);
+
+        let density = histogram.clone().density(true).values();
+        assert!((density.iter().sum::<f64>() - 1.0).abs() < 1e-9);
+        assert_eq!(
+            histogram.clone().cumulative(true).values(),
+            [1.0, 3.0, 6.0, 8.0]
+        );
+        assert_eq!(
+            histogram
+                .clone()
+                .density(true)
+                .cumulative(true)
+                .values()
+                .last(),
+            Some(&1.0)
+        );
+
+        let chart = BarChart::from(histogram);
+        assert_eq!(chart.bars.len(), 4);
+        assert_eq!(chart.bars[1].argument, 1.5);
+        assert_eq!(chart.bars[1].bar_width, 1.0);
+        assert_eq!(chart.bars[1].name, "[1, 2)\ncount: 2");
+        assert_eq!(chart.bars[3].name, "[3, 4]\ncount: 2");
+
+        // Freedman–Diaconis: IQR = 5.25 - 1.75, so the bins are 2 * 3.5 / 8^(1/3) = 3.5 wide.
+        let samples: Vec<f64> = (0..8).map(|x| x as f64).collect();
+        let histogram = Histogram::from_samples(&samples, BinSpec::Auto);
+        assert_eq!(histogram.edges(), &[0.0, 3.5, 7.0]);
+        assert_eq!(histogram.counts(), &[4, 4]);
+
+        // Without an IQR, Auto falls back to Sturges' rule: log2(8) + 1 = 4 bins.
+        let samples = [1.0, 1.0, 1.0, 1.0, 1.0, 1.0, 1.0, 5.0];
+        let histogram = Histogram::from_samples(&samples, BinSpec::Auto);
+        assert_eq!(histogram.edges(), &[1.0, 2.0, 3.0, 4.0, 5.0]);
+        assert_eq!(histogram.counts(), &[7, 0, 0, 1]);
+
+        // Neither empty nor constant samples panic:
+        for bins in [BinSpec::Auto, BinSpec::Count(0), BinSpec::Width(0.0)] {
+            let empty = Histogram::from_samples(&[], bins);
+            assert!(empty.counts().is_empty() && empty.edges().is_empty());
+            assert!(BarChart::from(empty).bars.is_empty());
+
+            let constant = Histogram::from_samples(&[3.0; 5], bins);
+            assert_eq!(constant.edges(), &[2.5, 3.5]);
+            assert_eq!(constant.counts(), &[5]);
+            assert_eq!(constant.density(true).values(), [1.0]);
+        }
+
+        // A far outlier, or a tiny width, don't make millions of bins:
+        let mut samples: Vec<f64> = (0..1000).map(|i| 1.0 + i as f64 * 1e-6).collect();
+        samples.push(1e9);
+        for bins in [
+            BinSpec::Auto,
+            BinSpec::Width(1e-6),
+            BinSpec::Count(usize::MAX),
+        ] {
+            let histogram = Histogram::from_samples(&samples, bins);
+            assert!(histogram.counts().len() <= 10_000, "{bins:?}");
+            assert_eq!(histogram.counts().iter().sum::<usize>(), samples.len());
+            assert_eq!(histogram.counts().last(), Some(&1));
+        }
+    }
+
+    #[test]
+    fn test_quantile() {
+        let values = [1.0, 2.0, 4.0, 8.0];
+        assert_eq!(quantile(&values, 0.0), 1.0);
+        assert_eq!(quantile(&values, 0.5), 3.0);
+        assert_eq!(quantile(&values, 1.0), 8.0);
+        assert_eq!(quantile(&[5.0], 0.25), 5.0);
+    }
+}
@@ -1,9 +1,9 @@
 //! Contains items that can be added to a plot.
 #![allow(clippy::type_complexity)] // TODO(emilk): simplify some of the callback types with type aliases
 
-use std::ops::RangeInclusive;
+use std::{ops::RangeInclusive, sync::Arc};
 
-use epaint::{emath::Rot2, Mesh};
+use epaint::{emath::Rot2, tessellator::Path, Mesh, Tessellator};
 
 use crate::*;
 
@@ -12,14 +12,20 @@ use rect_elem::*;
 
 pub use bar::Bar;
 pub use box_elem::{BoxElem, BoxSpread};
+pub use histogram::{BinSpec, Histogram};
 pub use values::{
-    ClosestElem, LineStyle, MarkerShape, Orientation, PlotGeometry, PlotPoint, PlotPoints,
+    ClosestElem, Colormap, DownsampleMode, ErrorValue, LabelPosition, LineStyle, MarkerShape,
+    Orientation, PlotGeometry, PlotPoint, PlotPoints, YAxis,
 };
+pub use violin::{ViolinElem, ViolinScale};
 
 mod bar;
 mod box_elem;
+mod histogram;
 mod rect_elem;
+mod triangulate;
 mod values;
+mod violin;
 
 const DEFAULT_FILL_ALPHA: f32 = 0.05;
 
@@ -29,6 +35,15 @@ pub struct PlotConfig<'a> {
     pub transform: &'a PlotTransform,
     pub show_x: bool,
     pub show_y: bool,
+
+    /// Category names of a categorical x-axis, see [`crate::Plot::x_axis_categories`].
+    pub x_categories: &'a [String],
+
+    /// Set for a time axis, see [`crate::Plot::x_axis_time`].
+    pub x_time: Option<crate::TimeAxis>,
+
+    /// The Y axis that [`Self::transform`] maps to.
+    pub y_axis: YAxis,
 }
 
 /// Trait shared by things that can be drawn in the plot.
@@ -55,6 +70,31 @@ pub trait PlotItem {
 
     fn id(&self) -> Option<Id>;
 
+    /// The Y axis this item is plotted against.
+    fn y_axis(&self) -> YAxis {
+        YAxis::Primary
+    }
+
+    /// Formats the hover text of this item, overriding [`crate::Plot::label_formatter`].
+    fn hover_formatter(&self) -> Option<&HoverFormatterFn> {
+        None
+    }
+
+    /// A colormap shown as a gradient in the legend, instead of [`Self::color`].
+    fn legend_colormap(&self) -> Option<&Colormap> {
+        None
+    }
+
+    /// The points copied by [`crate::Plot::allow_data_export`].
+    ///
+    /// By default these are the points of [`PlotGeometry::Points`].
+    fn export_points(&self) -> Vec<PlotPoint> {
+        match self.geometry() {
+            PlotGeometry::Points(points) => points.to_vec(),
+            PlotGeometry::None | PlotGeometry::Rects => Vec::new(),
+        }
+    }
+
     fn find_closest(&self, point: Pos2, transform: &PlotTransform) -> Option<ClosestElem> {
         match self.geometry() {
             PlotGeometry::None => None,
@@ -104,6 +144,9 @@ pub trait PlotItem {
         let pointer = plot.transform.position_from_point(&value);
         shapes.push(Shape::circle_filled(pointer, 3.0, line_color));
 
+        let item_formatter: LabelFormatter<'_> = self.hover_formatter().map(|formatter| {
+            Box::new(|_: &str, value: &PlotPoint| formatter(value)) as Box<LabelFormatterFn<'_>>
+        });
         rulers_at_value(
             pointer,
             value,
@@ -111,9 +154,47 @@ pub trait PlotItem {
             plot,
             shapes,
             cursors,
-            label_formatter,
+            if item_formatter.is_some() {
+                &item_formatter
+            } else {
+                label_formatter
+            },
         );
     }
+
+    /// The category of a hovered element, for [`crate::Plot::link_category_hover`].
+    ///
+    /// `None` (the default) for items that have no notion of categories.
+    fn category_index(&self, _elem: &ClosestElem) -> Option<usize> {
+        None
+    }
+
+    /// Can the user drag the points of [`PlotGeometry::Points`]? See [`crate::EditablePoints`].
+    fn points_draggable(&self) -> bool {
+        false
+    }
+
+    /// Move the point with the given index to `to`, because the user dragged it there.
+    ///
+    /// Adds all points that moved to `moved`, and returns the new index of the dragged point.
+    fn drag_point(
+        &mut self,
+        index: usize,
+        _to: PlotPoint,
+        _moved: &mut Vec<(usize, PlotPoint)>,
+    ) -> usize {
+        index
+    }
+
+    /// Paint the element of the given category as hovered,
+    /// because it is hovered in another plot of the same [`crate::Plot::link_category_hover`] group.
+    fn highlight_category(
+        &self,
+        _index: usize,
+        _transform: &PlotTransform,
+        _shapes: &mut Vec<Shape>,
+    ) {
+    }
 }
 
 // ----------------------------------------------------------------------------
@@ -127,6 +208,10 @@ pub struct HLine {
     pub(super) highlight: bool,
     pub(super) allow_hover: bool,
     pub(super) style: LineStyle,
+    pub(super) label: Option<String>,
+    pub(super) label_position: LabelPosition,
+    pub(super) label_font: Option<FontId>,
+    pub(super) label_color: Option<Color32>,
     id: Option<Id>,
 }
 
@@ -139,6 +224,10 @@ impl HLine {
             highlight: false,
             allow_hover: true,
             style: LineStyle::Solid,
+            label: None,
+            label_position: LabelPosition::default(),
+            label_font: None,
+            label_color: None,
             id: None,
         }
     }
@@ -185,6 +274,38 @@ impl HLine {
         self
     }
 
+    /// Caption the line, e.g. with the threshold it marks.
+    #[allow(clippy::needless_pass_by_value)]
+    #[inline]
+    pub fn label(mut self, label: impl ToString) -> Self {
+        self.label = Some(label.to_string());
+        self
+    }
+
+    /// The font of the label. Default is the [`TextStyle::Small`] font.
+    #[inline]
+    pub fn label_font(mut self, font: FontId) -> Self {
+        self.label_font = Some(font);
+        self
+    }
+
+    /// The color of the label. Default is the color of the line.
+    #[inline]
+    pub fn label_color(mut self, color: impl Into<Color32>) -> Self {
+        self.label_color = Some(color.into());
+        self
+    }
+
+    /// Where to place the label. Default is `LabelPosition::LeftAbove`.
+    ///
+    /// If the label doesn't fit into the plot there, e.g. because the line is at the edge of
+    /// the plot, it moves to the other side of the line.
+    #[inline]
+    pub fn label_position(mut self, position: LabelPosition) -> Self {
+        self.label_position = position;
+        self
+    }
+
     /// Name of this horizontal line.
     ///
     /// This name will show up in the plot legend, if legends are turned on.
@@ -217,15 +338,39 @@ impl PlotItem for HLine {
         } = self;
 
         // Round to minimize aliasing:
+        let bounds = transform.data_bounds();
         let points = vec![
             ui.painter().round_pos_to_pixels(
-                transform.position_from_point(&PlotPoint::new(transform.bounds().min[0], *y)),
+                transform.position_from_point(&PlotPoint::new(bounds.min[0], *y)),
             ),
             ui.painter().round_pos_to_pixels(
-                transform.position_from_point(&PlotPoint::new(transform.bounds().max[0], *y)),
+                transform.position_from_point(&PlotPoint::new(bounds.max[0], *y)),
             ),
         ];
+        let line_y = points[0].y;
         style.style_line(points, *stroke, *highlight, shapes);
+
+        if let Some(label) = &self.label {
+            let frame = *transform.frame();
+            if frame.y_range().contains(line_y) {
+                let position = self.label_position;
+                let (x, align_x) = if position.is_left() {
+                    (frame.left() + LINE_LABEL_MARGIN, Align::Min)
+                } else {
+                    (frame.right() - LINE_LABEL_MARGIN, Align::Max)
+                };
+                let above = (pos2(x, line_y - 1.0), Align2([align_x, Align::Max]));
+                let below = (pos2(x, line_y + 1.0), Align2([align_x, Align::Min]));
+                let candidates = if position.is_above() {
+                    [above, below]
+                } else {
+                    [below, above]
+                };
+                let font = self.label_font.clone();
+                let color = self.label_color.unwrap_or(stroke.color);
+                line_label(ui, label, font, color, frame, candidates, shapes);
+            }
+        }
     }
 
     fn initialize(&mut self, _x_range: RangeInclusive<f64>) {}
@@ -275,6 +420,10 @@ pub struct VLine {
     pub(super) highlight: bool,
     pub(super) allow_hover: bool,
     pub(super) style: LineStyle,
+    pub(super) label: Option<String>,
+    pub(super) label_position: LabelPosition,
+    pub(super) label_font: Option<FontId>,
+    pub(super) label_color: Option<Color32>,
     id: Option<Id>,
 }
 
@@ -287,6 +436,10 @@ impl VLine {
             highlight: false,
             allow_hover: true,
             style: LineStyle::Solid,
+            label: None,
+            label_position: LabelPosition::default(),
+            label_font: None,
+            label_color: None,
             id: None,
         }
     }
@@ -333,6 +486,38 @@ impl VLine {
         self
     }
 
+    /// Caption the line, e.g. with the threshold it marks.
+    #[allow(clippy::needless_pass_by_value)]
+    #[inline]
+    pub fn label(mut self, label: impl ToString) -> Self {
+        self.label = Some(label.to_string());
+        self
+    }
+
+    /// The font of the label. Default is the [`TextStyle::Small`] font.
+    #[inline]
+    pub fn label_font(mut self, font: FontId) -> Self {
+        self.label_font = Some(font);
+        self
+    }
+
+    /// The color of the label. Default is the color of the line.
+    #[inline]
+    pub fn label_color(mut self, color: impl Into<Color32>) -> Self {
+        self.label_color = Some(color.into());
+        self
+    }
+
+    /// Where to place the label. Default is `LabelPosition::LeftAbove`.
+    ///
+    /// If the label doesn't fit into the plot there, e.g. because the line is at the edge of
+    /// the plot, it moves to the other side of the line.
+    #[inline]
+    pub fn label_position(mut self, position: LabelPosition) -> Self {
+        self.label_position = position;
+        self
+    }
+
     /// Name of this vertical line.
     ///
     /// This name will show up in the plot legend, if legends are turned on.
@@ -365,15 +550,39 @@ impl PlotItem for VLine {
         } = self;
 
         // Round to minimize aliasing:
+        let bounds = transform.data_bounds();
         let points = vec![
             ui.painter().round_pos_to_pixels(
-                transform.position_from_point(&PlotPoint::new(*x, transform.bounds().min[1])),
+                transform.position_from_point(&PlotPoint::new(*x, bounds.min[1])),
             ),
             ui.painter().round_pos_to_pixels(
-                transform.position_from_point(&PlotPoint::new(*x, transform.bounds().max[1])),
+                transform.position_from_point(&PlotPoint::new(*x, bounds.max[1])),
             ),
         ];
+        let line_x = points[0].x;
         style.style_line(points, *stroke, *highlight, shapes);
+
+        if let Some(label) = &self.label {
+            let frame = *transform.frame();
+            if frame.x_range().contains(line_x) {
+                let position = self.label_position;
+                let (y, align_y) = if position.is_above() {
+                    (frame.top() + LINE_LABEL_MARGIN, Align::Min)
+                } else {
+                    (frame.bottom() - LINE_LABEL_MARGIN, Align::Max)
+                };
+                let left = (pos2(line_x - 1.0, y), Align2([Align::Max, align_y]));
+                let right = (pos2(line_x + 1.0, y), Align2([Align::Min, align_y]));
+                let candidates = if position.is_left() {
+                    [left, right]
+                } else {
+                    [right, left]
+                };
+                let font = self.label_font.clone();
+                let color = self.label_color.unwrap_or(stroke.color);
+                line_label(ui, label, font, color, frame, candidates, shapes);
+            }
+        }
     }
 
     fn initialize(&mut self, _x_range: RangeInclusive<f64>) {}
@@ -414,6 +623,46 @@ impl PlotItem for VLine {
     }
 }
 
+/// Distance of the label of an [`HLine`] or [`VLine`] from the edge of the plot.
+const LINE_LABEL_MARGIN: f32 = 4.0;
+
+/// The label of an [`HLine`] or [`VLine`], on a small chip.
+///
+/// It goes to the first of the `candidates` (position and anchor) where it fits into `frame`.
+/// If it fits nowhere, it is moved into the frame.
+fn line_label(
+    ui: &Ui,
+    label: &str,
+    font: Option<FontId>,
+    color: Color32,
+    frame: Rect,
+    candidates: [(Pos2, Align2); 2],
+    shapes: &mut Vec<Shape>,
+) {
+    let padding = vec2(3.0, 1.0);
+    let font = font.unwrap_or_else(|| TextStyle::Small.resolve(ui.style()));
+    let galley = ui.fonts(|f| f.layout_no_wrap(label.to_owned(), font, color));
+    let size = galley.size() + 2.0 * padding;
+    let rects = candidates.map(|(pos, anchor)| anchor.anchor_size(pos, size));
+    let rect = rects
+        .into_iter()
+        .find(|rect| frame.contains_rect(*rect))
+        .unwrap_or_else(|| {
+            let rect = rects[0];
+            rect.translate(vec2(
+                (frame.left() - rect.left()).max(0.0) - (rect.right() - frame.right()).max(0.0),
+                (frame.top() - rect.top()).max(0.0) - (rect.bottom() - frame.bottom()).max(0.0),
+            ))
+        });
+
+    shapes.push(Shape::rect_filled(
+        rect,
+        2.0,
+        ui.visuals().extreme_bg_color.gamma_multiply(0.8),
+    ));
+    shapes.push(epaint::TextShape::new(rect.min + padding, galley, color).into());
+}
+
 /// A series of values forming a path.
 pub struct Line {
     pub(super) series: PlotPoints,
@@ -423,6 +672,11 @@ pub struct Line {
     pub(super) allow_hover: bool,
     pub(super) fill: Option<f32>,
     pub(super) style: LineStyle,
+    pub(super) downsample: DownsampleMode,
+    pub(super) y_axis: YAxis,
+    pub(super) hover_formatter: Option<Box<HoverFormatterFn>>,
+    pub(super) gradient: Option<(Vec<f64>, Colormap)>,
+    pub(super) gradient_range: Option<RangeInclusive<f64>>,
     id: Option<Id>,
 }
 
@@ -436,6 +690,11 @@ impl Line {
             allow_hover: true,
             fill: None,
             style: LineStyle::Solid,
+            downsample: DownsampleMode::None,
+            y_axis: YAxis::Primary,
+            hover_formatter: None,
+            gradient: None,
+            gradient_range: None,
             id: None,
         }
     }
@@ -489,6 +748,54 @@ impl Line {
         self
     }
 
+    /// Reduce the number of drawn points of long lines. Default is `DownsampleMode::None`.
+    ///
+    /// This is recomputed every frame for the current zoom level.
+    /// Hovering still uses all points.
+    #[inline]
+    pub fn downsample(mut self, mode: DownsampleMode) -> Self {
+        self.downsample = mode;
+        self
+    }
+
+    /// Plot this line against the given Y axis. Default is `YAxis::Primary`.
+    #[inline]
+    pub fn y_axis(mut self, y_axis: YAxis) -> Self {
+        self.y_axis = y_axis;
+        self
+    }
+
+    /// Format the hover text of this line, e.g. to show its unit.
+    ///
+    /// This overrides [`crate::Plot::label_formatter`] for this line.
+    #[inline]
+    pub fn hover_formatter(mut self, formatter: impl Fn(&PlotPoint) -> String + 'static) -> Self {
+        self.hover_formatter = Some(Box::new(formatter));
+        self
+    }
+
+    /// Color the line by a value per point, e.g. the speed along a trajectory.
+    ///
+    /// `values` must have one entry per point. They are normalized to the range of the finite
+    /// values, or to [`Self::gradient_range`], and mapped through the colormap.
+    /// The legend then shows the colormap as a small gradient.
+    ///
+    /// The gradient is always drawn as a solid line, and is not downsampled.
+    #[inline]
+    pub fn gradient_by(mut self, values: Vec<f64>, colormap: Colormap) -> Self {
+        self.gradient = Some((values, colormap));
+        self
+    }
+
+    /// The values mapped to the start and the end of the colormap of [`Self::gradient_by`].
+    ///
+    /// Default is the range of the finite values.
+    #[inline]
+    pub fn gradient_range(mut self, range: RangeInclusive<f64>) -> Self {
+        self.gradient_range = Some(range);
+        self
+    }
+
     /// Name of this line.
     ///
     /// This name will show up in the plot legend, if legends are turned on.
@@ -508,6 +815,78 @@ impl Line {
         self.id = Some(id);
         self
     }
+
+    /// The color of each point, if the line has a gradient.
+    pub(crate) fn gradient_colors(&self) -> Option<Vec<Color32>> {
+        let (values, colormap) = self.gradient.as_ref()?;
+        let n_points = self.series.points().len();
+        debug_assert_eq!(
+            values.len(),
+            n_points,
+            "Line::gradient_by needs one value per point"
+        );
+
+        let range = self.gradient_range.clone().unwrap_or_else(|| {
+            let finite = values.iter().copied().filter(|v| v.is_finite());
+            let min = finite.clone().fold(f64::INFINITY, f64::min);
+            let max = finite.fold(f64::NEG_INFINITY, f64::max);
+            min..=max
+        });
+        let span = range.end() - range.start();
+        let colors = (0..n_points)
+            .map(|i| {
+                let value = values.get(i).copied().unwrap_or(f64::NAN);
+                if value.is_nan() {
+                    Color32::TRANSPARENT
+                } else {
+                    let t = if span > 0.0 {
+                        (value - range.start()) / span
+                    } else {
+                        0.5
+                    };
+                    colormap.color(t as f32)
+                }
+            })
+            .collect();
+        Some(colors)
+    }
+}
+
+/// A line of the given width through `points`, with the color of each point at its vertices.
+///
+/// Every point gets two vertices, offset along the mitered normal, so the colors blend smoothly
+/// along the line.
+pub(crate) fn gradient_line_mesh(points: &[Pos2], colors: &[Color32], width: f32) -> Mesh {
+    let mut mesh = Mesh::default();
+    let n = points.len().min(colors.len());
+    if n < 2 {
+        return mesh;
+    }
+    mesh.reserve_vertices(2 * n);
+    mesh.reserve_triangles(2 * (n - 1));
+
+    let segment_normal = |i: usize| (points[i + 1] - points[i]).normalized().rot90();
+    for i in 0..n {
+        let normal = if i == 0 {
+            segment_normal(0)
+        } else if i == n - 1 {
+            segment_normal(n - 2)
+        } else {
+            let (before, after) = (segment_normal(i - 1), segment_normal(i));
+            let miter = (before + after).normalized();
+            // Keep sharp corners from shooting off into the distance.
+            miter / miter.dot(after).max(0.5)
+        };
+        let offset = normal * (width / 2.0);
+        mesh.colored_vertex(points[i] - offset, colors[i]);
+        mesh.colored_vertex(points[i] + offset, colors[i]);
+        if i > 0 {
+            let v = 2 * i as u32;
+            mesh.add_triangle(v - 2, v - 1, v);
+            mesh.add_triangle(v - 1, v, v + 1);
+        }
+    }
+    mesh
 }
 
 /// Returns the x-coordinate of a possible intersection between a line segment from `p1` to `p2` and
@@ -525,6 +904,7 @@ impl PlotItem for Line {
             highlight,
             mut fill,
             style,
+            downsample,
             ..
         } = self;
 
@@ -533,6 +913,12 @@ impl PlotItem for Line {
             .iter()
             .map(|v| transform.position_from_point(v))
             .collect();
+        let gradient_colors = self.gradient_colors();
+        let values_tf = if gradient_colors.is_some() {
+            values_tf
+        } else {
+            downsample.downsample(values_tf, transform.frame().width())
+        };
         let n_values = values_tf.len();
 
         // Fill the area between the line and a reference line, if required.
@@ -574,7 +960,15 @@ impl PlotItem for Line {
             mesh.colored_vertex(pos2(last.x, y), fill_color);
             shapes.push(Shape::Mesh(mesh));
         }
-        style.style_line(values_tf, *stroke, *highlight, shapes);
+        if let Some(colors) = gradient_colors.filter(|_| values_tf.len() >= 2) {
+            let mut width = stroke.width;
+            if *highlight {
+                width *= 2.0;
+            }
+            shapes.push(Shape::Mesh(gradient_line_mesh(&values_tf, &colors, width)));
+        } else {
+            style.style_line(values_tf, *stroke, *highlight, shapes);
+        }
     }
 
     fn initialize(&mut self, x_range: RangeInclusive<f64>) {
@@ -612,36 +1006,125 @@ impl PlotItem for Line {
     fn id(&self) -> Option<Id> {
         self.id
     }
+
+    fn y_axis(&self) -> YAxis {
+        self.y_axis
+    }
+
+    fn hover_formatter(&self) -> Option<&HoverFormatterFn> {
+        self.hover_formatter.as_deref()
+    }
+
+    fn legend_colormap(&self) -> Option<&Colormap> {
+        self.gradient.as_ref().map(|(_, colormap)| colormap)
+    }
 }
 
-/// A convex polygon.
-pub struct Polygon {
-    pub(super) series: PlotPoints,
+/// A line of streaming data, e.g. telemetry that appends a point every frame.
+///
+/// Unlike [`Line`], the points live in a ring buffer that is kept in your own state and
+/// reused between frames: [`Self::push`] never reallocates once the buffer is full, and
+/// adding the line to a plot with [`crate::PlotUi::line_streaming`] does not copy the points.
+///
+/// Only the points within the visible x range are painted. They are tessellated again every
+/// frame: there is deliberately no cache of the tessellation of an unchanged prefix, since the
+/// bounds usually follow the newest point, so such a cache would rarely be reused.
+///
+/// ```
+/// # use egui_plot::StreamingLine;
+/// let mut line = StreamingLine::new(1000).name("Temperature");
+/// for i in 0..2000 {
+///     line.push(i as f64, (i as f64).sin());
+/// }
+/// assert_eq!(line.len(), 1000);
+/// ```
+#[derive(Clone)]
+pub struct StreamingLine {
+    /// The buffer holds up to `2 * max_len` points; the live ones start at `start`.
+    pub(super) points: Arc<Vec<PlotPoint>>,
+    start: usize,
+    max_len: usize,
     pub(super) stroke: Stroke,
-    pub(super) name: String,
-    pub(super) highlight: bool,
-    pub(super) allow_hover: bool,
-    pub(super) fill_color: Option<Color32>,
-    pub(super) style: LineStyle,
+    name: String,
+    highlight: bool,
+    allow_hover: bool,
+    style: LineStyle,
+    y_axis: YAxis,
     id: Option<Id>,
 }
 
-impl Polygon {
-    pub fn new(series: impl Into<PlotPoints>) -> Self {
+impl StreamingLine {
+    /// A line that keeps the last `max_len` pushed points.
+    pub fn new(max_len: usize) -> Self {
         Self {
-            series: series.into(),
-            stroke: Stroke::new(1.0, Color32::TRANSPARENT),
+            points: Default::default(),
+            start: 0,
+            max_len: max_len.max(1),
+            stroke: Stroke::new(1.5, Color32::TRANSPARENT),
             name: Default::default(),
             highlight: false,
             allow_hover: true,
-            fill_color: None,
             style: LineStyle::Solid,
+            y_axis: YAxis::Primary,
             id: None,
         }
     }
 
-    /// Highlight this polygon in the plot by scaling up the stroke and reducing the fill
-    /// transparency.
+    /// Append a point, evicting the oldest one if there are already `max_len` points.
+    ///
+    /// `x` should not be smaller than the `x` of the last pushed point.
+    pub fn push(&mut self, x: f64, y: f64) {
+        debug_assert!(
+            self.points.last().map_or(true, |last| last.x <= x),
+            "StreamingLine::push expects non-decreasing x"
+        );
+        let max_len = self.max_len;
+        let points = Arc::make_mut(&mut self.points);
+        if points.capacity() < 2 * max_len {
+            points.reserve_exact(2 * max_len - points.len());
+        }
+        if points.len() == 2 * max_len {
+            // Compacting once every `max_len` pushes keeps the amortized cost per push constant,
+            // and never needs more than the reserved capacity.
+            points.drain(..self.start);
+            self.start = 0;
+        }
+        points.push(PlotPoint::new(x, y));
+        if points.len() - self.start > max_len {
+            self.start += 1;
+        }
+    }
+
+    /// Remove all points, keeping the allocation.
+    pub fn clear(&mut self) {
+        Arc::make_mut(&mut self.points).clear();
+        self.start = 0;
+    }
+
+    /// The maximum number of points kept.
+    #[inline]
+    pub fn max_len(&self) -> usize {
+        self.max_len
+    }
+
+    /// The number of points currently kept.
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.points.len() - self.start
+    }
+
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// The points currently kept, oldest first.
+    #[inline]
+    pub fn points(&self) -> &[PlotPoint] {
+        &self.points[self.start..]
+    }
+
+    /// Highlight this line in the plot by scaling up the line.
     #[inline]
     pub fn highlight(mut self, highlight: bool) -> Self {
         self.highlight = highlight;
@@ -655,40 +1138,44 @@ impl Polygon {
         self
     }
 
-    /// Add a custom stroke.
+    /// Add a stroke.
     #[inline]
     pub fn stroke(mut self, stroke: impl Into<Stroke>) -> Self {
         self.stroke = stroke.into();
         self
     }
 
-    /// Set the stroke width.
+    /// Stroke width. A high value means the plot thickens.
     #[inline]
     pub fn width(mut self, width: impl Into<f32>) -> Self {
         self.stroke.width = width.into();
         self
     }
 
-    /// Fill color. Defaults to the stroke color with added transparency.
+    /// Stroke color. Default is `Color32::TRANSPARENT` which means a color will be auto-assigned.
     #[inline]
-    pub fn fill_color(mut self, color: impl Into<Color32>) -> Self {
-        self.fill_color = Some(color.into());
+    pub fn color(mut self, color: impl Into<Color32>) -> Self {
+        self.stroke.color = color.into();
         self
     }
 
-    /// Set the outline's style. Default is `LineStyle::Solid`.
+    /// Set the line's style. Default is `LineStyle::Solid`.
     #[inline]
     pub fn style(mut self, style: LineStyle) -> Self {
         self.style = style;
         self
     }
 
-    /// Name of this polygon.
+    /// Plot this line against the given Y axis. Default is `YAxis::Primary`.
+    #[inline]
+    pub fn y_axis(mut self, y_axis: YAxis) -> Self {
+        self.y_axis = y_axis;
+        self
+    }
+
+    /// Name of this line.
     ///
     /// This name will show up in the plot legend, if legends are turned on.
-    ///
-    /// Multiple plot items may share the same name, in which case they will also share an entry in
-    /// the legend.
     #[allow(clippy::needless_pass_by_value)]
     #[inline]
     pub fn name(mut self, name: impl ToString) -> Self {
@@ -696,7 +1183,7 @@ impl Polygon {
         self
     }
 
-    /// Set the polygon's id which is used to identify it in the plot's response.
+    /// Set the line's id which is used to identify it in the plot's response.
     #[inline]
     pub fn id(mut self, id: Id) -> Self {
         self.id = Some(id);
@@ -704,34 +1191,26 @@ impl Polygon {
     }
 }
 
-impl PlotItem for Polygon {
+impl PlotItem for StreamingLine {
     fn shapes(&self, _ui: &Ui, transform: &PlotTransform, shapes: &mut Vec<Shape>) {
-        let Self {
-            series,
-            stroke,
-            highlight,
-            fill_color,
-            style,
-            ..
-        } = self;
-
-        let mut values_tf: Vec<_> = series
-            .points()
+        let points = self.points();
+        let bounds = transform.data_bounds();
+
+        // The points are sorted by x, so the visible ones are a contiguous range. Keep one point
+        // on either side so the line runs up to the frame.
+        let first = points
+            .partition_point(|p| p.x < bounds.min()[0])
+            .saturating_sub(1);
+        let last = (points.partition_point(|p| p.x <= bounds.max()[0]) + 1).min(points.len());
+        let values_tf: Vec<_> = points[first..last.max(first)]
             .iter()
             .map(|v| transform.position_from_point(v))
             .collect();
-
-        let fill_color = fill_color.unwrap_or(stroke.color.linear_multiply(DEFAULT_FILL_ALPHA));
-
-        let shape = Shape::convex_polygon(values_tf.clone(), fill_color, Stroke::NONE);
-        shapes.push(shape);
-        values_tf.push(*values_tf.first().unwrap());
-        style.style_line(values_tf, *stroke, *highlight, shapes);
+        self.style
+            .style_line(values_tf, self.stroke, self.highlight, shapes);
     }
 
-    fn initialize(&mut self, x_range: RangeInclusive<f64>) {
-        self.series.generate_points(x_range);
-    }
+    fn initialize(&mut self, _x_range: RangeInclusive<f64>) {}
 
     fn name(&self) -> &str {
         self.name.as_str()
@@ -754,74 +1233,108 @@ impl PlotItem for Polygon {
     }
 
     fn geometry(&self) -> PlotGeometry<'_> {
-        PlotGeometry::Points(self.series.points())
+        PlotGeometry::Points(self.points())
     }
 
     fn bounds(&self) -> PlotBounds {
-        self.series.bounds()
+        let mut bounds = PlotBounds::NOTHING;
+        for point in self.points() {
+            bounds.extend_with(point);
+        }
+        bounds
     }
 
     fn id(&self) -> Option<Id> {
         self.id
     }
+
+    fn y_axis(&self) -> YAxis {
+        self.y_axis
+    }
 }
 
-/// Text inside the plot.
-#[derive(Clone)]
-pub struct Text {
-    pub(super) text: WidgetText,
-    pub(super) position: PlotPoint,
+/// A convex polygon.
+pub struct Polygon {
+    pub(super) series: PlotPoints,
+    pub(super) holes: Vec<PlotPoints>,
+    pub(super) stroke: Stroke,
     pub(super) name: String,
     pub(super) highlight: bool,
     pub(super) allow_hover: bool,
-    pub(super) color: Color32,
-    pub(super) anchor: Align2,
+    pub(super) fill_color: Option<Color32>,
+    pub(super) style: LineStyle,
     id: Option<Id>,
 }
 
-impl Text {
-    pub fn new(position: PlotPoint, text: impl Into<WidgetText>) -> Self {
+impl Polygon {
+    pub fn new(series: impl Into<PlotPoints>) -> Self {
         Self {
-            text: text.into(),
-            position,
+            series: series.into(),
+            holes: Vec::new(),
+            stroke: Stroke::new(1.0, Color32::TRANSPARENT),
             name: Default::default(),
             highlight: false,
             allow_hover: true,
-            color: Color32::TRANSPARENT,
-            anchor: Align2::CENTER_CENTER,
+            fill_color: None,
+            style: LineStyle::Solid,
             id: None,
         }
     }
 
-    /// Highlight this text in the plot by drawing a rectangle around it.
+    /// Cut holes into the polygon, e.g. for donut-shaped regions.
+    ///
+    /// The holes must be inside the polygon and must not overlap each other.
+    /// They are outlined with the stroke of the polygon, and don't count for hovering.
     #[inline]
-    pub fn highlight(mut self, highlight: bool) -> Self {
-        self.highlight = highlight;
+    pub fn holes(mut self, holes: Vec<PlotPoints>) -> Self {
+        self.holes = holes;
         self
     }
 
-    /// Allowed hovering this item in the plot. Default: `true`.
+    /// Highlight this polygon in the plot by scaling up the stroke and reducing the fill
+    /// transparency.
+    #[inline]
+    pub fn highlight(mut self, highlight: bool) -> Self {
+        self.highlight = highlight;
+        self
+    }
+
+    /// Allowed hovering this item in the plot. Default: `true`.
     #[inline]
     pub fn allow_hover(mut self, hovering: bool) -> Self {
         self.allow_hover = hovering;
         self
     }
 
-    /// Text color.
+    /// Add a custom stroke.
     #[inline]
-    pub fn color(mut self, color: impl Into<Color32>) -> Self {
-        self.color = color.into();
+    pub fn stroke(mut self, stroke: impl Into<Stroke>) -> Self {
+        self.stroke = stroke.into();
         self
     }
 
-    /// Anchor position of the text. Default is `Align2::CENTER_CENTER`.
+    /// Set the stroke width.
     #[inline]
-    pub fn anchor(mut self, anchor: Align2) -> Self {
-        self.anchor = anchor;
+    pub fn width(mut self, width: impl Into<f32>) -> Self {
+        self.stroke.width = width.into();
         self
     }
 
-    /// Name of this text.
+    /// Fill color. Defaults to the stroke color with added transparency.
+    #[inline]
+    pub fn fill_color(mut self, color: impl Into<Color32>) -> Self {
+        self.fill_color = Some(color.into());
+        self
+    }
+
+    /// Set the outline's style. Default is `LineStyle::Solid`.
+    #[inline]
+    pub fn style(mut self, style: LineStyle) -> Self {
+        self.style = style;
+        self
+    }
+
+    /// Name of this polygon.
     ///
     /// This name will show up in the plot legend, if legends are turned on.
     ///
@@ -834,7 +1347,7 @@ impl Text {
         self
     }
 
-    /// Set the text's id which is used to identify it in the plot's response.
+    /// Set the polygon's id which is used to identify it in the plot's response.
     #[inline]
     pub fn id(mut self, id: Id) -> Self {
         self.id = Some(id);
@@ -842,43 +1355,70 @@ impl Text {
     }
 }
 
-impl PlotItem for Text {
-    fn shapes(&self, ui: &Ui, transform: &PlotTransform, shapes: &mut Vec<Shape>) {
-        let color = if self.color == Color32::TRANSPARENT {
-            ui.style().visuals.text_color()
-        } else {
-            self.color
-        };
+impl PlotItem for Polygon {
+    fn shapes(&self, _ui: &Ui, transform: &PlotTransform, shapes: &mut Vec<Shape>) {
+        let Self {
+            series,
+            holes,
+            stroke,
+            highlight,
+            fill_color,
+            style,
+            ..
+        } = self;
 
-        let galley = self.text.clone().into_galley(
-            ui,
-            Some(egui::TextWrapMode::Extend),
-            f32::INFINITY,
-            TextStyle::Small,
-        );
+        let mut values_tf: Vec<_> = series
+            .points()
+            .iter()
+            .map(|v| transform.position_from_point(v))
+            .collect();
 
-        let pos = transform.position_from_point(&self.position);
-        let rect = self.anchor.anchor_size(pos, galley.size());
+        let fill_color = fill_color.unwrap_or(stroke.color.linear_multiply(DEFAULT_FILL_ALPHA));
 
-        shapes.push(epaint::TextShape::new(rect.min, galley, color).into());
+        if holes.is_empty() {
+            let shape = Shape::convex_polygon(values_tf.clone(), fill_color, Stroke::NONE);
+            shapes.push(shape);
+        } else {
+            let holes_tf: Vec<Vec<Pos2>> = holes
+                .iter()
+                .map(|hole| {
+                    hole.points()
+                        .iter()
+                        .map(|v| transform.position_from_point(v))
+                        .collect()
+                })
+                .collect();
+            let mut mesh = Mesh::default();
+            for &pos in values_tf.iter().chain(holes_tf.iter().flatten()) {
+                mesh.colored_vertex(pos, fill_color);
+            }
+            mesh.indices = triangulate::triangulate(&values_tf, &holes_tf);
+            shapes.push(Shape::mesh(mesh));
 
-        if self.highlight {
-            shapes.push(Shape::rect_stroke(
-                rect.expand(2.0),
-                1.0,
-                Stroke::new(0.5, color),
-            ));
+            for mut hole_tf in holes_tf {
+                if let Some(&first) = hole_tf.first() {
+                    hole_tf.push(first);
+                    style.style_line(hole_tf, *stroke, *highlight, shapes);
+                }
+            }
         }
+        values_tf.push(*values_tf.first().unwrap());
+        style.style_line(values_tf, *stroke, *highlight, shapes);
     }
 
-    fn initialize(&mut self, _x_range: RangeInclusive<f64>) {}
+    fn initialize(&mut self, x_range: RangeInclusive<f64>) {
+        self.series.generate_points(x_range.clone());
+        for hole in &mut self.holes {
+            hole.generate_points(x_range.clone());
+        }
+    }
 
     fn name(&self) -> &str {
         self.name.as_str()
     }
 
     fn color(&self) -> Color32 {
-        self.color
+        self.stroke.color
     }
 
     fn highlight(&mut self) {
@@ -894,13 +1434,38 @@ impl PlotItem for Text {
     }
 
     fn geometry(&self) -> PlotGeometry<'_> {
-        PlotGeometry::None
+        PlotGeometry::Points(self.series.points())
+    }
+
+    fn find_closest(&self, point: Pos2, transform: &PlotTransform) -> Option<ClosestElem> {
+        // Points inside a hole are outside of the polygon.
+        let in_hole = self.holes.iter().any(|hole| {
+            let hole_tf: Vec<Pos2> = hole
+                .points()
+                .iter()
+                .map(|v| transform.position_from_point(v))
+                .collect();
+            triangulate::contains(&hole_tf, point)
+        });
+        if in_hole {
+            return None;
+        }
+
+        self.series
+            .points()
+            .iter()
+            .enumerate()
+            .map(|(index, value)| {
+                let pos = transform.position_from_point(value);
+                let dist_sq = point.distance_sq(pos);
+                ClosestElem { index, dist_sq }
+            })
+            .min_by_key(|e| e.dist_sq.ord())
     }
 
+    /// Only the outer ring, as the holes are inside of it.
     fn bounds(&self) -> PlotBounds {
-        let mut bounds = PlotBounds::NOTHING;
-        bounds.extend_with(&self.position);
-        bounds
+        self.series.bounds()
     }
 
     fn id(&self) -> Option<Id> {
@@ -908,55 +1473,39 @@ impl PlotItem for Text {
     }
 }
 
-/// A set of points.
-pub struct Points {
-    pub(super) series: PlotPoints,
-
-    pub(super) shape: MarkerShape,
-
-    /// Color of the marker. `Color32::TRANSPARENT` means that it will be picked automatically.
+/// A filled band between two curves, e.g. a confidence interval.
+///
+/// The two series may have different x values: the band covers the x range of both, and each
+/// series is linearly interpolated at the x values of the other one.
+pub struct Band {
+    pub(super) upper: PlotPoints,
+    pub(super) lower: PlotPoints,
     pub(super) color: Color32,
-
-    /// Whether to fill the marker. Does not apply to all types.
-    pub(super) filled: bool,
-
-    /// The maximum extent of the marker from its center.
-    pub(super) radius: f32,
-
+    pub(super) fill: Option<Color32>,
+    pub(super) stroke: Option<Stroke>,
     pub(super) name: String,
-
     pub(super) highlight: bool,
-
     pub(super) allow_hover: bool,
-
-    pub(super) stems: Option<f32>,
     id: Option<Id>,
 }
 
-impl Points {
-    pub fn new(series: impl Into<PlotPoints>) -> Self {
+impl Band {
+    /// The x values of both series must be sorted in increasing order.
+    pub fn new(upper: impl Into<PlotPoints>, lower: impl Into<PlotPoints>) -> Self {
         Self {
-            series: series.into(),
-            shape: MarkerShape::Circle,
+            upper: upper.into(),
+            lower: lower.into(),
             color: Color32::TRANSPARENT,
-            filled: true,
-            radius: 1.0,
+            fill: None,
+            stroke: None,
             name: Default::default(),
             highlight: false,
             allow_hover: true,
-            stems: None,
             id: None,
         }
     }
 
-    /// Set the shape of the markers.
-    #[inline]
-    pub fn shape(mut self, shape: MarkerShape) -> Self {
-        self.shape = shape;
-        self
-    }
-
-    /// Highlight these points in the plot by scaling up their markers.
+    /// Highlight this band in the plot by making the fill more opaque.
     #[inline]
     pub fn highlight(mut self, highlight: bool) -> Self {
         self.highlight = highlight;
@@ -970,35 +1519,31 @@ impl Points {
         self
     }
 
-    /// Set the marker's color.
+    /// Color of the boundary strokes and in the legend.
+    /// Default is `Color32::TRANSPARENT` which means a color will be auto-assigned.
     #[inline]
     pub fn color(mut self, color: impl Into<Color32>) -> Self {
         self.color = color.into();
         self
     }
 
-    /// Whether to fill the marker.
-    #[inline]
-    pub fn filled(mut self, filled: bool) -> Self {
-        self.filled = filled;
-        self
-    }
-
-    /// Whether to add stems between the markers and a horizontal reference line.
+    /// Fill color. Defaults to the color with added transparency.
     #[inline]
-    pub fn stems(mut self, y_reference: impl Into<f32>) -> Self {
-        self.stems = Some(y_reference.into());
+    pub fn fill(mut self, fill: impl Into<Color32>) -> Self {
+        self.fill = Some(fill.into());
         self
     }
 
-    /// Set the maximum extent of the marker around its position, in ui points.
+    /// Draw both boundary curves with this stroke. Default: no boundary strokes.
+    ///
+    /// A transparent stroke color is replaced by the color of the band.
     #[inline]
-    pub fn radius(mut self, radius: impl Into<f32>) -> Self {
-        self.radius = radius.into();
+    pub fn stroke(mut self, stroke: impl Into<Stroke>) -> Self {
+        self.stroke = Some(stroke.into());
         self
     }
 
-    /// Name of this set of points.
+    /// Name of this band.
     ///
     /// This name will show up in the plot legend, if legends are turned on.
     ///
@@ -1011,145 +1556,122 @@ impl Points {
         self
     }
 
-    /// Set the points' id which is used to identify them in the plot's response.
+    /// Set the band's id which is used to identify it in the plot's response.
     #[inline]
     pub fn id(mut self, id: Id) -> Self {
         self.id = Some(id);
         self
     }
+
+    /// The band as `[x, upper, lower]`, at the x values of both series within their common
+    /// x range, and at the points where the two curves cross.
+    pub(crate) fn samples(&self) -> Vec<[f64; 3]> {
+        let (upper, lower) = (self.upper.points(), self.lower.points());
+        let (Some(first_upper), Some(first_lower), Some(last_upper), Some(last_lower)) =
+            (upper.first(), lower.first(), upper.last(), lower.last())
+        else {
+            return Vec::new();
+        };
+        let min_x = first_upper.x.max(first_lower.x);
+        let max_x = last_upper.x.min(last_lower.x);
+
+        let mut xs: Vec<f64> = upper
+            .iter()
+            .chain(lower)
+            .map(|point| point.x)
+            .filter(|x| (min_x..=max_x).contains(x))
+            .collect();
+        xs.sort_by(|a, b| a.total_cmp(b));
+        xs.dedup();
+
+        let mut samples: Vec<[f64; 3]> = Vec::with_capacity(xs.len());
+        for x in xs {
+            let sample = [x, interpolate_y(upper, x), interpolate_y(lower, x)];
+            if let Some(&[prev_x, prev_upper, prev_lower]) = samples.last() {
+                let (prev_diff, diff) = (prev_upper - prev_lower, sample[1] - sample[2]);
+                if prev_diff * diff < 0.0 {
+                    // The curves cross between the samples, add the crossing to get a clean mesh.
+                    let t = prev_diff / (prev_diff - diff);
+                    let y = prev_upper + t * (sample[1] - prev_upper);
+                    samples.push([prev_x + t * (x - prev_x), y, y]);
+                }
+            }
+            samples.push(sample);
+        }
+        samples
+    }
 }
 
-impl PlotItem for Points {
-    fn shapes(&self, _ui: &Ui, transform: &PlotTransform, shapes: &mut Vec<Shape>) {
-        let sqrt_3 = 3_f32.sqrt();
-        let frac_sqrt_3_2 = 3_f32.sqrt() / 2.0;
-        let frac_1_sqrt_2 = 1.0 / 2_f32.sqrt();
+/// Linear interpolation of the y value at `x`, for points sorted by x.
+fn interpolate_y(points: &[PlotPoint], x: f64) -> f64 {
+    let i = points.partition_point(|point| point.x < x);
+    match (i.checked_sub(1).map(|i| points[i]), points.get(i)) {
+        (Some(a), Some(b)) if a.x < b.x => a.y + (x - a.x) / (b.x - a.x) * (b.y - a.y),
+        (_, Some(b)) => b.y,
+        (Some(a), None) => a.y,
+        (None, None) => f64::NAN,
+    }
+}
 
+impl PlotItem for Band {
+    fn shapes(&self, _ui: &Ui, transform: &PlotTransform, shapes: &mut Vec<Shape>) {
         let Self {
-            series,
-            shape,
+            upper,
+            lower,
             color,
-            filled,
-            mut radius,
+            fill,
+            stroke,
             highlight,
-            stems,
             ..
         } = self;
 
-        let stroke_size = radius / 5.0;
-
-        let default_stroke = Stroke::new(stroke_size, *color);
-        let mut stem_stroke = default_stroke;
-        let (fill, stroke) = if *filled {
-            (*color, Stroke::NONE)
-        } else {
-            (Color32::TRANSPARENT, default_stroke)
-        };
-
-        if *highlight {
-            radius *= 2f32.sqrt();
-            stem_stroke.width *= 2.0;
-        }
-
-        let y_reference = stems.map(|y| transform.position_from_point(&PlotPoint::new(0.0, y)).y);
-
-        series
-            .points()
-            .iter()
-            .map(|value| transform.position_from_point(value))
-            .for_each(|center| {
-                let tf = |dx: f32, dy: f32| -> Pos2 { center + radius * vec2(dx, dy) };
+        let samples = self.samples();
+        if samples.len() >= 2 {
+            let mut fill = fill.unwrap_or(color.linear_multiply(0.2));
+            if *highlight {
+                fill = fill.gamma_multiply(2.0);
+            }
 
-                if let Some(y) = y_reference {
-                    let stem = Shape::line_segment([center, pos2(center.x, y)], stem_stroke);
-                    shapes.push(stem);
+            // A single strip: each sample adds its upper and lower vertex.
+            let mut mesh = Mesh::default();
+            mesh.reserve_vertices(samples.len() * 2);
+            mesh.reserve_triangles((samples.len() - 1) * 2);
+            for (i, [x, y_upper, y_lower]) in samples.iter().copied().enumerate() {
+                let i = 2 * i as u32;
+                mesh.colored_vertex(
+                    transform.position_from_point(&PlotPoint::new(x, y_upper)),
+                    fill,
+                );
+                mesh.colored_vertex(
+                    transform.position_from_point(&PlotPoint::new(x, y_lower)),
+                    fill,
+                );
+                if i > 0 {
+                    mesh.add_triangle(i - 2, i - 1, i);
+                    mesh.add_triangle(i - 1, i, i + 1);
                 }
+            }
+            shapes.push(Shape::Mesh(mesh));
+        }
 
-                match shape {
-                    MarkerShape::Circle => {
-                        shapes.push(Shape::Circle(epaint::CircleShape {
-                            center,
-                            radius,
-                            fill,
-                            stroke,
-                        }));
-                    }
-                    MarkerShape::Diamond => {
-                        let points = vec![
-                            tf(0.0, 1.0),  // bottom
-                            tf(-1.0, 0.0), // left
-                            tf(0.0, -1.0), // top
-                            tf(1.0, 0.0),  // right
-                        ];
-                        shapes.push(Shape::convex_polygon(points, fill, stroke));
-                    }
-                    MarkerShape::Square => {
-                        let points = vec![
-                            tf(-frac_1_sqrt_2, frac_1_sqrt_2),
-                            tf(-frac_1_sqrt_2, -frac_1_sqrt_2),
-                            tf(frac_1_sqrt_2, -frac_1_sqrt_2),
-                            tf(frac_1_sqrt_2, frac_1_sqrt_2),
-                        ];
-                        shapes.push(Shape::convex_polygon(points, fill, stroke));
-                    }
-                    MarkerShape::Cross => {
-                        let diagonal1 = [
-                            tf(-frac_1_sqrt_2, -frac_1_sqrt_2),
-                            tf(frac_1_sqrt_2, frac_1_sqrt_2),
-                        ];
-                        let diagonal2 = [
-                            tf(frac_1_sqrt_2, -frac_1_sqrt_2),
-                            tf(-frac_1_sqrt_2, frac_1_sqrt_2),
-                        ];
-                        shapes.push(Shape::line_segment(diagonal1, default_stroke));
-                        shapes.push(Shape::line_segment(diagonal2, default_stroke));
-                    }
-                    MarkerShape::Plus => {
-                        let horizontal = [tf(-1.0, 0.0), tf(1.0, 0.0)];
-                        let vertical = [tf(0.0, -1.0), tf(0.0, 1.0)];
-                        shapes.push(Shape::line_segment(horizontal, default_stroke));
-                        shapes.push(Shape::line_segment(vertical, default_stroke));
-                    }
-                    MarkerShape::Up => {
-                        let points =
-                            vec![tf(0.0, -1.0), tf(0.5 * sqrt_3, 0.5), tf(-0.5 * sqrt_3, 0.5)];
-                        shapes.push(Shape::convex_polygon(points, fill, stroke));
-                    }
-                    MarkerShape::Down => {
-                        let points = vec![
-                            tf(0.0, 1.0),
-                            tf(-0.5 * sqrt_3, -0.5),
-                            tf(0.5 * sqrt_3, -0.5),
-                        ];
-                        shapes.push(Shape::convex_polygon(points, fill, stroke));
-                    }
-                    MarkerShape::Left => {
-                        let points =
-                            vec![tf(-1.0, 0.0), tf(0.5, -0.5 * sqrt_3), tf(0.5, 0.5 * sqrt_3)];
-                        shapes.push(Shape::convex_polygon(points, fill, stroke));
-                    }
-                    MarkerShape::Right => {
-                        let points = vec![
-                            tf(1.0, 0.0),
-                            tf(-0.5, 0.5 * sqrt_3),
-                            tf(-0.5, -0.5 * sqrt_3),
-                        ];
-                        shapes.push(Shape::convex_polygon(points, fill, stroke));
-                    }
-                    MarkerShape::Asterisk => {
-                        let vertical = [tf(0.0, -1.0), tf(0.0, 1.0)];
-                        let diagonal1 = [tf(-frac_sqrt_3_2, 0.5), tf(frac_sqrt_3_2, -0.5)];
-                        let diagonal2 = [tf(-frac_sqrt_3_2, -0.5), tf(frac_sqrt_3_2, 0.5)];
-                        shapes.push(Shape::line_segment(vertical, default_stroke));
-                        shapes.push(Shape::line_segment(diagonal1, default_stroke));
-                        shapes.push(Shape::line_segment(diagonal2, default_stroke));
-                    }
-                }
-            });
+        if let Some(mut stroke) = *stroke {
+            if stroke.color == Color32::TRANSPARENT {
+                stroke.color = *color;
+            }
+            for series in [upper, lower] {
+                let line = series
+                    .points()
+                    .iter()
+                    .map(|point| transform.position_from_point(point))
+                    .collect();
+                LineStyle::Solid.style_line(line, stroke, *highlight, shapes);
+            }
+        }
     }
 
     fn initialize(&mut self, x_range: RangeInclusive<f64>) {
-        self.series.generate_points(x_range);
+        self.upper.generate_points(x_range.clone());
+        self.lower.generate_points(x_range);
     }
 
     fn name(&self) -> &str {
@@ -1173,45 +1695,115 @@ impl PlotItem for Points {
     }
 
     fn geometry(&self) -> PlotGeometry<'_> {
-        PlotGeometry::Points(self.series.points())
+        PlotGeometry::Rects
     }
 
     fn bounds(&self) -> PlotBounds {
-        self.series.bounds()
+        let mut bounds = self.upper.bounds();
+        bounds.merge(&self.lower.bounds());
+        bounds
     }
 
     fn id(&self) -> Option<Id> {
         self.id
     }
-}
 
-/// A set of arrows.
-pub struct Arrows {
-    pub(super) origins: PlotPoints,
-    pub(super) tips: PlotPoints,
-    pub(super) tip_length: Option<f32>,
-    pub(super) color: Color32,
-    pub(super) name: String,
-    pub(super) highlight: bool,
-    pub(super) allow_hover: bool,
-    id: Option<Id>,
-}
+    /// The closest sample, measured to the vertical span between the two curves.
+    fn find_closest(&self, point: Pos2, transform: &PlotTransform) -> Option<ClosestElem> {
+        self.samples()
+            .iter()
+            .enumerate()
+            .map(|(index, &[x, y_upper, y_lower])| {
+                let upper = transform.position_from_point(&PlotPoint::new(x, y_upper));
+                let lower = transform.position_from_point(&PlotPoint::new(x, y_lower));
+                let span = Rangef::new(upper.y.min(lower.y), upper.y.max(lower.y));
+                let closest = pos2(upper.x, point.y.clamp(span.min, span.max));
+                ClosestElem {
+                    index,
+                    dist_sq: point.distance_sq(closest),
+                }
+            })
+            .min_by_key(|e| e.dist_sq.ord())
+    }
 
-impl Arrows {
-    pub fn new(origins: impl Into<PlotPoints>, tips: impl Into<PlotPoints>) -> Self {
-        Self {
-            origins: origins.into(),
-            tips: tips.into(),
-            tip_length: None,
-            color: Color32::TRANSPARENT,
+    fn on_hover(
+        &self,
+        elem: ClosestElem,
+        shapes: &mut Vec<Shape>,
+        cursors: &mut Vec<Cursor>,
+        plot: &PlotConfig<'_>,
+        _: &LabelFormatter<'_>,
+    ) {
+        let [x, y_upper, y_lower] = self.samples()[elem.index];
+        let upper = plot
+            .transform
+            .position_from_point(&PlotPoint::new(x, y_upper));
+        let lower = plot
+            .transform
+            .position_from_point(&PlotPoint::new(x, y_lower));
+
+        let line_color = rulers_color(plot.ui);
+        shapes.push(Shape::circle_filled(upper, 3.0, line_color));
+        shapes.push(Shape::circle_filled(lower, 3.0, line_color));
+
+        if plot.show_x {
+            cursors.push(Cursor::Vertical { x });
+        }
+        if plot.show_y {
+            cursors.push(Cursor::Horizontal { y: y_upper });
+            cursors.push(Cursor::Horizontal { y: y_lower });
+        }
+
+        let mut text = String::new();
+        if !self.name.is_empty() {
+            text += &format!("{}\n", self.name);
+        }
+        text += &x_value_text(plot, x);
+        let y_decimals = value_decimals(plot.transform, Axis::Y);
+        text += &format!("\nupper = {y_upper:.y_decimals$}\nlower = {y_lower:.y_decimals$}");
+
+        let font_id = TextStyle::Body.resolve(plot.ui.style());
+        plot.ui.fonts(|f| {
+            shapes.push(Shape::text(
+                f,
+                upper + vec2(3.0, -2.0),
+                Align2::LEFT_BOTTOM,
+                text,
+                font_id,
+                plot.ui.visuals().text_color(),
+            ));
+        });
+    }
+}
+
+/// Text inside the plot.
+#[derive(Clone)]
+pub struct Text {
+    pub(super) text: WidgetText,
+    pub(super) position: PlotPoint,
+    pub(super) name: String,
+    pub(super) highlight: bool,
+    pub(super) allow_hover: bool,
+    pub(super) color: Color32,
+    pub(super) anchor: Align2,
+    id: Option<Id>,
+}
+
+impl Text {
+    pub fn new(position: PlotPoint, text: impl Into<WidgetText>) -> Self {
+        Self {
+            text: text.into(),
+            position,
             name: Default::default(),
             highlight: false,
             allow_hover: true,
+            color: Color32::TRANSPARENT,
+            anchor: Align2::CENTER_CENTER,
             id: None,
         }
     }
 
-    /// Highlight these arrows in the plot.
+    /// Highlight this text in the plot by drawing a rectangle around it.
     #[inline]
     pub fn highlight(mut self, highlight: bool) -> Self {
         self.highlight = highlight;
@@ -1225,21 +1817,21 @@ impl Arrows {
         self
     }
 
-    /// Set the length of the arrow tips
+    /// Text color.
     #[inline]
-    pub fn tip_length(mut self, tip_length: f32) -> Self {
-        self.tip_length = Some(tip_length);
+    pub fn color(mut self, color: impl Into<Color32>) -> Self {
+        self.color = color.into();
         self
     }
 
-    /// Set the arrows' color.
+    /// Anchor position of the text. Default is `Align2::CENTER_CENTER`.
     #[inline]
-    pub fn color(mut self, color: impl Into<Color32>) -> Self {
-        self.color = color.into();
+    pub fn anchor(mut self, anchor: Align2) -> Self {
+        self.anchor = anchor;
         self
     }
 
-    /// Name of this set of arrows.
+    /// Name of this text.
     ///
     /// This name will show up in the plot legend, if legends are turned on.
     ///
@@ -1252,7 +1844,7 @@ impl Arrows {
         self
     }
 
-    /// Set the arrows' id which is used to identify them in the plot's response.
+    /// Set the text's id which is used to identify it in the plot's response.
     #[inline]
     pub fn id(mut self, id: Id) -> Self {
         self.id = Some(id);
@@ -1260,56 +1852,37 @@ impl Arrows {
     }
 }
 
-impl PlotItem for Arrows {
-    fn shapes(&self, _ui: &Ui, transform: &PlotTransform, shapes: &mut Vec<Shape>) {
-        use crate::emath::*;
-        let Self {
-            origins,
-            tips,
-            tip_length,
-            color,
-            highlight,
-            ..
-        } = self;
-        let stroke = Stroke::new(if *highlight { 2.0 } else { 1.0 }, *color);
-        origins
-            .points()
-            .iter()
-            .zip(tips.points().iter())
-            .map(|(origin, tip)| {
-                (
-                    transform.position_from_point(origin),
-                    transform.position_from_point(tip),
-                )
-            })
-            .for_each(|(origin, tip)| {
-                let vector = tip - origin;
-                let rot = Rot2::from_angle(std::f32::consts::TAU / 10.0);
-                let tip_length = if let Some(tip_length) = tip_length {
-                    *tip_length
-                } else {
-                    vector.length() / 4.0
-                };
-                let tip = origin + vector;
-                let dir = vector.normalized();
-                shapes.push(Shape::line_segment([origin, tip], stroke));
-                shapes.push(Shape::line(
-                    vec![
-                        tip - tip_length * (rot.inverse() * dir),
-                        tip,
-                        tip - tip_length * (rot * dir),
-                    ],
-                    stroke,
-                ));
-            });
-    }
+impl PlotItem for Text {
+    fn shapes(&self, ui: &Ui, transform: &PlotTransform, shapes: &mut Vec<Shape>) {
+        let color = if self.color == Color32::TRANSPARENT {
+            ui.style().visuals.text_color()
+        } else {
+            self.color
+        };
 
-    fn initialize(&mut self, _x_range: RangeInclusive<f64>) {
-        self.origins
-            .generate_points(f64::NEG_INFINITY..=f64::INFINITY);
-        self.tips.generate_points(f64::NEG_INFINITY..=f64::INFINITY);
+        let galley = self.text.clone().into_galley(
+            ui,
+            Some(egui::TextWrapMode::Extend),
+            f32::INFINITY,
+            TextStyle::Small,
+        );
+
+        let pos = transform.position_from_point(&self.position);
+        let rect = self.anchor.anchor_size(pos, galley.size());
+
+        shapes.push(epaint::TextShape::new(rect.min, galley, color).into());
+
+        if self.highlight {
+            shapes.push(Shape::rect_stroke(
+                rect.expand(2.0),
+                1.0,
+                Stroke::new(0.5, color),
+            ));
+        }
     }
 
+    fn initialize(&mut self, _x_range: RangeInclusive<f64>) {}
+
     fn name(&self) -> &str {
         self.name.as_str()
     }
@@ -1331,11 +1904,13 @@ impl PlotItem for Arrows {
     }
 
     fn geometry(&self) -> PlotGeometry<'_> {
-        PlotGeometry::Points(self.origins.points())
+        PlotGeometry::None
     }
 
     fn bounds(&self) -> PlotBounds {
-        self.origins.bounds()
+        let mut bounds = PlotBounds::NOTHING;
+        bounds.extend_with(&self.position);
+        bounds
     }
 
     fn id(&self) -> Option<Id> {
@@ -1343,80 +1918,115 @@ impl PlotItem for Arrows {
     }
 }
 
-/// An image in the plot.
+/// What an [`Annotation`] does when its anchor is outside the plot.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum AnnotationClip {
+    /// Don't show the annotation.
+    #[default]
+    Hide,
+
+    /// Keep the text box inside the plot, with the arrow pointing towards the anchor.
+    ClampToEdge,
+}
+
+/// A text box pinned to a point of the plot, e.g. a callout for an event in a time series.
+///
+/// The text box is shown at a fixed offset in ui points from its anchor,
+/// so it stays attached to the anchor when panning and zooming.
 #[derive(Clone)]
-pub struct PlotImage {
-    pub(super) position: PlotPoint,
-    pub(super) texture_id: TextureId,
-    pub(super) uv: Rect,
-    pub(super) size: Vec2,
-    pub(crate) rotation: f64,
-    pub(super) bg_fill: Color32,
-    pub(super) tint: Color32,
+pub struct Annotation {
+    pub(super) text: WidgetText,
+    pub(super) anchor: PlotPoint,
+    pub(super) offset: Vec2,
+    pub(super) arrow: bool,
+    pub(super) fill: Color32,
+    pub(super) color: Color32,
+    pub(super) clip: AnnotationClip,
+    pub(super) include_in_auto_bounds: bool,
+    pub(super) name: String,
     pub(super) highlight: bool,
     pub(super) allow_hover: bool,
-    pub(super) name: String,
     id: Option<Id>,
 }
 
-impl PlotImage {
-    /// Create a new image with position and size in plot coordinates.
-    pub fn new(
-        texture_id: impl Into<TextureId>,
-        center_position: PlotPoint,
-        size: impl Into<Vec2>,
-    ) -> Self {
+impl Annotation {
+    pub fn new(anchor: impl Into<PlotPoint>, text: impl Into<WidgetText>) -> Self {
         Self {
-            position: center_position,
+            text: text.into(),
+            anchor: anchor.into(),
+            offset: vec2(24.0, -24.0),
+            arrow: true,
+            fill: Color32::TRANSPARENT,
+            color: Color32::TRANSPARENT,
+            clip: AnnotationClip::default(),
+            include_in_auto_bounds: false,
             name: Default::default(),
             highlight: false,
             allow_hover: true,
-            texture_id: texture_id.into(),
-            uv: Rect::from_min_max(pos2(0.0, 0.0), pos2(1.0, 1.0)),
-            size: size.into(),
-            rotation: 0.0,
-            bg_fill: Default::default(),
-            tint: Color32::WHITE,
             id: None,
         }
     }
 
-    /// Highlight this image in the plot.
+    /// The offset of the center of the text box from the anchor, in ui points.
+    ///
+    /// Default: `vec2(24.0, -24.0)`, i.e. up and to the right.
     #[inline]
-    pub fn highlight(mut self, highlight: bool) -> Self {
-        self.highlight = highlight;
+    pub fn offset_in_points(mut self, offset: Vec2) -> Self {
+        self.offset = offset;
         self
     }
 
-    /// Allowed hovering this item in the plot. Default: `true`.
+    /// Draw an arrow from the text box to the anchor. Default: `true`.
     #[inline]
-    pub fn allow_hover(mut self, hovering: bool) -> Self {
-        self.allow_hover = hovering;
+    pub fn arrow(mut self, arrow: bool) -> Self {
+        self.arrow = arrow;
         self
     }
 
-    /// Select UV range. Default is (0,0) in top-left, (1,1) bottom right.
+    /// Background of the text box. Default is `Color32::TRANSPARENT`, i.e. no background.
     #[inline]
-    pub fn uv(mut self, uv: impl Into<Rect>) -> Self {
-        self.uv = uv.into();
+    pub fn fill(mut self, fill: impl Into<Color32>) -> Self {
+        self.fill = fill.into();
         self
     }
 
-    /// A solid color to put behind the image. Useful for transparent images.
+    /// Color of the text, the arrow and the frame of the text box.
+    /// Default is `Color32::TRANSPARENT` which means the text color of the ui.
     #[inline]
-    pub fn bg_fill(mut self, bg_fill: impl Into<Color32>) -> Self {
-        self.bg_fill = bg_fill.into();
+    pub fn color(mut self, color: impl Into<Color32>) -> Self {
+        self.color = color.into();
         self
     }
 
-    /// Multiply image color with this. Default is WHITE (no tint).
+    /// What to do when the anchor is outside the plot. Default: [`AnnotationClip::Hide`].
     #[inline]
-    pub fn tint(mut self, tint: impl Into<Color32>) -> Self {
-        self.tint = tint.into();
+    pub fn clip(mut self, clip: AnnotationClip) -> Self {
+        self.clip = clip;
         self
     }
 
-    /// Name of this image.
+    /// Whether the anchor is included in the automatic bounds of the plot. Default: `false`.
+    #[inline]
+    pub fn include_in_auto_bounds(mut self, include: bool) -> Self {
+        self.include_in_auto_bounds = include;
+        self
+    }
+
+    /// Highlight this annotation in the plot by drawing it thicker.
+    #[inline]
+    pub fn highlight(mut self, highlight: bool) -> Self {
+        self.highlight = highlight;
+        self
+    }
+
+    /// Allowed hovering this item in the plot. Default: `true`.
+    #[inline]
+    pub fn allow_hover(mut self, hovering: bool) -> Self {
+        self.allow_hover = hovering;
+        self
+    }
+
+    /// Name of this annotation.
     ///
     /// This name will show up in the plot legend, if legends are turned on.
     ///
@@ -1429,69 +2039,70 @@ impl PlotImage {
         self
     }
 
-    /// Rotate the image counter-clockwise around its center by an angle in radians.
+    /// Set the annotation's id which is used to identify it in the plot's response.
     #[inline]
-    pub fn rotate(mut self, angle: f64) -> Self {
-        self.rotation = angle;
+    pub fn id(mut self, id: Id) -> Self {
+        self.id = Some(id);
         self
     }
 }
 
-impl PlotItem for PlotImage {
+impl PlotItem for Annotation {
     fn shapes(&self, ui: &Ui, transform: &PlotTransform, shapes: &mut Vec<Shape>) {
-        let Self {
-            position,
-            rotation,
-            texture_id,
-            uv,
-            size,
-            bg_fill,
-            tint,
-            highlight,
-            ..
-        } = self;
-        let image_screen_rect = {
-            let left_top = PlotPoint::new(
-                position.x - 0.5 * size.x as f64,
-                position.y - 0.5 * size.y as f64,
-            );
-            let right_bottom = PlotPoint::new(
-                position.x + 0.5 * size.x as f64,
-                position.y + 0.5 * size.y as f64,
-            );
-            let left_top_screen = transform.position_from_point(&left_top);
-            let right_bottom_screen = transform.position_from_point(&right_bottom);
-            Rect::from_two_pos(left_top_screen, right_bottom_screen)
+        const PADDING: f32 = 3.0;
+        const TIP_LENGTH: f32 = 6.0;
+
+        let color = if self.color == Color32::TRANSPARENT {
+            ui.style().visuals.text_color()
+        } else {
+            self.color
         };
-        let screen_rotation = -*rotation as f32;
-
-        egui::paint_texture_at(
-            ui.painter(),
-            image_screen_rect,
-            &ImageOptions {
-                uv: *uv,
-                bg_fill: *bg_fill,
-                tint: *tint,
-                rotation: Some((Rot2::from_angle(screen_rotation), Vec2::splat(0.5))),
-                rounding: Rounding::ZERO,
-            },
-            &(*texture_id, image_screen_rect.size()).into(),
+        let stroke = Stroke::new(if self.highlight { 2.0 } else { 1.0 }, color);
+
+        let frame = *transform.frame();
+        let mut target = transform.position_from_point(&self.anchor);
+        if !frame.contains(target) {
+            match self.clip {
+                AnnotationClip::Hide => return,
+                AnnotationClip::ClampToEdge => target = frame.clamp(target),
+            }
+        }
+
+        let galley = self.text.clone().into_galley(
+            ui,
+            Some(egui::TextWrapMode::Extend),
+            f32::INFINITY,
+            TextStyle::Small,
         );
-        if *highlight {
-            let center = image_screen_rect.center();
-            let rotation = Rot2::from_angle(screen_rotation);
-            let outline = [
-                image_screen_rect.right_bottom(),
-                image_screen_rect.right_top(),
-                image_screen_rect.left_top(),
-                image_screen_rect.left_bottom(),
-            ]
-            .iter()
-            .map(|point| center + rotation * (*point - center))
-            .collect();
-            shapes.push(Shape::closed_line(
-                outline,
-                Stroke::new(1.0, ui.visuals().strong_text_color()),
+        let size = galley.size() + Vec2::splat(2.0 * PADDING);
+        let mut rect = Align2::CENTER_CENTER.anchor_size(target + self.offset, size);
+        if self.clip == AnnotationClip::ClampToEdge {
+            // Move the text box back inside, as far as it fits.
+            let shift_x =
+                (frame.left() - rect.left()).max(0.0) - (rect.right() - frame.right()).max(0.0);
+            let shift_y =
+                (frame.top() - rect.top()).max(0.0) - (rect.bottom() - frame.bottom()).max(0.0);
+            rect = rect.translate(vec2(shift_x, shift_y));
+        }
+
+        if self.fill != Color32::TRANSPARENT {
+            shapes.push(Shape::rect_filled(rect, 2.0, self.fill));
+            shapes.push(Shape::rect_stroke(rect, 2.0, Stroke::new(0.5, color)));
+        }
+        shapes.push(epaint::TextShape::new(rect.min + Vec2::splat(PADDING), galley, color).into());
+
+        if self.arrow && !rect.contains(target) {
+            let origin = rect.clamp(target);
+            let dir = (target - origin).normalized();
+            let rot = Rot2::from_angle(std::f32::consts::TAU / 10.0);
+            shapes.push(Shape::line_segment([origin, target], stroke));
+            shapes.push(Shape::line(
+                vec![
+                    target - TIP_LENGTH * (rot.inverse() * dir),
+                    target,
+                    target - TIP_LENGTH * (rot * dir),
+                ],
+                stroke,
             ));
         }
     }
@@ -1503,7 +2114,7 @@ impl PlotItem for PlotImage {
     }
 
     fn color(&self) -> Color32 {
-        Color32::TRANSPARENT
+        self.color
     }
 
     fn highlight(&mut self) {
@@ -1524,16 +2135,9 @@ impl PlotItem for PlotImage {
 
     fn bounds(&self) -> PlotBounds {
         let mut bounds = PlotBounds::NOTHING;
-        let left_top = PlotPoint::new(
-            self.position.x as f32 - self.size.x / 2.0,
-            self.position.y as f32 - self.size.y / 2.0,
-        );
-        let right_bottom = PlotPoint::new(
-            self.position.x as f32 + self.size.x / 2.0,
-            self.position.y as f32 + self.size.y / 2.0,
-        );
-        bounds.extend_with(&left_top);
-        bounds.extend_with(&right_bottom);
+        if self.include_in_auto_bounds {
+            bounds.extend_with(&self.anchor);
+        }
         bounds
     }
 
@@ -1542,56 +2146,2571 @@ impl PlotItem for PlotImage {
     }
 }
 
-// ----------------------------------------------------------------------------
+/// A set of points.
+pub struct Points {
+    pub(super) series: PlotPoints,
+
+    pub(super) shape: MarkerShape,
+
+    /// Color of the marker. `Color32::TRANSPARENT` means that it will be picked automatically.
+    pub(super) color: Color32,
+
+    /// Whether to fill the marker. Does not apply to all types.
+    pub(super) filled: bool,
+
+    /// The maximum extent of the marker from its center.
+    pub(super) radius: f32,
 
-/// A bar chart.
-pub struct BarChart {
-    pub(super) bars: Vec<Bar>,
-    pub(super) default_color: Color32,
     pub(super) name: String,
 
-    /// A custom element formatter
-    pub(super) element_formatter: Option<Box<dyn Fn(&Bar, &BarChart) -> String>>,
+    pub(super) highlight: bool,
 
-    highlight: bool,
-    allow_hover: bool,
+    pub(super) allow_hover: bool,
+
+    pub(super) stems: Option<f32>,
+
+    /// Per-point colors, overriding `color`. Cycled if shorter than the points.
+    pub(super) colors: Vec<Color32>,
+
+    /// Per-point shapes, overriding `shape`. Cycled if shorter than the points.
+    pub(super) shapes: Vec<MarkerShape>,
+
+    pub(super) y_axis: YAxis,
+    pub(super) hover_formatter: Option<Box<HoverFormatterFn>>,
     id: Option<Id>,
 }
 
-impl BarChart {
-    /// Create a bar chart. It defaults to vertically oriented elements.
-    pub fn new(bars: Vec<Bar>) -> Self {
+impl Points {
+    pub fn new(series: impl Into<PlotPoints>) -> Self {
         Self {
-            bars,
-            default_color: Color32::TRANSPARENT,
-            name: String::new(),
-            element_formatter: None,
-            highlight: false,
-            allow_hover: true,
-            id: None,
-        }
-    }
+            series: series.into(),
+            shape: MarkerShape::Circle,
+            color: Color32::TRANSPARENT,
+            filled: true,
+            radius: 1.0,
+            name: Default::default(),
+            highlight: false,
+            allow_hover: true,
+            stems: None,
+            colors: Vec::new(),
+            shapes: Vec::new(),
+            y_axis: YAxis::Primary,
+            hover_formatter: None,
+            id: None,
+        }
+    }
+
+    /// Set the shape of the markers.
+    #[inline]
+    pub fn shape(mut self, shape: MarkerShape) -> Self {
+        self.shape = shape;
+        self
+    }
+
+    /// Highlight these points in the plot by scaling up their markers.
+    #[inline]
+    pub fn highlight(mut self, highlight: bool) -> Self {
+        self.highlight = highlight;
+        self
+    }
+
+    /// Allowed hovering this item in the plot. Default: `true`.
+    #[inline]
+    pub fn allow_hover(mut self, hovering: bool) -> Self {
+        self.allow_hover = hovering;
+        self
+    }
+
+    /// Set the marker's color.
+    #[inline]
+    pub fn color(mut self, color: impl Into<Color32>) -> Self {
+        self.color = color.into();
+        self
+    }
+
+    /// Set the color of each marker, overriding [`Self::color`].
+    ///
+    /// If there are fewer colors than points, the colors repeat.
+    /// The legend still shows [`Self::color`].
+    #[inline]
+    pub fn colors(mut self, colors: Vec<Color32>) -> Self {
+        self.colors = colors;
+        self
+    }
+
+    /// Set the shape of each marker, overriding [`Self::shape`].
+    ///
+    /// If there are fewer shapes than points, the shapes repeat.
+    #[inline]
+    pub fn shapes(mut self, shapes: Vec<MarkerShape>) -> Self {
+        self.shapes = shapes;
+        self
+    }
+
+    /// Whether to fill the marker.
+    #[inline]
+    pub fn filled(mut self, filled: bool) -> Self {
+        self.filled = filled;
+        self
+    }
+
+    /// Whether to add stems between the markers and a horizontal reference line.
+    #[inline]
+    pub fn stems(mut self, y_reference: impl Into<f32>) -> Self {
+        self.stems = Some(y_reference.into());
+        self
+    }
+
+    /// Set the maximum extent of the marker around its position, in ui points.
+    #[inline]
+    pub fn radius(mut self, radius: impl Into<f32>) -> Self {
+        self.radius = radius.into();
+        self
+    }
+
+    /// Name of this set of points.
+    ///
+    /// This name will show up in the plot legend, if legends are turned on.
+    ///
+    /// Multiple plot items may share the same name, in which case they will also share an entry in
+    /// the legend.
+    #[allow(clippy::needless_pass_by_value)]
+    #[inline]
+    pub fn name(mut self, name: impl ToString) -> Self {
+        self.name = name.to_string();
+        self
+    }
+
+    /// Plot these points against the given Y axis. Default is `YAxis::Primary`.
+    #[inline]
+    pub fn y_axis(mut self, y_axis: YAxis) -> Self {
+        self.y_axis = y_axis;
+        self
+    }
+
+    /// Format the hover text of these points, e.g. to show their unit.
+    ///
+    /// This overrides [`crate::Plot::label_formatter`] for these points.
+    #[inline]
+    pub fn hover_formatter(mut self, formatter: impl Fn(&PlotPoint) -> String + 'static) -> Self {
+        self.hover_formatter = Some(Box::new(formatter));
+        self
+    }
+
+    /// Set the points' id which is used to identify them in the plot's response.
+    #[inline]
+    pub fn id(mut self, id: Id) -> Self {
+        self.id = Some(id);
+        self
+    }
+}
+
+/// A piece of a marker, see [`marker_parts`].
+enum MarkerPart<'a> {
+    Circle(Pos2, f32),
+
+    /// A convex polygon, which is filled or outlined.
+    Polygon(&'a [Pos2]),
+
+    /// A line segment, which is always stroked.
+    Segment([Pos2; 2]),
+}
+
+/// The pieces of a marker of the given shape around `center`, without allocating.
+fn marker_parts(
+    shape: MarkerShape,
+    center: Pos2,
+    radius: f32,
+    mut part: impl FnMut(MarkerPart<'_>),
+) {
+    let sqrt_3 = 3_f32.sqrt();
+    let frac_sqrt_3_2 = 3_f32.sqrt() / 2.0;
+    let frac_1_sqrt_2 = 1.0 / 2_f32.sqrt();
+
+    let tf = |dx: f32, dy: f32| -> Pos2 { center + radius * vec2(dx, dy) };
+
+    match shape {
+        MarkerShape::Circle => part(MarkerPart::Circle(center, radius)),
+        MarkerShape::Diamond => {
+            let points = [
+                tf(0.0, 1.0),  // bottom
+                tf(-1.0, 0.0), // left
+                tf(0.0, -1.0), // top
+                tf(1.0, 0.0),  // right
+            ];
+            part(MarkerPart::Polygon(&points));
+        }
+        MarkerShape::Square => {
+            let points = [
+                tf(-frac_1_sqrt_2, frac_1_sqrt_2),
+                tf(-frac_1_sqrt_2, -frac_1_sqrt_2),
+                tf(frac_1_sqrt_2, -frac_1_sqrt_2),
+                tf(frac_1_sqrt_2, frac_1_sqrt_2),
+            ];
+            part(MarkerPart::Polygon(&points));
+        }
+        MarkerShape::Cross => {
+            part(MarkerPart::Segment([
+                tf(-frac_1_sqrt_2, -frac_1_sqrt_2),
+                tf(frac_1_sqrt_2, frac_1_sqrt_2),
+            ]));
+            part(MarkerPart::Segment([
+                tf(frac_1_sqrt_2, -frac_1_sqrt_2),
+                tf(-frac_1_sqrt_2, frac_1_sqrt_2),
+            ]));
+        }
+        MarkerShape::Plus => {
+            part(MarkerPart::Segment([tf(-1.0, 0.0), tf(1.0, 0.0)]));
+            part(MarkerPart::Segment([tf(0.0, -1.0), tf(0.0, 1.0)]));
+        }
+        MarkerShape::Up => {
+            let points = [tf(0.0, -1.0), tf(0.5 * sqrt_3, 0.5), tf(-0.5 * sqrt_3, 0.5)];
+            part(MarkerPart::Polygon(&points));
+        }
+        MarkerShape::Down => {
+            let points = [
+                tf(0.0, 1.0),
+                tf(-0.5 * sqrt_3, -0.5),
+                tf(0.5 * sqrt_3, -0.5),
+            ];
+            part(MarkerPart::Polygon(&points));
+        }
+        MarkerShape::Left => {
+            let points = [tf(-1.0, 0.0), tf(0.5, -0.5 * sqrt_3), tf(0.5, 0.5 * sqrt_3)];
+            part(MarkerPart::Polygon(&points));
+        }
+        MarkerShape::Right => {
+            let points = [
+                tf(1.0, 0.0),
+                tf(-0.5, 0.5 * sqrt_3),
+                tf(-0.5, -0.5 * sqrt_3),
+            ];
+            part(MarkerPart::Polygon(&points));
+        }
+        MarkerShape::Asterisk => {
+            part(MarkerPart::Segment([tf(0.0, -1.0), tf(0.0, 1.0)]));
+            part(MarkerPart::Segment([
+                tf(-frac_sqrt_3_2, 0.5),
+                tf(frac_sqrt_3_2, -0.5),
+            ]));
+            part(MarkerPart::Segment([
+                tf(-frac_sqrt_3_2, -0.5),
+                tf(frac_sqrt_3_2, 0.5),
+            ]));
+        }
+    }
+}
+
+impl Points {
+    /// The color and shape of the marker of the point with the given index.
+    fn style_at(&self, index: usize) -> (Color32, MarkerShape) {
+        let color = if self.colors.is_empty() {
+            self.color
+        } else {
+            self.colors[index % self.colors.len()]
+        };
+        let shape = if self.shapes.is_empty() {
+            self.shape
+        } else {
+            self.shapes[index % self.shapes.len()]
+        };
+        (color, shape)
+    }
+
+    /// Tessellate the markers with their own colors and shapes into a single mesh.
+    fn styled_mesh(
+        &self,
+        ui: &Ui,
+        transform: &PlotTransform,
+        radius: f32,
+        stem_width: f32,
+    ) -> Mesh {
+        let n_points = self.series.points().len();
+        debug_assert!(
+            self.colors.len() <= n_points && self.shapes.len() <= n_points,
+            "Points::colors and Points::shapes must not be longer than the points"
+        );
+
+        let mut tessellator = MarkerTessellator::new(ui);
+
+        let y_reference = self
+            .stems
+            .map(|y| transform.position_from_point(&PlotPoint::new(0.0, y)).y);
+
+        let mut mesh = Mesh::default();
+        for (index, value) in self.series.points().iter().enumerate() {
+            let center = transform.position_from_point(value);
+            let (color, shape) = self.style_at(index);
+            let line_stroke = Stroke::new(radius / 5.0, color);
+            let (fill, stroke) = if self.filled {
+                (color, Stroke::NONE)
+            } else {
+                (Color32::TRANSPARENT, line_stroke)
+            };
+
+            if let Some(y) = y_reference {
+                let stem_stroke = Stroke::new(stem_width, color);
+                tessellator.line([center, pos2(center.x, y)], stem_stroke, &mut mesh);
+            }
+
+            let marker = MarkerStyle {
+                fill,
+                stroke,
+                line_stroke,
+            };
+            tessellator.marker(shape, center, radius, marker, &mut mesh);
+        }
+        mesh
+    }
+}
+
+/// How [`MarkerTessellator::marker`] paints the parts of a marker.
+#[derive(Clone, Copy)]
+struct MarkerStyle {
+    fill: Color32,
+
+    /// The outline of circles and polygons.
+    stroke: Stroke,
+
+    /// The stroke of segments, which are never filled.
+    line_stroke: Stroke,
+}
+
+/// Tessellates many markers into one mesh, with the settings of the ui.
+struct MarkerTessellator {
+    tessellator: Tessellator,
+
+    /// Scratch space for polygons.
+    path: Path,
+
+    /// In ui points.
+    feathering: f32,
+}
+
+impl MarkerTessellator {
+    fn new(ui: &Ui) -> Self {
+        let pixels_per_point = ui.ctx().pixels_per_point();
+        let options = ui.ctx().tessellation_options(|options| *options);
+        let feathering = if options.feathering {
+            options.feathering_size_in_pixels / pixels_per_point
+        } else {
+            0.0
+        };
+        let font_tex_size = ui.fonts(|f| f.font_image_size());
+        Self {
+            tessellator: Tessellator::new(pixels_per_point, options, font_tex_size, vec![]),
+            path: Path::default(),
+            feathering,
+        }
+    }
+
+    fn line(&mut self, points: [Pos2; 2], stroke: Stroke, mesh: &mut Mesh) {
+        self.tessellator.tessellate_line(points, stroke, mesh);
+    }
+
+    fn marker(
+        &mut self,
+        shape: MarkerShape,
+        center: Pos2,
+        radius: f32,
+        style: MarkerStyle,
+        mesh: &mut Mesh,
+    ) {
+        let Self {
+            tessellator,
+            path,
+            feathering,
+        } = self;
+        let MarkerStyle {
+            fill,
+            stroke,
+            line_stroke,
+        } = style;
+        marker_parts(shape, center, radius, |part| match part {
+            MarkerPart::Circle(center, radius) => {
+                let circle = epaint::CircleShape {
+                    center,
+                    radius,
+                    fill,
+                    stroke,
+                };
+                tessellator.tessellate_circle(circle, mesh);
+            }
+            MarkerPart::Polygon(points) => {
+                path.clear();
+                path.add_line_loop(points);
+                path.fill(*feathering, fill, mesh);
+                path.stroke_closed(*feathering, &stroke.into(), mesh);
+            }
+            MarkerPart::Segment(points) => {
+                tessellator.tessellate_line(points, line_stroke, mesh);
+            }
+        });
+    }
+}
+
+impl PlotItem for Points {
+    fn shapes(&self, ui: &Ui, transform: &PlotTransform, shapes: &mut Vec<Shape>) {
+        let Self {
+            series,
+            shape,
+            color,
+            filled,
+            mut radius,
+            highlight,
+            stems,
+            ..
+        } = self;
+
+        let stroke_size = radius / 5.0;
+
+        let default_stroke = Stroke::new(stroke_size, *color);
+        let mut stem_stroke = default_stroke;
+        let (fill, stroke) = if *filled {
+            (*color, Stroke::NONE)
+        } else {
+            (Color32::TRANSPARENT, default_stroke)
+        };
+
+        if *highlight {
+            radius *= 2f32.sqrt();
+            stem_stroke.width *= 2.0;
+        }
+
+        if !self.colors.is_empty() || !self.shapes.is_empty() {
+            shapes.push(Shape::Mesh(self.styled_mesh(
+                ui,
+                transform,
+                radius,
+                stem_stroke.width,
+            )));
+            return;
+        }
+
+        let y_reference = stems.map(|y| transform.position_from_point(&PlotPoint::new(0.0, y)).y);
+
+        series
+            .points()
+            .iter()
+            .map(|value| transform.position_from_point(value))
+            .for_each(|center| {
+                if let Some(y) = y_reference {
+                    let stem = Shape::line_segment([center, pos2(center.x, y)], stem_stroke);
+                    shapes.push(stem);
+                }
+
+                marker_parts(*shape, center, radius, |part| match part {
+                    MarkerPart::Circle(center, radius) => {
+                        shapes.push(Shape::Circle(epaint::CircleShape {
+                            center,
+                            radius,
+                            fill,
+                            stroke,
+                        }));
+                    }
+                    MarkerPart::Polygon(points) => {
+                        shapes.push(Shape::convex_polygon(points.to_vec(), fill, stroke));
+                    }
+                    MarkerPart::Segment(points) => {
+                        shapes.push(Shape::line_segment(points, default_stroke));
+                    }
+                });
+            });
+    }
+
+    fn initialize(&mut self, x_range: RangeInclusive<f64>) {
+        self.series.generate_points(x_range);
+    }
+
+    fn name(&self) -> &str {
+        self.name.as_str()
+    }
+
+    fn color(&self) -> Color32 {
+        self.color
+    }
+
+    fn highlight(&mut self) {
+        self.highlight = true;
+    }
+
+    fn highlighted(&self) -> bool {
+        self.highlight
+    }
+
+    fn allow_hover(&self) -> bool {
+        self.allow_hover
+    }
+
+    fn geometry(&self) -> PlotGeometry<'_> {
+        PlotGeometry::Points(self.series.points())
+    }
+
+    fn bounds(&self) -> PlotBounds {
+        self.series.bounds()
+    }
+
+    fn id(&self) -> Option<Id> {
+        self.id
+    }
+
+    fn y_axis(&self) -> YAxis {
+        self.y_axis
+    }
+
+    fn hover_formatter(&self) -> Option<&HoverFormatterFn> {
+        self.hover_formatter.as_deref()
+    }
+}
+
+/// A stem plot: a vertical line from a baseline to each point, topped with a marker.
+///
+/// Useful for discrete-time signals. All stems are drawn as a single mesh, so this stays
+/// fast for many thousands of points.
+pub struct Stems {
+    pub(super) series: PlotPoints,
+    pub(super) baseline: f64,
+    pub(super) stroke: Stroke,
+    pub(super) shape: MarkerShape,
+    pub(super) radius: f32,
+    pub(super) filled: bool,
+    pub(super) name: String,
+    pub(super) highlight: bool,
+    pub(super) allow_hover: bool,
+    pub(super) y_axis: YAxis,
+    pub(super) hover_formatter: Option<Box<HoverFormatterFn>>,
+    id: Option<Id>,
+}
+
+impl Stems {
+    pub fn new(series: impl Into<PlotPoints>) -> Self {
+        Self {
+            series: series.into(),
+            baseline: 0.0,
+            stroke: Stroke::new(1.0, Color32::TRANSPARENT),
+            shape: MarkerShape::Circle,
+            radius: 3.0,
+            filled: true,
+            name: Default::default(),
+            highlight: false,
+            allow_hover: true,
+            y_axis: YAxis::Primary,
+            hover_formatter: None,
+            id: None,
+        }
+    }
+
+    /// The y value the stems start from. Default: `0.0`.
+    #[inline]
+    pub fn baseline(mut self, baseline: f64) -> Self {
+        self.baseline = baseline;
+        self
+    }
+
+    /// Set the stroke of the stems. Its color is also the color of the markers.
+    #[inline]
+    pub fn stroke(mut self, stroke: impl Into<Stroke>) -> Self {
+        self.stroke = stroke.into();
+        self
+    }
+
+    /// Stroke width of the stems.
+    #[inline]
+    pub fn width(mut self, width: impl Into<f32>) -> Self {
+        self.stroke.width = width.into();
+        self
+    }
+
+    /// Color of the stems and markers.
+    /// Default is `Color32::TRANSPARENT` which means a color will be auto-assigned.
+    #[inline]
+    pub fn color(mut self, color: impl Into<Color32>) -> Self {
+        self.stroke.color = color.into();
+        self
+    }
+
+    /// Set the shape of the markers. Default: `MarkerShape::Circle`.
+    #[inline]
+    pub fn shape(mut self, shape: MarkerShape) -> Self {
+        self.shape = shape;
+        self
+    }
+
+    /// Set the maximum extent of the markers around their position, in ui points.
+    ///
+    /// Zero hides the markers. Default: `3.0`.
+    #[inline]
+    pub fn radius(mut self, radius: impl Into<f32>) -> Self {
+        self.radius = radius.into();
+        self
+    }
+
+    /// Whether to fill the markers. Default: `true`.
+    #[inline]
+    pub fn filled(mut self, filled: bool) -> Self {
+        self.filled = filled;
+        self
+    }
+
+    /// Highlight the stems in the plot by scaling up their markers and strokes.
+    #[inline]
+    pub fn highlight(mut self, highlight: bool) -> Self {
+        self.highlight = highlight;
+        self
+    }
+
+    /// Allowed hovering this item in the plot. Default: `true`.
+    #[inline]
+    pub fn allow_hover(mut self, hovering: bool) -> Self {
+        self.allow_hover = hovering;
+        self
+    }
+
+    /// Name of the stems.
+    ///
+    /// This name will show up in the plot legend, if legends are turned on.
+    ///
+    /// Multiple plot items may share the same name, in which case they will also share an entry in
+    /// the legend.
+    #[allow(clippy::needless_pass_by_value)]
+    #[inline]
+    pub fn name(mut self, name: impl ToString) -> Self {
+        self.name = name.to_string();
+        self
+    }
+
+    /// Plot the stems against the given Y axis. Default is `YAxis::Primary`.
+    #[inline]
+    pub fn y_axis(mut self, y_axis: YAxis) -> Self {
+        self.y_axis = y_axis;
+        self
+    }
+
+    /// Format the hover text of the stems, e.g. to show their unit.
+    ///
+    /// This overrides [`crate::Plot::label_formatter`] for these stems.
+    #[inline]
+    pub fn hover_formatter(mut self, formatter: impl Fn(&PlotPoint) -> String + 'static) -> Self {
+        self.hover_formatter = Some(Box::new(formatter));
+        self
+    }
+
+    /// Set the stems' id which is used to identify them in the plot's response.
+    #[inline]
+    pub fn id(mut self, id: Id) -> Self {
+        self.id = Some(id);
+        self
+    }
+}
+
+impl PlotItem for Stems {
+    fn shapes(&self, ui: &Ui, transform: &PlotTransform, shapes: &mut Vec<Shape>) {
+        let mut stroke = self.stroke;
+        let mut radius = self.radius;
+        if self.highlight {
+            radius *= 2f32.sqrt();
+            stroke.width *= 2.0;
+        }
+        let line_stroke = Stroke::new(radius / 5.0, stroke.color);
+        let marker = if self.filled {
+            MarkerStyle {
+                fill: stroke.color,
+                stroke: Stroke::NONE,
+                line_stroke,
+            }
+        } else {
+            MarkerStyle {
+                fill: Color32::TRANSPARENT,
+                stroke: line_stroke,
+                line_stroke,
+            }
+        };
+
+        let baseline = transform.position_from_point_y(self.baseline);
+        let mut tessellator = MarkerTessellator::new(ui);
+        let mut mesh = Mesh::default();
+        for value in self.series.points() {
+            let top = transform.position_from_point(value);
+            tessellator.line([pos2(top.x, baseline), top], stroke, &mut mesh);
+            if radius > 0.0 {
+                tessellator.marker(self.shape, top, radius, marker, &mut mesh);
+            }
+        }
+        shapes.push(Shape::Mesh(mesh));
+    }
+
+    fn initialize(&mut self, x_range: RangeInclusive<f64>) {
+        self.series.generate_points(x_range);
+    }
+
+    fn name(&self) -> &str {
+        self.name.as_str()
+    }
+
+    fn color(&self) -> Color32 {
+        self.stroke.color
+    }
+
+    fn highlight(&mut self) {
+        self.highlight = true;
+    }
+
+    fn highlighted(&self) -> bool {
+        self.highlight
+    }
+
+    fn allow_hover(&self) -> bool {
+        self.allow_hover
+    }
+
+    fn geometry(&self) -> PlotGeometry<'_> {
+        PlotGeometry::Points(self.series.points())
+    }
+
+    fn bounds(&self) -> PlotBounds {
+        let mut bounds = self.series.bounds();
+        if bounds.is_finite_y() {
+            bounds.extend_with_y(self.baseline);
+        }
+        bounds
+    }
+
+    fn id(&self) -> Option<Id> {
+        self.id
+    }
+
+    fn y_axis(&self) -> YAxis {
+        self.y_axis
+    }
+
+    fn hover_formatter(&self) -> Option<&HoverFormatterFn> {
+        self.hover_formatter.as_deref()
+    }
+}
+
+/// Points which the user can drag with the mouse, e.g. for editing an envelope or a lookup table.
+///
+/// Dragging a point doesn't change your data: apply [`crate::PlotResponse::moved_points`]
+/// to it, so that the next frame shows the points where they were dragged to.
+/// Give each editable item in a plot a unique name or [`Self::id`].
+///
+/// ```
+/// # egui::__run_test_ui(|ui| {
+/// use egui_plot::{EditablePoints, Plot};
+///
+/// let mut envelope = vec![[0.0, 0.0], [0.1, 1.0], [0.5, 0.6], [1.0, 0.0]];
+/// let response = Plot::new("envelope").show(ui, |plot_ui| {
+///     plot_ui.editable_points(EditablePoints::new(envelope.clone()).name("envelope").keep_sorted(true));
+/// });
+/// for (_, index, point) in response.moved_points {
+///     envelope[index] = [point.x, point.y];
+/// }
+/// # });
+/// ```
+pub struct EditablePoints {
+    pub(super) points: Vec<PlotPoint>,
+    pub(super) color: Color32,
+    pub(super) shape: MarkerShape,
+    pub(super) radius: f32,
+    pub(super) name: String,
+    pub(super) highlight: bool,
+    pub(super) allow_hover: bool,
+    pub(super) lock_x: bool,
+    pub(super) clamp: Option<PlotBounds>,
+    pub(super) keep_sorted: bool,
+    id: Option<Id>,
+}
+
+impl EditablePoints {
+    pub fn new(points: impl Into<PlotPoints>) -> Self {
+        Self {
+            points: points.into().points().to_vec(),
+            color: Color32::TRANSPARENT,
+            shape: MarkerShape::Circle,
+            radius: 4.0,
+            name: Default::default(),
+            highlight: false,
+            allow_hover: true,
+            lock_x: false,
+            clamp: None,
+            keep_sorted: false,
+            id: None,
+        }
+    }
+
+    /// Set the marker's color.
+    /// Default is `Color32::TRANSPARENT` which means a color will be auto-assigned.
+    #[inline]
+    pub fn color(mut self, color: impl Into<Color32>) -> Self {
+        self.color = color.into();
+        self
+    }
+
+    /// Set the shape of the markers. Default: `MarkerShape::Circle`.
+    #[inline]
+    pub fn shape(mut self, shape: MarkerShape) -> Self {
+        self.shape = shape;
+        self
+    }
+
+    /// Set the maximum extent of the markers around their position, in ui points. Default: `4.0`.
+    #[inline]
+    pub fn radius(mut self, radius: impl Into<f32>) -> Self {
+        self.radius = radius.into();
+        self
+    }
+
+    /// Only allow dragging the points up and down. Default: `false`.
+    #[inline]
+    pub fn lock_x(mut self, lock_x: bool) -> Self {
+        self.lock_x = lock_x;
+        self
+    }
+
+    /// Keep the dragged points within these bounds.
+    #[inline]
+    pub fn clamp_to(mut self, bounds: PlotBounds) -> Self {
+        self.clamp = Some(bounds);
+        self
+    }
+
+    /// Keep the points sorted by x: a point dragged past its neighbor swaps places with it.
+    ///
+    /// The points must be sorted to begin with. Default: `false`.
+    #[inline]
+    pub fn keep_sorted(mut self, keep_sorted: bool) -> Self {
+        self.keep_sorted = keep_sorted;
+        self
+    }
+
+    /// Highlight these points in the plot by scaling up their markers.
+    #[inline]
+    pub fn highlight(mut self, highlight: bool) -> Self {
+        self.highlight = highlight;
+        self
+    }
+
+    /// Allowed hovering this item in the plot. Default: `true`.
+    ///
+    /// The points can be dragged either way.
+    #[inline]
+    pub fn allow_hover(mut self, hovering: bool) -> Self {
+        self.allow_hover = hovering;
+        self
+    }
+
+    /// Name of this set of points.
+    ///
+    /// This name will show up in the plot legend, if legends are turned on.
+    #[allow(clippy::needless_pass_by_value)]
+    #[inline]
+    pub fn name(mut self, name: impl ToString) -> Self {
+        self.name = name.to_string();
+        self
+    }
+
+    /// Set the points' id which is used to identify them in the plot's response.
+    #[inline]
+    pub fn id(mut self, id: Id) -> Self {
+        self.id = Some(id);
+        self
+    }
+}
+
+impl PlotItem for EditablePoints {
+    fn shapes(&self, ui: &Ui, transform: &PlotTransform, shapes: &mut Vec<Shape>) {
+        let mut radius = self.radius;
+        if self.highlight {
+            radius *= 2f32.sqrt();
+        }
+        let marker = MarkerStyle {
+            fill: self.color,
+            stroke: Stroke::NONE,
+            line_stroke: Stroke::new(radius / 5.0, self.color),
+        };
+        let mut tessellator = MarkerTessellator::new(ui);
+        let mut mesh = Mesh::default();
+        for point in &self.points {
+            let center = transform.position_from_point(point);
+            tessellator.marker(self.shape, center, radius, marker, &mut mesh);
+        }
+        shapes.push(Shape::Mesh(mesh));
+    }
+
+    fn initialize(&mut self, _x_range: RangeInclusive<f64>) {}
+
+    fn name(&self) -> &str {
+        self.name.as_str()
+    }
+
+    fn color(&self) -> Color32 {
+        self.color
+    }
+
+    fn highlight(&mut self) {
+        self.highlight = true;
+    }
+
+    fn highlighted(&self) -> bool {
+        self.highlight
+    }
+
+    fn allow_hover(&self) -> bool {
+        self.allow_hover
+    }
+
+    fn geometry(&self) -> PlotGeometry<'_> {
+        PlotGeometry::Points(&self.points)
+    }
+
+    fn bounds(&self) -> PlotBounds {
+        let mut bounds = PlotBounds::NOTHING;
+        for point in &self.points {
+            bounds.extend_with(point);
+        }
+        bounds
+    }
+
+    fn id(&self) -> Option<Id> {
+        self.id
+    }
+
+    fn points_draggable(&self) -> bool {
+        true
+    }
+
+    fn drag_point(
+        &mut self,
+        index: usize,
+        mut to: PlotPoint,
+        moved: &mut Vec<(usize, PlotPoint)>,
+    ) -> usize {
+        let Some(from) = self.points.get(index).copied() else {
+            return index;
+        };
+        if self.lock_x {
+            to.x = from.x;
+        }
+        if let Some(bounds) = &self.clamp {
+            to.x = to.x.clamp(bounds.min[0], bounds.max[0]);
+            to.y = to.y.clamp(bounds.min[1], bounds.max[1]);
+        }
+        self.points[index] = to;
+
+        let mut new_index = index;
+        if self.keep_sorted {
+            while new_index > 0 && self.points[new_index - 1].x > to.x {
+                self.points.swap(new_index - 1, new_index);
+                new_index -= 1;
+            }
+            while new_index + 1 < self.points.len() && self.points[new_index + 1].x < to.x {
+                self.points.swap(new_index, new_index + 1);
+                new_index += 1;
+            }
+        }
+
+        let changed = index.min(new_index)..=index.max(new_index);
+        moved.extend(changed.map(|i| (i, self.points[i])));
+        new_index
+    }
+}
+
+/// Error bars around a series of values, e.g. for scatter plots of measurements.
+pub struct ErrorBars {
+    pub(super) series: PlotPoints,
+    pub(super) y_errors: Vec<ErrorValue>,
+    pub(super) x_errors: Vec<ErrorValue>,
+    pub(super) stroke: Stroke,
+    pub(super) cap_width: f32,
+    pub(super) name: String,
+
+    /// Take the color of the series with the same name, see [`Self::attach_to`].
+    pub(super) attached: bool,
+    pub(super) highlight: bool,
+    pub(super) allow_hover: bool,
+    id: Option<Id>,
+}
+
+impl ErrorBars {
+    /// Vertical error bars, with one error per point.
+    ///
+    /// Errors are either symmetric (`0.5`) or asymmetric (`(low, high)`), see [`ErrorValue`].
+    pub fn new(
+        series: impl Into<PlotPoints>,
+        y_errors: impl IntoIterator<Item = impl Into<ErrorValue>>,
+    ) -> Self {
+        Self {
+            series: series.into(),
+            y_errors: y_errors.into_iter().map(Into::into).collect(),
+            x_errors: Vec::new(),
+            stroke: Stroke::new(1.0, Color32::TRANSPARENT),
+            cap_width: 6.0,
+            name: Default::default(),
+            attached: false,
+            highlight: false,
+            allow_hover: true,
+            id: None,
+        }
+    }
+
+    /// Also show horizontal error bars, with one error per point.
+    #[inline]
+    pub fn x_errors(mut self, x_errors: impl IntoIterator<Item = impl Into<ErrorValue>>) -> Self {
+        self.x_errors = x_errors.into_iter().map(Into::into).collect();
+        self
+    }
+
+    /// Highlight these error bars in the plot by scaling up the stroke.
+    #[inline]
+    pub fn highlight(mut self, highlight: bool) -> Self {
+        self.highlight = highlight;
+        self
+    }
+
+    /// Allowed hovering this item in the plot. Default: `true`.
+    #[inline]
+    pub fn allow_hover(mut self, hovering: bool) -> Self {
+        self.allow_hover = hovering;
+        self
+    }
+
+    /// Add a stroke.
+    #[inline]
+    pub fn stroke(mut self, stroke: impl Into<Stroke>) -> Self {
+        self.stroke = stroke.into();
+        self
+    }
+
+    /// Stroke width.
+    #[inline]
+    pub fn width(mut self, width: impl Into<f32>) -> Self {
+        self.stroke.width = width.into();
+        self
+    }
+
+    /// Stroke color. Default is `Color32::TRANSPARENT` which means a color will be auto-assigned.
+    #[inline]
+    pub fn color(mut self, color: impl Into<Color32>) -> Self {
+        self.stroke.color = color.into();
+        self
+    }
+
+    /// Width of the caps at the ends of the bars, in points. Default: `6.0`.
+    #[inline]
+    pub fn cap_width(mut self, cap_width: f32) -> Self {
+        self.cap_width = cap_width;
+        self
+    }
+
+    /// Name of these error bars.
+    ///
+    /// This name will show up in the plot legend, if legends are turned on.
+    ///
+    /// Multiple plot items may share the same name, in which case they will also share an entry in
+    /// the legend.
+    #[allow(clippy::needless_pass_by_value)]
+    #[inline]
+    pub fn name(mut self, name: impl ToString) -> Self {
+        self.name = name.to_string();
+        self
+    }
+
+    /// Attach the error bars to the series with the given name, which must be added to the plot
+    /// before the error bars.
+    ///
+    /// They share its legend entry, and take its color unless [`Self::color`] is set.
+    #[allow(clippy::needless_pass_by_value)]
+    #[inline]
+    pub fn attach_to(mut self, series_name: impl ToString) -> Self {
+        self.name = series_name.to_string();
+        self.attached = true;
+        self
+    }
+
+    /// Set the error bars' id which is used to identify them in the plot's response.
+    #[inline]
+    pub fn id(mut self, id: Id) -> Self {
+        self.id = Some(id);
+        self
+    }
+
+    /// The line segments of the bars and their caps, in screen coordinates.
+    pub(crate) fn segments(&self, transform: &PlotTransform) -> Vec<[Pos2; 2]> {
+        let half_cap = self.cap_width / 2.0;
+        let mut segments = Vec::new();
+        for (i, value) in self.series.points().iter().enumerate() {
+            if let Some(error) = self.y_errors.get(i) {
+                let (low, high) = error.extents();
+                let bottom = transform.position_from_point(&PlotPoint::new(value.x, value.y - low));
+                let top = transform.position_from_point(&PlotPoint::new(value.x, value.y + high));
+                segments.push([bottom, top]);
+                for end in [bottom, top] {
+                    segments.push([end - vec2(half_cap, 0.0), end + vec2(half_cap, 0.0)]);
+                }
+            }
+            if let Some(error) = self.x_errors.get(i) {
+                let (low, high) = error.extents();
+                let left = transform.position_from_point(&PlotPoint::new(value.x - low, value.y));
+                let right = transform.position_from_point(&PlotPoint::new(value.x + high, value.y));
+                segments.push([left, right]);
+                for end in [left, right] {
+                    segments.push([end - vec2(0.0, half_cap), end + vec2(0.0, half_cap)]);
+                }
+            }
+        }
+        segments
+    }
+}
+
+impl PlotItem for ErrorBars {
+    fn shapes(&self, _ui: &Ui, transform: &PlotTransform, shapes: &mut Vec<Shape>) {
+        let mut stroke = self.stroke;
+        if self.highlight {
+            stroke.width *= 2.0;
+        }
+        shapes.extend(
+            self.segments(transform)
+                .into_iter()
+                .map(|segment| Shape::line_segment(segment, stroke)),
+        );
+    }
+
+    fn initialize(&mut self, x_range: RangeInclusive<f64>) {
+        self.series.generate_points(x_range);
+    }
+
+    fn name(&self) -> &str {
+        self.name.as_str()
+    }
+
+    fn color(&self) -> Color32 {
+        self.stroke.color
+    }
+
+    fn highlight(&mut self) {
+        self.highlight = true;
+    }
+
+    fn highlighted(&self) -> bool {
+        self.highlight
+    }
+
+    fn allow_hover(&self) -> bool {
+        self.allow_hover
+    }
+
+    fn geometry(&self) -> PlotGeometry<'_> {
+        PlotGeometry::Points(self.series.points())
+    }
+
+    fn bounds(&self) -> PlotBounds {
+        let mut bounds = PlotBounds::NOTHING;
+        for (i, value) in self.series.points().iter().enumerate() {
+            let (down, up) = self.y_errors.get(i).map_or((0.0, 0.0), |e| e.extents());
+            let (left, right) = self.x_errors.get(i).map_or((0.0, 0.0), |e| e.extents());
+            bounds.extend_with(&PlotPoint::new(value.x - left, value.y - down));
+            bounds.extend_with(&PlotPoint::new(value.x + right, value.y + up));
+        }
+        bounds
+    }
+
+    fn id(&self) -> Option<Id> {
+        self.id
+    }
+
+    fn on_hover(
+        &self,
+        elem: ClosestElem,
+        shapes: &mut Vec<Shape>,
+        cursors: &mut Vec<Cursor>,
+        plot: &PlotConfig<'_>,
+        _: &LabelFormatter<'_>,
+    ) {
+        let value = self.series.points()[elem.index];
+        let pointer = plot.transform.position_from_point(&value);
+        shapes.push(Shape::circle_filled(pointer, 3.0, rulers_color(plot.ui)));
+
+        if plot.show_x {
+            cursors.push(Cursor::Vertical { x: value.x });
+        }
+        if plot.show_y {
+            cursors.push(Cursor::Horizontal { y: value.y });
+        }
+
+        let format_error = |value: f64, error: Option<&ErrorValue>, decimals: usize| match error {
+            None => format!("{value:.decimals$}"),
+            Some(ErrorValue::Symmetric(error)) => {
+                format!("{value:.decimals$} ± {error:.decimals$}")
+            }
+            Some(ErrorValue::Asymmetric { low, high }) => {
+                format!("{value:.decimals$} +{high:.decimals$} −{low:.decimals$}")
+            }
+        };
+
+        let mut text = String::new();
+        if !self.name.is_empty() {
+            text += &format!("{}\n", self.name);
+        }
+        let x_error = self.x_errors.get(elem.index);
+        if x_error.is_some() && plot.x_categories.is_empty() && plot.x_time.is_none() {
+            let x_decimals = value_decimals(plot.transform, Axis::X);
+            text += &format!("x = {}", format_error(value.x, x_error, x_decimals));
+        } else {
+            text += &x_value_text(plot, value.x);
+        }
+        let y_decimals = value_decimals(plot.transform, Axis::Y);
+        let y_error = self.y_errors.get(elem.index);
+        text += &format!("\ny = {}", format_error(value.y, y_error, y_decimals));
+
+        let font_id = TextStyle::Body.resolve(plot.ui.style());
+        plot.ui.fonts(|f| {
+            shapes.push(Shape::text(
+                f,
+                pointer + vec2(3.0, -2.0),
+                Align2::LEFT_BOTTOM,
+                text,
+                font_id,
+                plot.ui.visuals().text_color(),
+            ));
+        });
+    }
+}
+
+/// A set of arrows, e.g. a vector field.
+pub struct Arrows {
+    pub(super) origins: PlotPoints,
+    pub(super) tips: PlotPoints,
+    pub(super) head_size: Option<f32>,
+    pub(super) scale: f64,
+    pub(super) normalize: bool,
+    pub(super) magnitude_colors: Option<(Colormap, Option<RangeInclusive<f64>>)>,
+    pub(super) color: Color32,
+    pub(super) name: String,
+    pub(super) highlight: bool,
+    pub(super) allow_hover: bool,
+    id: Option<Id>,
+}
+
+impl Arrows {
+    pub fn new(origins: impl Into<PlotPoints>, tips: impl Into<PlotPoints>) -> Self {
+        Self {
+            origins: origins.into(),
+            tips: tips.into(),
+            head_size: None,
+            scale: 1.0,
+            normalize: false,
+            magnitude_colors: None,
+            color: Color32::TRANSPARENT,
+            name: Default::default(),
+            highlight: false,
+            allow_hover: true,
+            id: None,
+        }
+    }
+
+    /// Highlight these arrows in the plot.
+    #[inline]
+    pub fn highlight(mut self, highlight: bool) -> Self {
+        self.highlight = highlight;
+        self
+    }
+
+    /// Allowed hovering this item in the plot. Default: `true`.
+    #[inline]
+    pub fn allow_hover(mut self, hovering: bool) -> Self {
+        self.allow_hover = hovering;
+        self
+    }
+
+    /// Set the length of the arrow tips
+    #[inline]
+    #[deprecated = "Use `head_size_points` instead"]
+    pub fn tip_length(self, tip_length: f32) -> Self {
+        self.head_size_points(tip_length)
+    }
+
+    /// Set the length of the arrow heads in ui points, so they stay readable when zooming out.
+    ///
+    /// By default the heads are a quarter of the length of their arrow.
+    #[inline]
+    pub fn head_size_points(mut self, head_size: f32) -> Self {
+        self.head_size = Some(head_size);
+        self
+    }
+
+    /// Scale the arrows from their origin, e.g. to fit the vectors of a field between its grid points.
+    /// Default: `1.0`.
+    #[inline]
+    pub fn scale(mut self, scale: f64) -> Self {
+        self.scale = scale;
+        self
+    }
+
+    /// Make all arrows the same length, so they only show the direction.
+    ///
+    /// The length is then [`Self::scale`] in plot coordinates. Default: `false`.
+    #[inline]
+    pub fn normalize(mut self, normalize: bool) -> Self {
+        self.normalize = normalize;
+        self
+    }
+
+    /// Color each arrow by its magnitude, i.e. the distance from its origin to its tip.
+    ///
+    /// The magnitudes in `range` are mapped to the colormap; by default this is
+    /// the range from the smallest to the largest magnitude.
+    #[inline]
+    pub fn color_by_magnitude(
+        mut self,
+        colormap: Colormap,
+        range: Option<RangeInclusive<f64>>,
+    ) -> Self {
+        self.magnitude_colors = Some((colormap, range));
+        self
+    }
+
+    /// Set the arrows' color.
+    #[inline]
+    pub fn color(mut self, color: impl Into<Color32>) -> Self {
+        self.color = color.into();
+        self
+    }
+
+    /// Name of this set of arrows.
+    ///
+    /// This name will show up in the plot legend, if legends are turned on.
+    ///
+    /// Multiple plot items may share the same name, in which case they will also share an entry in
+    /// the legend.
+    #[allow(clippy::needless_pass_by_value)]
+    #[inline]
+    pub fn name(mut self, name: impl ToString) -> Self {
+        self.name = name.to_string();
+        self
+    }
+
+    /// Set the arrows' id which is used to identify them in the plot's response.
+    #[inline]
+    pub fn id(mut self, id: Id) -> Self {
+        self.id = Some(id);
+        self
+    }
+}
+
+impl PlotItem for Arrows {
+    fn shapes(&self, _ui: &Ui, transform: &PlotTransform, shapes: &mut Vec<Shape>) {
+        let rot = Rot2::from_angle(std::f32::consts::TAU / 10.0);
+        let width = if self.highlight { 2.0 } else { 1.0 };
+        let vectors: Vec<(PlotPoint, [f64; 2])> = self
+            .origins
+            .points()
+            .iter()
+            .zip(self.tips.points())
+            .map(|(origin, tip)| (*origin, [tip.x - origin.x, tip.y - origin.y]))
+            .collect();
+        let magnitudes: Vec<f64> = vectors.iter().map(|(_, [dx, dy])| dx.hypot(*dy)).collect();
+        let color_range = self.magnitude_colors.as_ref().map(|(_, range)| {
+            range.as_ref().map_or_else(
+                || {
+                    let min = magnitudes.iter().copied().fold(f64::INFINITY, f64::min);
+                    let max = magnitudes.iter().copied().fold(f64::NEG_INFINITY, f64::max);
+                    (min, max)
+                },
+                |range| (*range.start(), *range.end()),
+            )
+        });
+
+        for ((origin, [dx, dy]), magnitude) in vectors.into_iter().zip(magnitudes) {
+            let scale = if self.normalize {
+                if magnitude == 0.0 {
+                    continue;
+                }
+                self.scale / magnitude
+            } else {
+                self.scale
+            };
+            let color = match (&self.magnitude_colors, color_range) {
+                (Some((colormap, _)), Some((min, max))) => {
+                    let t = if max > min {
+                        (magnitude - min) / (max - min)
+                    } else {
+                        0.5
+                    };
+                    colormap.color(t as f32)
+                }
+                _ => self.color,
+            };
+            let stroke = Stroke::new(width, color);
+
+            let tip = PlotPoint::new(origin.x + scale * dx, origin.y + scale * dy);
+            let origin = transform.position_from_point(&origin);
+            let tip = transform.position_from_point(&tip);
+            let vector = tip - origin;
+            let head_size = self.head_size.unwrap_or(vector.length() / 4.0);
+            let dir = vector.normalized();
+            shapes.push(Shape::line_segment([origin, tip], stroke));
+            shapes.push(Shape::line(
+                vec![
+                    tip - head_size * (rot.inverse() * dir),
+                    tip,
+                    tip - head_size * (rot * dir),
+                ],
+                stroke,
+            ));
+        }
+    }
+
+    fn initialize(&mut self, _x_range: RangeInclusive<f64>) {
+        self.origins
+            .generate_points(f64::NEG_INFINITY..=f64::INFINITY);
+        self.tips.generate_points(f64::NEG_INFINITY..=f64::INFINITY);
+    }
+
+    fn name(&self) -> &str {
+        self.name.as_str()
+    }
+
+    fn color(&self) -> Color32 {
+        self.color
+    }
+
+    fn highlight(&mut self) {
+        self.highlight = true;
+    }
+
+    fn highlighted(&self) -> bool {
+        self.highlight
+    }
+
+    fn allow_hover(&self) -> bool {
+        self.allow_hover
+    }
+
+    fn geometry(&self) -> PlotGeometry<'_> {
+        PlotGeometry::Points(self.origins.points())
+    }
+
+    fn legend_colormap(&self) -> Option<&Colormap> {
+        self.magnitude_colors.as_ref().map(|(colormap, _)| colormap)
+    }
+
+    fn bounds(&self) -> PlotBounds {
+        self.origins.bounds()
+    }
+
+    fn id(&self) -> Option<Id> {
+        self.id
+    }
+}
+
+/// An image in the plot.
+#[derive(Clone)]
+pub struct PlotImage {
+    pub(super) position: PlotPoint,
+    pub(super) texture_id: TextureId,
+    pub(super) uv: Rect,
+    pub(super) size: Vec2,
+    pub(super) rotation: f64,
+    pub(super) bg_fill: Color32,
+    pub(super) tint: Color32,
+    pub(super) highlight: bool,
+    pub(super) allow_hover: bool,
+    pub(super) name: String,
+    id: Option<Id>,
+}
+
+impl PlotImage {
+    /// Create a new image with position and size in plot coordinates.
+    pub fn new(
+        texture_id: impl Into<TextureId>,
+        center_position: PlotPoint,
+        size: impl Into<Vec2>,
+    ) -> Self {
+        Self {
+            position: center_position,
+            name: Default::default(),
+            highlight: false,
+            allow_hover: true,
+            texture_id: texture_id.into(),
+            uv: Rect::from_min_max(pos2(0.0, 0.0), pos2(1.0, 1.0)),
+            size: size.into(),
+            rotation: 0.0,
+            bg_fill: Default::default(),
+            tint: Color32::WHITE,
+            id: None,
+        }
+    }
+
+    /// Highlight this image in the plot.
+    #[inline]
+    pub fn highlight(mut self, highlight: bool) -> Self {
+        self.highlight = highlight;
+        self
+    }
+
+    /// Allowed hovering this item in the plot. Default: `true`.
+    #[inline]
+    pub fn allow_hover(mut self, hovering: bool) -> Self {
+        self.allow_hover = hovering;
+        self
+    }
+
+    /// Select UV range. Default is (0,0) in top-left, (1,1) bottom right.
+    ///
+    /// Crop the image with a smaller range, or flip it by swapping `min` and `max`,
+    /// e.g. `Rect { min: pos2(1.0, 0.0), max: pos2(0.0, 1.0) }` mirrors it horizontally.
+    #[inline]
+    pub fn uv(mut self, uv: impl Into<Rect>) -> Self {
+        self.uv = uv.into();
+        self
+    }
+
+    /// A solid color to put behind the image. Useful for transparent images.
+    #[inline]
+    pub fn bg_fill(mut self, bg_fill: impl Into<Color32>) -> Self {
+        self.bg_fill = bg_fill.into();
+        self
+    }
+
+    /// Multiply image color with this. Default is WHITE (no tint).
+    #[inline]
+    pub fn tint(mut self, tint: impl Into<Color32>) -> Self {
+        self.tint = tint.into();
+        self
+    }
+
+    /// Name of this image.
+    ///
+    /// This name will show up in the plot legend, if legends are turned on.
+    ///
+    /// Multiple plot items may share the same name, in which case they will also share an entry in
+    /// the legend.
+    #[allow(clippy::needless_pass_by_value)]
+    #[inline]
+    pub fn name(mut self, name: impl ToString) -> Self {
+        self.name = name.to_string();
+        self
+    }
+
+    /// Rotate the image counter-clockwise around its center by an angle in radians.
+    ///
+    /// The image is rotated in plot coordinates, so it is sheared if the axes have different scales.
+    #[inline]
+    pub fn rotate(mut self, angle: f64) -> Self {
+        self.rotation = angle;
+        self
+    }
+
+    /// The corners of the rotated image in plot coordinates,
+    /// starting with the one at the top left of the texture and going clockwise.
+    fn corners(&self) -> [PlotPoint; 4] {
+        let (sin, cos) = self.rotation.sin_cos();
+        let (half_width, half_height) = (self.size.x as f64 / 2.0, self.size.y as f64 / 2.0);
+        [
+            [-half_width, half_height],
+            [half_width, half_height],
+            [half_width, -half_height],
+            [-half_width, -half_height],
+        ]
+        .map(|[dx, dy]| {
+            PlotPoint::new(
+                self.position.x + dx * cos - dy * sin,
+                self.position.y + dx * sin + dy * cos,
+            )
+        })
+    }
+}
+
+impl PlotItem for PlotImage {
+    fn shapes(&self, ui: &Ui, transform: &PlotTransform, shapes: &mut Vec<Shape>) {
+        let corners = self
+            .corners()
+            .map(|corner| transform.position_from_point(&corner));
+        if self.bg_fill != Color32::TRANSPARENT {
+            shapes.push(Shape::convex_polygon(
+                corners.to_vec(),
+                self.bg_fill,
+                Stroke::NONE,
+            ));
+        }
+
+        let uv = self.uv;
+        let mut mesh = Mesh::with_texture(self.texture_id);
+        for (pos, uv) in corners.into_iter().zip([
+            uv.left_top(),
+            uv.right_top(),
+            uv.right_bottom(),
+            uv.left_bottom(),
+        ]) {
+            mesh.vertices.push(epaint::Vertex {
+                pos,
+                uv,
+                color: self.tint,
+            });
+        }
+        mesh.add_triangle(0, 1, 2);
+        mesh.add_triangle(0, 2, 3);
+        shapes.push(Shape::Mesh(mesh));
+
+        if self.highlight {
+            shapes.push(Shape::closed_line(
+                corners.to_vec(),
+                Stroke::new(1.0, ui.visuals().strong_text_color()),
+            ));
+        }
+    }
+
+    fn initialize(&mut self, _x_range: RangeInclusive<f64>) {}
+
+    fn name(&self) -> &str {
+        self.name.as_str()
+    }
+
+    fn color(&self) -> Color32 {
+        Color32::TRANSPARENT
+    }
+
+    fn highlight(&mut self) {
+        self.highlight = true;
+    }
+
+    fn highlighted(&self) -> bool {
+        self.highlight
+    }
+
+    fn allow_hover(&self) -> bool {
+        self.allow_hover
+    }
+
+    fn geometry(&self) -> PlotGeometry<'_> {
+        PlotGeometry::None
+    }
+
+    /// The bounds of the rotated image.
+    fn bounds(&self) -> PlotBounds {
+        let mut bounds = PlotBounds::NOTHING;
+        for corner in &self.corners() {
+            bounds.extend_with(corner);
+        }
+        bounds
+    }
+
+    /// Hovering anywhere on the rotated image counts as hovering the image.
+    fn find_closest(&self, point: Pos2, transform: &PlotTransform) -> Option<ClosestElem> {
+        let corners = self
+            .corners()
+            .map(|corner| transform.position_from_point(&corner));
+        let edges = [0, 1, 2, 3].map(|i| (corners[i], corners[(i + 1) % 4]));
+        // Inside a convex quad the point is on the same side of all edges.
+        let sides = edges.map(|(a, b)| (b - a).x * (point - a).y - (b - a).y * (point - a).x);
+        let inside = sides.iter().all(|side| *side >= 0.0) || sides.iter().all(|side| *side <= 0.0);
+        let dist_sq = if inside {
+            0.0
+        } else {
+            edges
+                .iter()
+                .map(|&(a, b)| {
+                    let t = ((point - a).dot(b - a) / (b - a).length_sq().max(f32::EPSILON))
+                        .clamp(0.0, 1.0);
+                    point.distance_sq(a + t * (b - a))
+                })
+                .fold(f32::INFINITY, f32::min)
+        };
+        Some(ClosestElem { index: 0, dist_sq })
+    }
+
+    fn on_hover(
+        &self,
+        _elem: ClosestElem,
+        shapes: &mut Vec<Shape>,
+        _cursors: &mut Vec<Cursor>,
+        plot: &PlotConfig<'_>,
+        _: &LabelFormatter<'_>,
+    ) {
+        let corners = self
+            .corners()
+            .map(|corner| plot.transform.position_from_point(&corner));
+        shapes.push(Shape::closed_line(
+            corners.to_vec(),
+            Stroke::new(1.0, plot.ui.visuals().strong_text_color()),
+        ));
+    }
+
+    fn id(&self) -> Option<Id> {
+        self.id
+    }
+}
+
+// ----------------------------------------------------------------------------
+
+/// A grid of values shown as colored cells, e.g. a spectrogram.
+///
+/// The grid is uploaded as a texture, which is only updated when the values, the colormap
+/// or the normalization change.
+pub struct HeatMap {
+    pub(super) x_range: RangeInclusive<f64>,
+    pub(super) y_range: RangeInclusive<f64>,
+    pub(super) ncols: usize,
+    pub(super) nrows: usize,
+    pub(super) values: Vec<f64>,
+    pub(super) colormap: Colormap,
+    pub(super) value_range: Option<RangeInclusive<f64>>,
+    pub(super) name: String,
+    pub(super) highlight: bool,
+    pub(super) allow_hover: bool,
+    id: Option<Id>,
+}
+
+impl HeatMap {
+    /// `values` holds `nrows` rows of `ncols` values each. The first row is at the bottom,
+    /// at the start of `y_range`.
+    ///
+    /// Missing values are treated as NaN, which is drawn transparent.
+    pub fn new(
+        x_range: RangeInclusive<f64>,
+        y_range: RangeInclusive<f64>,
+        ncols: usize,
+        nrows: usize,
+        mut values: Vec<f64>,
+    ) -> Self {
+        values.resize(ncols * nrows, f64::NAN);
+        Self {
+            x_range,
+            y_range,
+            ncols,
+            nrows,
+            values,
+            colormap: Colormap::default(),
+            value_range: None,
+            name: Default::default(),
+            highlight: false,
+            allow_hover: true,
+            id: None,
+        }
+    }
+
+    /// Set the colormap. Default is `Colormap::Viridis`.
+    #[inline]
+    pub fn colormap(mut self, colormap: Colormap) -> Self {
+        self.colormap = colormap;
+        self
+    }
+
+    /// The values mapped to the start and the end of the colormap.
+    ///
+    /// By default, the smallest and the largest finite value are used.
+    #[inline]
+    pub fn value_range(mut self, range: RangeInclusive<f64>) -> Self {
+        self.value_range = Some(range);
+        self
+    }
+
+    /// Highlight this heat map in the plot by drawing an outline.
+    #[inline]
+    pub fn highlight(mut self, highlight: bool) -> Self {
+        self.highlight = highlight;
+        self
+    }
+
+    /// Allowed hovering this item in the plot. Default: `true`.
+    #[inline]
+    pub fn allow_hover(mut self, hovering: bool) -> Self {
+        self.allow_hover = hovering;
+        self
+    }
+
+    /// Name of this heat map.
+    ///
+    /// This name will show up in the plot legend, if legends are turned on.
+    ///
+    /// Multiple plot items may share the same name, in which case they will also share an entry in
+    /// the legend.
+    #[allow(clippy::needless_pass_by_value)]
+    #[inline]
+    pub fn name(mut self, name: impl ToString) -> Self {
+        self.name = name.to_string();
+        self
+    }
+
+    /// Set the heat map's id which is used to identify it in the plot's response.
+    ///
+    /// It is also used to cache the texture, so several heat maps in one plot need different ids
+    /// or names.
+    #[inline]
+    pub fn id(mut self, id: Id) -> Self {
+        self.id = Some(id);
+        self
+    }
+
+    /// The `(column, row)` of the cell containing `value`.
+    pub(crate) fn cell_at(&self, value: PlotPoint) -> Option<(usize, usize)> {
+        let cell = |range: &RangeInclusive<f64>, count: usize, v: f64| {
+            let t = (v - range.start()) / (range.end() - range.start());
+            (0.0..=1.0)
+                .contains(&t)
+                .then(|| ((t * count as f64) as usize).min(count.saturating_sub(1)))
+        };
+        if self.ncols == 0 || self.nrows == 0 {
+            return None;
+        }
+        Some((
+            cell(&self.x_range, self.ncols, value.x)?,
+            cell(&self.y_range, self.nrows, value.y)?,
+        ))
+    }
+
+    fn value(&self, col: usize, row: usize) -> f64 {
+        self.values[row * self.ncols + col]
+    }
+
+    fn normalization(&self) -> RangeInclusive<f64> {
+        self.value_range.clone().unwrap_or_else(|| {
+            let finite = self.values.iter().copied().filter(|v| v.is_finite());
+            let min = finite.clone().fold(f64::INFINITY, f64::min);
+            let max = finite.fold(f64::NEG_INFINITY, f64::max);
+            min..=max
+        })
+    }
+
+    /// The grid colored through the colormap, with the top row first.
+    pub(crate) fn color_image(&self) -> ColorImage {
+        let range = self.normalization();
+        let span = range.end() - range.start();
+        let pixels = (0..self.nrows)
+            .rev()
+            .flat_map(|row| (0..self.ncols).map(move |col| (col, row)))
+            .map(|(col, row)| {
+                let value = self.value(col, row);
+                if value.is_nan() {
+                    Color32::TRANSPARENT
+                } else {
+                    let t = if span > 0.0 {
+                        (value - range.start()) / span
+                    } else {
+                        0.5
+                    };
+                    self.colormap.color(t as f32)
+                }
+            })
+            .collect();
+        ColorImage {
+            size: [self.ncols, self.nrows],
+            pixels,
+        }
+    }
+
+    /// Hash of everything that ends up in the texture.
+    fn texture_hash(&self) -> u64 {
+        use std::hash::{BuildHasher as _, Hash as _, Hasher as _};
+
+        let mut hasher = ahash::RandomState::with_seeds(1, 2, 3, 4).build_hasher();
+        (self.ncols, self.nrows, &self.colormap).hash(&mut hasher);
+        // Without a `value_range`, the normalization follows from the values:
+        self.value_range
+            .as_ref()
+            .map(|range| (range.start().to_bits(), range.end().to_bits()))
+            .hash(&mut hasher);
+        for value in &self.values {
+            hasher.write_u64(value.to_bits());
+        }
+        hasher.finish()
+    }
+
+    fn texture(&self, ctx: &Context, cache_id: Id) -> TextureHandle {
+        let hash = self.texture_hash();
+        if let Some((cached_hash, texture)) =
+            ctx.data(|d| d.get_temp::<(u64, TextureHandle)>(cache_id))
+        {
+            if cached_hash == hash {
+                return texture;
+            }
+        }
+        let texture = ctx.load_texture(
+            format!("egui_plot heat map {}", self.name),
+            self.color_image(),
+            TextureOptions::NEAREST,
+        );
+        ctx.data_mut(|d| d.insert_temp(cache_id, (hash, texture.clone())));
+        texture
+    }
+
+    fn screen_rect(&self, transform: &PlotTransform) -> Rect {
+        Rect::from_two_pos(
+            transform.position_from_point(&PlotPoint::new(
+                *self.x_range.start(),
+                *self.y_range.start(),
+            )),
+            transform
+                .position_from_point(&PlotPoint::new(*self.x_range.end(), *self.y_range.end())),
+        )
+    }
+}
+
+impl PlotItem for HeatMap {
+    fn shapes(&self, ui: &Ui, transform: &PlotTransform, shapes: &mut Vec<Shape>) {
+        if self.ncols == 0 || self.nrows == 0 {
+            return;
+        }
+        let cache_id = ui
+            .id()
+            .with("egui_plot_heat_map")
+            .with(self.id.unwrap_or_else(|| Id::new(&self.name)));
+        let texture = self.texture(ui.ctx(), cache_id);
+        let rect = self.screen_rect(transform);
+        let uv = Rect::from_min_max(pos2(0.0, 0.0), pos2(1.0, 1.0));
+        shapes.push(Shape::image(texture.id(), rect, uv, Color32::WHITE));
+        if self.highlight {
+            shapes.push(Shape::rect_stroke(
+                rect,
+                0.0,
+                Stroke::new(1.0, ui.visuals().strong_text_color()),
+            ));
+        }
+    }
+
+    fn initialize(&mut self, _x_range: RangeInclusive<f64>) {}
+
+    fn name(&self) -> &str {
+        self.name.as_str()
+    }
+
+    fn color(&self) -> Color32 {
+        self.colormap.color(0.5)
+    }
+
+    fn highlight(&mut self) {
+        self.highlight = true;
+    }
+
+    fn highlighted(&self) -> bool {
+        self.highlight
+    }
+
+    fn allow_hover(&self) -> bool {
+        self.allow_hover
+    }
+
+    fn geometry(&self) -> PlotGeometry<'_> {
+        PlotGeometry::Rects
+    }
+
+    fn bounds(&self) -> PlotBounds {
+        let mut bounds = PlotBounds::NOTHING;
+        bounds.extend_with(&PlotPoint::new(
+            *self.x_range.start(),
+            *self.y_range.start(),
+        ));
+        bounds.extend_with(&PlotPoint::new(*self.x_range.end(), *self.y_range.end()));
+        bounds
+    }
+
+    fn id(&self) -> Option<Id> {
+        self.id
+    }
+
+    /// Any point inside the heat map hovers the cell under it.
+    fn find_closest(&self, point: Pos2, transform: &PlotTransform) -> Option<ClosestElem> {
+        let (col, row) = self.cell_at(transform.value_from_position(point))?;
+        Some(ClosestElem {
+            index: row * self.ncols + col,
+            dist_sq: 0.0,
+        })
+    }
+
+    fn on_hover(
+        &self,
+        elem: ClosestElem,
+        shapes: &mut Vec<Shape>,
+        _cursors: &mut Vec<Cursor>,
+        plot: &PlotConfig<'_>,
+        _: &LabelFormatter<'_>,
+    ) {
+        let (col, row) = (elem.index % self.ncols, elem.index / self.ncols);
+        let cell_size = [
+            (self.x_range.end() - self.x_range.start()) / self.ncols as f64,
+            (self.y_range.end() - self.y_range.start()) / self.nrows as f64,
+        ];
+        let cell_min = PlotPoint::new(
+            self.x_range.start() + col as f64 * cell_size[0],
+            self.y_range.start() + row as f64 * cell_size[1],
+        );
+        let cell_max = PlotPoint::new(cell_min.x + cell_size[0], cell_min.y + cell_size[1]);
+        let rect = Rect::from_two_pos(
+            plot.transform.position_from_point(&cell_min),
+            plot.transform.position_from_point(&cell_max),
+        );
+        shapes.push(Shape::rect_stroke(
+            rect,
+            0.0,
+            Stroke::new(1.0, plot.ui.visuals().strong_text_color()),
+        ));
+
+        let mut text = String::new();
+        if !self.name.is_empty() {
+            text += &format!("{}\n", self.name);
+        }
+        text += &format!(
+            "column {col}, row {row}\nvalue = {}",
+            crate::format_number(self.value(col, row), 3)
+        );
+
+        let font_id = TextStyle::Body.resolve(plot.ui.style());
+        plot.ui.fonts(|f| {
+            shapes.push(Shape::text(
+                f,
+                rect.right_top() + vec2(3.0, -2.0),
+                Align2::LEFT_BOTTOM,
+                text,
+                font_id,
+                plot.ui.visuals().text_color(),
+            ));
+        });
+    }
+}
+
+// ----------------------------------------------------------------------------
+
+/// A bar chart.
+pub struct BarChart {
+    pub(super) bars: Vec<Bar>,
+    pub(super) default_color: Color32,
+    pub(super) name: String,
+
+    /// A custom element formatter
+    pub(super) element_formatter: Option<Box<dyn Fn(&Bar, &BarChart) -> String>>,
+
+    /// A custom formatter of the hovered bar, see [`Self::hover_formatter`].
+    pub(super) hover_formatter: Option<Box<HoverFormatterFn>>,
+
+    highlight: bool,
+    allow_hover: bool,
+    y_axis: YAxis,
+    id: Option<Id>,
+}
+
+impl BarChart {
+    /// Create a bar chart. It defaults to vertically oriented elements.
+    pub fn new(bars: Vec<Bar>) -> Self {
+        Self {
+            bars,
+            default_color: Color32::TRANSPARENT,
+            name: String::new(),
+            element_formatter: None,
+            hover_formatter: None,
+            highlight: false,
+            allow_hover: true,
+            y_axis: YAxis::Primary,
+            id: None,
+        }
+    }
+
+    /// Set the default color. It is set on all elements that do not already have a specific color.
+    /// This is the color that shows up in the legend.
+    /// It can be overridden at the bar level (see [[`Bar`]]).
+    /// Default is `Color32::TRANSPARENT` which means a color will be auto-assigned.
+    #[inline]
+    pub fn color(mut self, color: impl Into<Color32>) -> Self {
+        let plot_color = color.into();
+        self.default_color = plot_color;
+        for b in &mut self.bars {
+            if b.fill == Color32::TRANSPARENT && b.stroke.color == Color32::TRANSPARENT {
+                b.fill = plot_color.linear_multiply(0.2);
+                b.stroke.color = plot_color;
+            }
+        }
+        self
+    }
+
+    /// Name of this chart.
+    ///
+    /// This name will show up in the plot legend, if legends are turned on. Multiple charts may
+    /// share the same name, in which case they will also share an entry in the legend.
+    #[allow(clippy::needless_pass_by_value)]
+    #[inline]
+    pub fn name(mut self, name: impl ToString) -> Self {
+        self.name = name.to_string();
+        self
+    }
+
+    /// Set all elements to be in a vertical orientation.
+    /// Argument axis will be X and bar values will be on the Y axis.
+    #[inline]
+    pub fn vertical(mut self) -> Self {
+        for b in &mut self.bars {
+            b.orientation = Orientation::Vertical;
+        }
+        self
+    }
+
+    /// Set all elements to be in a horizontal orientation.
+    /// Argument axis will be Y and bar values will be on the X axis.
+    #[inline]
+    pub fn horizontal(mut self) -> Self {
+        for b in &mut self.bars {
+            b.orientation = Orientation::Horizontal;
+        }
+        self
+    }
+
+    /// Set the width (thickness) of all its elements.
+    #[inline]
+    pub fn width(mut self, width: f64) -> Self {
+        for b in &mut self.bars {
+            b.bar_width = width;
+        }
+        self
+    }
+
+    /// Highlight all plot elements.
+    #[inline]
+    pub fn highlight(mut self, highlight: bool) -> Self {
+        self.highlight = highlight;
+        self
+    }
+
+    /// Allowed hovering this item in the plot. Default: `true`.
+    #[inline]
+    pub fn allow_hover(mut self, hovering: bool) -> Self {
+        self.allow_hover = hovering;
+        self
+    }
+
+    /// Add a custom way to format an element.
+    /// Can be used to display a set number of decimals or custom labels.
+    #[inline]
+    pub fn element_formatter(mut self, formatter: Box<dyn Fn(&Bar, &Self) -> String>) -> Self {
+        self.element_formatter = Some(formatter);
+        self
+    }
+
+    /// Stacks the bars on top of another chart.
+    /// Positive values are stacked on top of other positive values.
+    /// Negative values are stacked below other negative values.
+    #[inline]
+    pub fn stack_on(mut self, others: &[&Self]) -> Self {
+        for (index, bar) in self.bars.iter_mut().enumerate() {
+            let new_base_offset = if bar.value.is_sign_positive() {
+                others
+                    .iter()
+                    .filter_map(|other_chart| other_chart.bars.get(index).map(|bar| bar.upper()))
+                    .max_by_key(|value| value.ord())
+            } else {
+                others
+                    .iter()
+                    .filter_map(|other_chart| other_chart.bars.get(index).map(|bar| bar.lower()))
+                    .min_by_key(|value| value.ord())
+            };
+
+            if let Some(value) = new_base_offset {
+                bar.base_offset = Some(value);
+            }
+        }
+        self
+    }
+
+    /// Plot this bar chart against the given Y axis. Default is `YAxis::Primary`.
+    #[inline]
+    pub fn y_axis(mut self, y_axis: YAxis) -> Self {
+        self.y_axis = y_axis;
+        self
+    }
+
+    /// Format the hover text of this bar chart, e.g. to show its unit.
+    ///
+    /// This overrides [`crate::Plot::label_formatter`] for this bar chart.
+    #[inline]
+    pub fn hover_formatter(mut self, formatter: impl Fn(&PlotPoint) -> String + 'static) -> Self {
+        self.hover_formatter = Some(Box::new(formatter));
+        self
+    }
+
+    /// Set the bar chart's id which is used to identify it in the plot's response.
+    #[inline]
+    pub fn id(mut self, id: Id) -> Self {
+        self.id = Some(id);
+        self
+    }
+}
+
+impl PlotItem for BarChart {
+    fn shapes(&self, _ui: &Ui, transform: &PlotTransform, shapes: &mut Vec<Shape>) {
+        for b in &self.bars {
+            b.add_shapes(transform, self.highlight, shapes);
+        }
+    }
+
+    fn initialize(&mut self, _x_range: RangeInclusive<f64>) {
+        // nothing to do
+    }
+
+    fn name(&self) -> &str {
+        self.name.as_str()
+    }
+
+    fn color(&self) -> Color32 {
+        self.default_color
+    }
+
+    fn highlight(&mut self) {
+        self.highlight = true;
+    }
+
+    fn highlighted(&self) -> bool {
+        self.highlight
+    }
+
+    fn allow_hover(&self) -> bool {
+        self.allow_hover
+    }
+
+    fn geometry(&self) -> PlotGeometry<'_> {
+        PlotGeometry::Rects
+    }
+
+    fn bounds(&self) -> PlotBounds {
+        let mut bounds = PlotBounds::NOTHING;
+        for b in &self.bars {
+            bounds.merge(&b.bounds());
+        }
+        bounds
+    }
+
+    /// The argument and value of each bar.
+    fn export_points(&self) -> Vec<PlotPoint> {
+        self.bars
+            .iter()
+            .map(|bar| bar.point_at(bar.argument, bar.value))
+            .collect()
+    }
+
+    fn find_closest(&self, point: Pos2, transform: &PlotTransform) -> Option<ClosestElem> {
+        find_closest_rect(&self.bars, point, transform)
+    }
+
+    fn on_hover(
+        &self,
+        elem: ClosestElem,
+        shapes: &mut Vec<Shape>,
+        cursors: &mut Vec<Cursor>,
+        plot: &PlotConfig<'_>,
+        _: &LabelFormatter<'_>,
+    ) {
+        let bar = &self.bars[elem.index];
+
+        bar.add_shapes(plot.transform, true, shapes);
+        bar.add_rulers_and_text(self, plot, shapes, cursors);
+    }
+
+    fn id(&self) -> Option<Id> {
+        self.id
+    }
+
+    fn y_axis(&self) -> YAxis {
+        self.y_axis
+    }
+
+    fn hover_formatter(&self) -> Option<&HoverFormatterFn> {
+        self.hover_formatter.as_deref()
+    }
+
+    /// The category of a bar is its index in the chart.
+    fn category_index(&self, elem: &ClosestElem) -> Option<usize> {
+        Some(elem.index)
+    }
+
+    fn highlight_category(&self, index: usize, transform: &PlotTransform, shapes: &mut Vec<Shape>) {
+        if let Some(bar) = self.bars.get(index) {
+            bar.add_shapes(transform, true, shapes);
+        }
+    }
+}
+
+/// Bar charts stacked on top of each other, one layer per chart.
+///
+/// For each argument, positive values are stacked upward from zero and negative values
+/// downward from zero. Layers hidden in the legend are left out of the stack.
+pub struct StackedBars {
+    pub(super) charts: Vec<BarChart>,
+}
+
+impl StackedBars {
+    /// The first chart is the bottom layer.
+    ///
+    /// Give each chart a name to be able to toggle it in the legend.
+    pub fn new(charts: Vec<BarChart>) -> Self {
+        Self { charts }
+    }
+
+    /// Set the base of every bar to the total of the layers below it.
+    pub(crate) fn stack(&mut self, hidden_items: &ahash::HashSet<String>) {
+        // The positive and the negative total of each argument.
+        let mut totals: ahash::HashMap<u64, (f64, f64)> = Default::default();
+        for chart in &mut self.charts {
+            let hidden = hidden_items.contains(&chart.name);
+            for bar in &mut chart.bars {
+                let (positive, negative) = totals.entry(bar.argument.to_bits()).or_default();
+                let total = if bar.value.is_sign_positive() {
+                    positive
+                } else {
+                    negative
+                };
+                bar.base_offset = Some(*total);
+                if !hidden {
+                    *total += bar.value;
+                }
+            }
+        }
+    }
+}
+
+/// A diagram containing a series of [`BoxElem`] elements.
+pub struct BoxPlot {
+    pub(super) boxes: Vec<BoxElem>,
+    pub(super) default_color: Color32,
+    pub(super) name: String,
+
+    /// A custom element formatter
+    pub(super) element_formatter: Option<Box<dyn Fn(&BoxElem, &BoxPlot) -> String>>,
+
+    highlight: bool,
+    allow_hover: bool,
+    id: Option<Id>,
+}
+
+impl BoxPlot {
+    /// Create a plot containing multiple `boxes`. It defaults to vertically oriented elements.
+    pub fn new(boxes: Vec<BoxElem>) -> Self {
+        Self {
+            boxes,
+            default_color: Color32::TRANSPARENT,
+            name: String::new(),
+            element_formatter: None,
+            highlight: false,
+            allow_hover: true,
+            id: None,
+        }
+    }
+
+    /// Set the default color. It is set on all elements that do not already have a specific color.
+    /// This is the color that shows up in the legend.
+    /// It can be overridden at the element level (see [`BoxElem`]).
+    /// Default is `Color32::TRANSPARENT` which means a color will be auto-assigned.
+    #[inline]
+    pub fn color(mut self, color: impl Into<Color32>) -> Self {
+        let plot_color = color.into();
+        self.default_color = plot_color;
+        for box_elem in &mut self.boxes {
+            if box_elem.fill == Color32::TRANSPARENT
+                && box_elem.stroke.color == Color32::TRANSPARENT
+            {
+                box_elem.fill = plot_color.linear_multiply(0.2);
+                box_elem.stroke.color = plot_color;
+            }
+        }
+        self
+    }
+
+    /// Name of this box plot diagram.
+    ///
+    /// This name will show up in the plot legend, if legends are turned on. Multiple series may
+    /// share the same name, in which case they will also share an entry in the legend.
+    #[allow(clippy::needless_pass_by_value)]
+    #[inline]
+    pub fn name(mut self, name: impl ToString) -> Self {
+        self.name = name.to_string();
+        self
+    }
+
+    /// Set all elements to be in a vertical orientation.
+    /// Argument axis will be X and values will be on the Y axis.
+    #[inline]
+    pub fn vertical(mut self) -> Self {
+        for box_elem in &mut self.boxes {
+            box_elem.orientation = Orientation::Vertical;
+        }
+        self
+    }
+
+    /// Set all elements to be in a horizontal orientation.
+    /// Argument axis will be Y and values will be on the X axis.
+    #[inline]
+    pub fn horizontal(mut self) -> Self {
+        for box_elem in &mut self.boxes {
+            box_elem.orientation = Orientation::Horizontal;
+        }
+        self
+    }
+
+    /// Highlight all plot elements.
+    #[inline]
+    pub fn highlight(mut self, highlight: bool) -> Self {
+        self.highlight = highlight;
+        self
+    }
+
+    /// Allowed hovering this item in the plot. Default: `true`.
+    #[inline]
+    pub fn allow_hover(mut self, hovering: bool) -> Self {
+        self.allow_hover = hovering;
+        self
+    }
+
+    /// Add a custom way to format an element.
+    /// Can be used to display a set number of decimals or custom labels.
+    #[inline]
+    pub fn element_formatter(mut self, formatter: Box<dyn Fn(&BoxElem, &Self) -> String>) -> Self {
+        self.element_formatter = Some(formatter);
+        self
+    }
+
+    /// Set the box plot's id which is used to identify it in the plot's response.
+    #[inline]
+    pub fn id(mut self, id: Id) -> Self {
+        self.id = Some(id);
+        self
+    }
+}
+
+impl PlotItem for BoxPlot {
+    fn shapes(&self, _ui: &Ui, transform: &PlotTransform, shapes: &mut Vec<Shape>) {
+        for b in &self.boxes {
+            b.add_shapes(transform, self.highlight, shapes);
+        }
+    }
+
+    fn initialize(&mut self, _x_range: RangeInclusive<f64>) {
+        // nothing to do
+    }
+
+    fn name(&self) -> &str {
+        self.name.as_str()
+    }
+
+    fn color(&self) -> Color32 {
+        self.default_color
+    }
+
+    fn highlight(&mut self) {
+        self.highlight = true;
+    }
+
+    fn highlighted(&self) -> bool {
+        self.highlight
+    }
+
+    fn allow_hover(&self) -> bool {
+        self.allow_hover
+    }
+
+    fn geometry(&self) -> PlotGeometry<'_> {
+        PlotGeometry::Rects
+    }
+
+    fn bounds(&self) -> PlotBounds {
+        let mut bounds = PlotBounds::NOTHING;
+        for b in &self.boxes {
+            bounds.merge(&b.bounds());
+        }
+        bounds
+    }
+
+    /// The whiskers, quartiles and median of each box, from low to high.
+    fn export_points(&self) -> Vec<PlotPoint> {
+        self.boxes
+            .iter()
+            .flat_map(|elem| {
+                let spread = &elem.spread;
+                [
+                    spread.lower_whisker,
+                    spread.quartile1,
+                    spread.median,
+                    spread.quartile3,
+                    spread.upper_whisker,
+                ]
+                .map(|value| elem.point_at(elem.argument, value))
+            })
+            .collect()
+    }
+
+    fn find_closest(&self, point: Pos2, transform: &PlotTransform) -> Option<ClosestElem> {
+        find_closest_rect(&self.boxes, point, transform)
+    }
+
+    fn on_hover(
+        &self,
+        elem: ClosestElem,
+        shapes: &mut Vec<Shape>,
+        cursors: &mut Vec<Cursor>,
+        plot: &PlotConfig<'_>,
+        _: &LabelFormatter<'_>,
+    ) {
+        let box_plot = &self.boxes[elem.index];
+
+        box_plot.add_shapes(plot.transform, true, shapes);
+        box_plot.add_rulers_and_text(self, plot, shapes, cursors);
+    }
+
+    fn id(&self) -> Option<Id> {
+        self.id
+    }
+}
+
+// ----------------------------------------------------------------------------
+
+/// A diagram containing a series of [`ViolinElem`] elements, e.g. one per category.
+///
+/// Unlike a [`BoxPlot`], the violins show distributions with several peaks.
+pub struct Violin {
+    pub(super) violins: Vec<ViolinElem>,
+    pub(super) default_color: Color32,
+    pub(super) name: String,
+    pub(super) scale: ViolinScale,
+    pub(super) inner_box: bool,
+
+    /// A custom element formatter
+    pub(super) element_formatter: Option<Box<dyn Fn(&ViolinElem, &Violin) -> String>>,
+
+    highlight: bool,
+    allow_hover: bool,
+    id: Option<Id>,
+}
+
+impl Violin {
+    /// Create a plot containing multiple `violins`. It defaults to vertically oriented elements.
+    pub fn new(violins: Vec<ViolinElem>) -> Self {
+        Self {
+            violins,
+            default_color: Color32::TRANSPARENT,
+            name: String::new(),
+            scale: ViolinScale::default(),
+            inner_box: true,
+            element_formatter: None,
+            highlight: false,
+            allow_hover: true,
+            id: None,
+        }
+    }
 
     /// Set the default color. It is set on all elements that do not already have a specific color.
     /// This is the color that shows up in the legend.
-    /// It can be overridden at the bar level (see [[`Bar`]]).
+    /// It can be overridden at the element level (see [`ViolinElem`]).
     /// Default is `Color32::TRANSPARENT` which means a color will be auto-assigned.
     #[inline]
     pub fn color(mut self, color: impl Into<Color32>) -> Self {
         let plot_color = color.into();
         self.default_color = plot_color;
-        for b in &mut self.bars {
-            if b.fill == Color32::TRANSPARENT && b.stroke.color == Color32::TRANSPARENT {
-                b.fill = plot_color.linear_multiply(0.2);
-                b.stroke.color = plot_color;
+        for violin in &mut self.violins {
+            if violin.fill == Color32::TRANSPARENT && violin.stroke.color == Color32::TRANSPARENT {
+                violin.fill = plot_color.linear_multiply(0.2);
+                violin.stroke.color = plot_color;
             }
         }
         self
     }
 
-    /// Name of this chart.
+    /// Name of this violin diagram.
     ///
-    /// This name will show up in the plot legend, if legends are turned on. Multiple charts may
+    /// This name will show up in the plot legend, if legends are turned on. Multiple series may
     /// share the same name, in which case they will also share an entry in the legend.
     #[allow(clippy::needless_pass_by_value)]
     #[inline]
@@ -1600,31 +4719,37 @@ impl BarChart {
         self
     }
 
-    /// Set all elements to be in a vertical orientation.
-    /// Argument axis will be X and bar values will be on the Y axis.
+    /// How the violins are scaled to their width. Default: [`ViolinScale::Area`].
     #[inline]
-    pub fn vertical(mut self) -> Self {
-        for b in &mut self.bars {
-            b.orientation = Orientation::Vertical;
-        }
+    pub fn scale(mut self, scale: ViolinScale) -> Self {
+        self.scale = scale;
         self
     }
 
-    /// Set all elements to be in a horizontal orientation.
-    /// Argument axis will be Y and bar values will be on the X axis.
+    /// Draw a narrow box from the first to the third quartile, and a line at the median,
+    /// inside each violin. Default: `true`.
     #[inline]
-    pub fn horizontal(mut self) -> Self {
-        for b in &mut self.bars {
-            b.orientation = Orientation::Horizontal;
+    pub fn inner_box(mut self, inner_box: bool) -> Self {
+        self.inner_box = inner_box;
+        self
+    }
+
+    /// Set all elements to be in a vertical orientation.
+    /// Argument axis will be X and values will be on the Y axis.
+    #[inline]
+    pub fn vertical(mut self) -> Self {
+        for violin in &mut self.violins {
+            violin.orientation = Orientation::Vertical;
         }
         self
     }
 
-    /// Set the width (thickness) of all its elements.
+    /// Set all elements to be in a horizontal orientation.
+    /// Argument axis will be Y and values will be on the X axis.
     #[inline]
-    pub fn width(mut self, width: f64) -> Self {
-        for b in &mut self.bars {
-            b.bar_width = width;
+    pub fn horizontal(mut self) -> Self {
+        for violin in &mut self.violins {
+            violin.orientation = Orientation::Horizontal;
         }
         self
     }
@@ -1646,48 +4771,354 @@ impl BarChart {
     /// Add a custom way to format an element.
     /// Can be used to display a set number of decimals or custom labels.
     #[inline]
-    pub fn element_formatter(mut self, formatter: Box<dyn Fn(&Bar, &Self) -> String>) -> Self {
+    pub fn element_formatter(
+        mut self,
+        formatter: Box<dyn Fn(&ViolinElem, &Self) -> String>,
+    ) -> Self {
         self.element_formatter = Some(formatter);
         self
     }
 
-    /// Stacks the bars on top of another chart.
-    /// Positive values are stacked on top of other positive values.
-    /// Negative values are stacked below other negative values.
+    /// Set the violin plot's id which is used to identify it in the plot's response.
     #[inline]
-    pub fn stack_on(mut self, others: &[&Self]) -> Self {
-        for (index, bar) in self.bars.iter_mut().enumerate() {
-            let new_base_offset = if bar.value.is_sign_positive() {
-                others
-                    .iter()
-                    .filter_map(|other_chart| other_chart.bars.get(index).map(|bar| bar.upper()))
-                    .max_by_key(|value| value.ord())
-            } else {
-                others
-                    .iter()
-                    .filter_map(|other_chart| other_chart.bars.get(index).map(|bar| bar.lower()))
-                    .min_by_key(|value| value.ord())
-            };
+    pub fn id(mut self, id: Id) -> Self {
+        self.id = Some(id);
+        self
+    }
+
+    /// The factor from the density of `violin` to half its thickness.
+    fn density_scale(&self, violin: &ViolinElem) -> f64 {
+        let max_density = match self.scale {
+            ViolinScale::Area => self
+                .violins
+                .iter()
+                .map(|v| v.max_density())
+                .fold(0.0, f64::max),
+            ViolinScale::Width => violin.max_density(),
+        };
+        if max_density > 0.0 {
+            violin.width / 2.0 / max_density
+        } else {
+            0.0
+        }
+    }
+}
+
+impl PlotItem for Violin {
+    fn shapes(&self, _ui: &Ui, transform: &PlotTransform, shapes: &mut Vec<Shape>) {
+        for violin in &self.violins {
+            let scale = self.density_scale(violin);
+            violin.add_shapes(transform, scale, self.inner_box, self.highlight, shapes);
+        }
+    }
+
+    fn initialize(&mut self, _x_range: RangeInclusive<f64>) {
+        // nothing to do
+    }
+
+    fn name(&self) -> &str {
+        self.name.as_str()
+    }
+
+    fn color(&self) -> Color32 {
+        self.default_color
+    }
+
+    fn highlight(&mut self) {
+        self.highlight = true;
+    }
+
+    fn highlighted(&self) -> bool {
+        self.highlight
+    }
+
+    fn allow_hover(&self) -> bool {
+        self.allow_hover
+    }
+
+    fn geometry(&self) -> PlotGeometry<'_> {
+        PlotGeometry::Rects
+    }
+
+    fn bounds(&self) -> PlotBounds {
+        let mut bounds = PlotBounds::NOTHING;
+        for violin in self.violins.iter().filter(|v| !v.is_empty()) {
+            bounds.merge(&violin.bounds());
+        }
+        bounds
+    }
+
+    /// The minimum, quartiles, median and maximum of each violin, from low to high.
+    fn export_points(&self) -> Vec<PlotPoint> {
+        self.violins
+            .iter()
+            .flat_map(|violin| {
+                let spread = violin.spread();
+                [
+                    spread.lower_whisker,
+                    spread.quartile1,
+                    spread.median,
+                    spread.quartile3,
+                    spread.upper_whisker,
+                ]
+                .map(|value| violin.point_at(violin.argument, value))
+            })
+            .collect()
+    }
+
+    fn find_closest(&self, point: Pos2, transform: &PlotTransform) -> Option<ClosestElem> {
+        find_closest_rect(&self.violins, point, transform)
+    }
+
+    fn on_hover(
+        &self,
+        elem: ClosestElem,
+        shapes: &mut Vec<Shape>,
+        cursors: &mut Vec<Cursor>,
+        plot: &PlotConfig<'_>,
+        _: &LabelFormatter<'_>,
+    ) {
+        let violin = &self.violins[elem.index];
+        let scale = self.density_scale(violin);
+
+        violin.add_shapes(plot.transform, scale, self.inner_box, true, shapes);
+        violin.add_rulers_and_text(self, plot, shapes, cursors);
+    }
+
+    fn id(&self) -> Option<Id> {
+        self.id
+    }
+}
+
+// ----------------------------------------------------------------------------
+
+/// A rose chart (also known as a polar bar chart or Nightingale chart), e.g. for wind-rose data.
+///
+/// The full circle is split into one equal angular sector per value,
+/// and each sector is drawn as a wedge whose radius is proportional to its value.
+pub struct RoseChart {
+    pub(super) values: Vec<f64>,
+    pub(super) center: PlotPoint,
+    pub(super) scale: f64,
+    pub(super) sqrt_scaling: bool,
+    pub(super) start_angle: f64,
+    pub(super) clockwise: bool,
+    pub(super) fills: Vec<Color32>,
+    pub(super) stroke: Stroke,
+    pub(super) default_color: Color32,
+    pub(super) name: String,
+    highlight: bool,
+    allow_hover: bool,
+    id: Option<Id>,
+}
+
+impl RoseChart {
+    /// One value per sector. Negative values are drawn as empty sectors.
+    ///
+    /// By default the chart is centered at the origin, the first sector starts at 12 o'clock
+    /// and the sectors go clockwise, like a compass.
+    pub fn new(values: Vec<f64>) -> Self {
+        Self {
+            values,
+            center: PlotPoint::new(0.0, 0.0),
+            scale: 1.0,
+            sqrt_scaling: false,
+            start_angle: std::f64::consts::FRAC_PI_2,
+            clockwise: true,
+            fills: Vec::new(),
+            stroke: Stroke::new(1.0, Color32::TRANSPARENT),
+            default_color: Color32::TRANSPARENT,
+            name: String::new(),
+            highlight: false,
+            allow_hover: true,
+            id: None,
+        }
+    }
+
+    /// Center of the chart, in plot coordinates. Default: the origin.
+    #[inline]
+    pub fn center(mut self, center: impl Into<PlotPoint>) -> Self {
+        self.center = center.into();
+        self
+    }
+
+    /// The radius (in plot units) of a sector with value `1.0`. Default: `1.0`.
+    #[inline]
+    pub fn scale(mut self, scale: f64) -> Self {
+        self.scale = scale;
+        self
+    }
+
+    /// Make the radius proportional to the square root of the value,
+    /// so that the area of each sector is proportional to its value. Default: `false`.
+    #[inline]
+    pub fn sqrt_scaling(mut self, sqrt_scaling: bool) -> Self {
+        self.sqrt_scaling = sqrt_scaling;
+        self
+    }
+
+    /// Angle of the start of the first sector, in radians counter-clockwise from the positive x axis.
+    ///
+    /// Default: π/2 (12 o'clock).
+    #[inline]
+    pub fn start_angle(mut self, start_angle: f64) -> Self {
+        self.start_angle = start_angle;
+        self
+    }
+
+    /// Do the sectors go clockwise? Default: `true`.
+    #[inline]
+    pub fn clockwise(mut self, clockwise: bool) -> Self {
+        self.clockwise = clockwise;
+        self
+    }
+
+    /// Fill color of each sector.
+    ///
+    /// Sectors without a fill color here use a translucent version of [`Self::color`].
+    #[inline]
+    pub fn fills(mut self, fills: Vec<Color32>) -> Self {
+        self.fills = fills;
+        self
+    }
+
+    /// Stroke around each sector.
+    ///
+    /// If the color is transparent (the default), [`Self::color`] is used.
+    #[inline]
+    pub fn stroke(mut self, stroke: impl Into<Stroke>) -> Self {
+        self.stroke = stroke.into();
+        self
+    }
+
+    /// Set the default color. This is the color that shows up in the legend.
+    ///
+    /// Default is `Color32::TRANSPARENT` which means a color will be auto-assigned.
+    #[inline]
+    pub fn color(mut self, color: impl Into<Color32>) -> Self {
+        self.default_color = color.into();
+        self
+    }
+
+    /// Name of this chart.
+    ///
+    /// This name will show up in the plot legend, if legends are turned on. Multiple charts may
+    /// share the same name, in which case they will also share an entry in the legend.
+    #[allow(clippy::needless_pass_by_value)]
+    #[inline]
+    pub fn name(mut self, name: impl ToString) -> Self {
+        self.name = name.to_string();
+        self
+    }
 
-            if let Some(value) = new_base_offset {
-                bar.base_offset = Some(value);
-            }
-        }
+    /// Highlight all sectors.
+    #[inline]
+    pub fn highlight(mut self, highlight: bool) -> Self {
+        self.highlight = highlight;
         self
     }
 
-    /// Set the bar chart's id which is used to identify it in the plot's response.
+    /// Allowed hovering this item in the plot. Default: `true`.
+    #[inline]
+    pub fn allow_hover(mut self, hovering: bool) -> Self {
+        self.allow_hover = hovering;
+        self
+    }
+
+    /// Set the chart's id which is used to identify it in the plot's response.
     #[inline]
     pub fn id(mut self, id: Id) -> Self {
         self.id = Some(id);
         self
     }
+
+    /// Radius of the sector with the given value, in plot units.
+    pub(crate) fn radius_of(&self, value: f64) -> f64 {
+        let value = value.max(0.0);
+        if self.sqrt_scaling {
+            value.sqrt() * self.scale
+        } else {
+            value * self.scale
+        }
+    }
+
+    /// The start and end angle of a sector, in radians counter-clockwise from the positive x axis.
+    pub(crate) fn sector_angles(&self, index: usize) -> (f64, f64) {
+        let sweep = std::f64::consts::TAU / self.values.len() as f64;
+        let sweep = if self.clockwise { -sweep } else { sweep };
+        let start = self.start_angle + sweep * index as f64;
+        (start, start + sweep)
+    }
+
+    /// Which sector contains the given angle, counter-clockwise from the positive x axis.
+    fn sector_at_angle(&self, angle: f64) -> Option<usize> {
+        if self.values.is_empty() {
+            return None;
+        }
+        let n = self.values.len();
+        let mut turns = (angle - self.start_angle) / std::f64::consts::TAU;
+        if self.clockwise {
+            turns = -turns;
+        }
+        let index = (turns.rem_euclid(1.0) * n as f64) as usize;
+        Some(index.min(n - 1))
+    }
+
+    fn point_at(&self, angle: f64, radius: f64) -> PlotPoint {
+        PlotPoint::new(
+            self.center.x + radius * angle.cos(),
+            self.center.y + radius * angle.sin(),
+        )
+    }
+
+    fn sector_colors(&self, index: usize, highlighted: bool) -> (Stroke, Color32) {
+        let fill = self
+            .fills
+            .get(index)
+            .copied()
+            .unwrap_or_else(|| self.default_color.linear_multiply(0.2));
+        let mut stroke = self.stroke;
+        if stroke.color == Color32::TRANSPARENT {
+            stroke.color = self.default_color;
+        }
+        if highlighted {
+            highlighted_color(stroke, fill)
+        } else {
+            (stroke, fill)
+        }
+    }
+
+    fn add_sector_shape(
+        &self,
+        index: usize,
+        transform: &PlotTransform,
+        highlighted: bool,
+        shapes: &mut Vec<Shape>,
+    ) {
+        let radius = self.radius_of(self.values[index]);
+        if radius <= 0.0 {
+            return;
+        }
+        let (start, end) = self.sector_angles(index);
+        let segments = ((end - start).abs() / std::f64::consts::TAU * 128.0).ceil() as usize;
+        let segments = segments.max(2);
+
+        let mut points = Vec::with_capacity(segments + 2);
+        points.push(transform.position_from_point(&self.center));
+        points.extend((0..=segments).map(|i| {
+            let angle = start + (end - start) * i as f64 / segments as f64;
+            transform.position_from_point(&self.point_at(angle, radius))
+        }));
+
+        let (stroke, fill) = self.sector_colors(index, highlighted);
+        shapes.push(Shape::convex_polygon(points, fill, stroke));
+    }
 }
 
-impl PlotItem for BarChart {
+impl PlotItem for RoseChart {
     fn shapes(&self, _ui: &Ui, transform: &PlotTransform, shapes: &mut Vec<Shape>) {
-        for b in &self.bars {
-            b.add_shapes(transform, self.highlight, shapes);
+        for index in 0..self.values.len() {
+            self.add_sector_shape(index, transform, self.highlight, shapes);
         }
     }
 
@@ -1719,116 +5150,240 @@ impl PlotItem for BarChart {
         PlotGeometry::Rects
     }
 
+    /// A square around the center, big enough for the largest sector.
     fn bounds(&self) -> PlotBounds {
-        let mut bounds = PlotBounds::NOTHING;
-        for b in &self.bars {
-            bounds.merge(&b.bounds());
+        if self.values.is_empty() {
+            return PlotBounds::NOTHING;
         }
-        bounds
+        let max_radius = self
+            .values
+            .iter()
+            .map(|&value| self.radius_of(value))
+            .fold(0.0, f64::max);
+        PlotBounds::from_min_max(
+            [self.center.x - max_radius, self.center.y - max_radius],
+            [self.center.x + max_radius, self.center.y + max_radius],
+        )
     }
 
     fn find_closest(&self, point: Pos2, transform: &PlotTransform) -> Option<ClosestElem> {
-        find_closest_rect(&self.bars, point, transform)
+        let value = transform.value_from_position(point);
+        let (dx, dy) = (value.x - self.center.x, value.y - self.center.y);
+        let angle = dy.atan2(dx);
+        let index = self.sector_at_angle(angle)?;
+
+        // Closest point of the sector along the direction of the pointer:
+        let radius = dx.hypot(dy).min(self.radius_of(self.values[index]));
+        let closest = transform.position_from_point(&self.point_at(angle, radius));
+        Some(ClosestElem {
+            index,
+            dist_sq: point.distance_sq(closest),
+        })
     }
 
     fn on_hover(
         &self,
         elem: ClosestElem,
         shapes: &mut Vec<Shape>,
-        cursors: &mut Vec<Cursor>,
+        _cursors: &mut Vec<Cursor>,
         plot: &PlotConfig<'_>,
         _: &LabelFormatter<'_>,
     ) {
-        let bar = &self.bars[elem.index];
+        let index = elem.index;
+        self.add_sector_shape(index, plot.transform, true, shapes);
 
-        bar.add_shapes(plot.transform, true, shapes);
-        bar.add_rulers_and_text(self, plot, shapes, cursors);
+        let (start, end) = self.sector_angles(index);
+        let mut text = self.name.clone();
+        if !text.is_empty() {
+            text.push('\n');
+        }
+        text.push_str(&format!(
+            "#{index}: {:.0}° to {:.0}°\n{}",
+            start.to_degrees(),
+            end.to_degrees(),
+            crate::format_number(self.values[index], 3)
+        ));
+
+        let mid_angle = (start + end) / 2.0;
+        let tip = self.point_at(mid_angle, self.radius_of(self.values[index]));
+        let font_id = TextStyle::Body.resolve(plot.ui.style());
+        plot.ui.fonts(|f| {
+            shapes.push(Shape::text(
+                f,
+                plot.transform.position_from_point(&tip) + vec2(3.0, -2.0),
+                Align2::LEFT_BOTTOM,
+                text,
+                font_id,
+                plot.ui.visuals().text_color(),
+            ));
+        });
     }
 
     fn id(&self) -> Option<Id> {
         self.id
     }
+
+    /// The category of a sector is its index in the chart.
+    fn category_index(&self, elem: &ClosestElem) -> Option<usize> {
+        Some(elem.index)
+    }
+
+    fn highlight_category(&self, index: usize, transform: &PlotTransform, shapes: &mut Vec<Shape>) {
+        if index < self.values.len() {
+            self.add_sector_shape(index, transform, true, shapes);
+        }
+    }
 }
 
-/// A diagram containing a series of [`BoxElem`] elements.
-pub struct BoxPlot {
-    pub(super) boxes: Vec<BoxElem>,
+// ----------------------------------------------------------------------------
+
+/// A speedometer-style gauge: a background arc, an arc up to the current value,
+/// and optionally tick marks and a needle.
+pub struct Gauge {
+    pub(super) center: PlotPoint,
+    pub(super) radius: f64,
+    pub(super) range: RangeInclusive<f64>,
+    pub(super) value: f64,
+    pub(super) start_angle: f64,
+    pub(super) end_angle: f64,
+    pub(super) background_stroke: Stroke,
+    pub(super) value_stroke: Stroke,
+    pub(super) ticks: usize,
+
+    /// A custom tick label formatter
+    pub(super) tick_formatter: Option<Box<dyn Fn(f64) -> String>>,
+
+    pub(super) needle: bool,
+    pub(super) animation: Option<(f32, BoxedCurve)>,
     pub(super) default_color: Color32,
     pub(super) name: String,
-
-    /// A custom element formatter
-    pub(super) element_formatter: Option<Box<dyn Fn(&BoxElem, &BoxPlot) -> String>>,
-
     highlight: bool,
     allow_hover: bool,
     id: Option<Id>,
 }
 
-impl BoxPlot {
-    /// Create a plot containing multiple `boxes`. It defaults to vertically oriented elements.
-    pub fn new(boxes: Vec<BoxElem>) -> Self {
+impl Gauge {
+    /// A gauge showing `value` within `range`, as an arc around `center` with the given `radius` (in plot units).
+    ///
+    /// `value` is clamped to `range` when drawn.
+    pub fn new(
+        center: impl Into<PlotPoint>,
+        radius: f64,
+        range: RangeInclusive<f64>,
+        value: f64,
+    ) -> Self {
         Self {
-            boxes,
+            center: center.into(),
+            radius,
+            range,
+            value,
+            start_angle: 210_f64.to_radians(),
+            end_angle: (-30_f64).to_radians(),
+            background_stroke: Stroke::new(8.0, Color32::TRANSPARENT),
+            value_stroke: Stroke::new(8.0, Color32::TRANSPARENT),
+            ticks: 0,
+            tick_formatter: None,
+            needle: false,
+            animation: None,
             default_color: Color32::TRANSPARENT,
             name: String::new(),
-            element_formatter: None,
             highlight: false,
             allow_hover: true,
             id: None,
         }
     }
 
-    /// Set the default color. It is set on all elements that do not already have a specific color.
-    /// This is the color that shows up in the legend.
-    /// It can be overridden at the element level (see [`BoxElem`]).
-    /// Default is `Color32::TRANSPARENT` which means a color will be auto-assigned.
+    /// Angle of the start of the range, in radians counter-clockwise from the positive x axis.
+    ///
+    /// Default: 210° (about 8 o'clock).
     #[inline]
-    pub fn color(mut self, color: impl Into<Color32>) -> Self {
-        let plot_color = color.into();
-        self.default_color = plot_color;
-        for box_elem in &mut self.boxes {
-            if box_elem.fill == Color32::TRANSPARENT
-                && box_elem.stroke.color == Color32::TRANSPARENT
-            {
-                box_elem.fill = plot_color.linear_multiply(0.2);
-                box_elem.stroke.color = plot_color;
-            }
-        }
+    pub fn start_angle(mut self, start_angle: f64) -> Self {
+        self.start_angle = start_angle;
         self
     }
 
-    /// Name of this box plot diagram.
+    /// Angle of the end of the range, in radians counter-clockwise from the positive x axis.
     ///
-    /// This name will show up in the plot legend, if legends are turned on. Multiple series may
-    /// share the same name, in which case they will also share an entry in the legend.
-    #[allow(clippy::needless_pass_by_value)]
+    /// Default: -30° (about 4 o'clock).
     #[inline]
-    pub fn name(mut self, name: impl ToString) -> Self {
-        self.name = name.to_string();
+    pub fn end_angle(mut self, end_angle: f64) -> Self {
+        self.end_angle = end_angle;
         self
     }
 
-    /// Set all elements to be in a vertical orientation.
-    /// Argument axis will be X and values will be on the Y axis.
+    /// Stroke of the arc covering the whole range. The width is in screen pixels.
+    ///
+    /// If the color is transparent (the default), a faint color from the [`Ui`] style is used.
     #[inline]
-    pub fn vertical(mut self) -> Self {
-        for box_elem in &mut self.boxes {
-            box_elem.orientation = Orientation::Vertical;
-        }
+    pub fn background_stroke(mut self, stroke: impl Into<Stroke>) -> Self {
+        self.background_stroke = stroke.into();
         self
     }
 
-    /// Set all elements to be in a horizontal orientation.
-    /// Argument axis will be Y and values will be on the X axis.
+    /// Stroke of the arc up to the current value. The width is in screen pixels.
+    ///
+    /// If the color is transparent (the default), [`Self::color`] is used.
     #[inline]
-    pub fn horizontal(mut self) -> Self {
-        for box_elem in &mut self.boxes {
-            box_elem.orientation = Orientation::Horizontal;
-        }
+    pub fn value_stroke(mut self, stroke: impl Into<Stroke>) -> Self {
+        self.value_stroke = stroke.into();
         self
     }
 
-    /// Highlight all plot elements.
+    /// Number of evenly spaced tick marks, including both ends of the range. Default: `0`.
+    #[inline]
+    pub fn ticks(mut self, ticks: usize) -> Self {
+        self.ticks = ticks;
+        self
+    }
+
+    /// Label each tick mark with the text returned by `formatter`.
+    #[inline]
+    pub fn tick_formatter(mut self, formatter: Box<dyn Fn(f64) -> String>) -> Self {
+        self.tick_formatter = Some(formatter);
+        self
+    }
+
+    /// Draw a needle from the center to the current value. Default: `false`.
+    #[inline]
+    pub fn needle(mut self, needle: bool) -> Self {
+        self.needle = needle;
+        self
+    }
+
+    /// Animate the value arc and needle towards changes of the value, over `duration` seconds.
+    ///
+    /// The animation is keyed on the plot and [`Self::id`], or [`Self::name`] if no id is set.
+    /// `easing` is e.g. [`emath::easing::cubic_out`], or any other [`emath::easing::Curve`].
+    #[inline]
+    pub fn animate(
+        mut self,
+        duration: f32,
+        easing: impl emath::easing::Curve + Send + Sync + 'static,
+    ) -> Self {
+        self.animation = Some((duration, BoxedCurve::new(easing)));
+        self
+    }
+
+    /// Set the default color. This is the color that shows up in the legend.
+    ///
+    /// Default is `Color32::TRANSPARENT` which means a color will be auto-assigned.
+    #[inline]
+    pub fn color(mut self, color: impl Into<Color32>) -> Self {
+        self.default_color = color.into();
+        self
+    }
+
+    /// Name of this gauge.
+    ///
+    /// This name will show up in the plot legend, if legends are turned on.
+    #[allow(clippy::needless_pass_by_value)]
+    #[inline]
+    pub fn name(mut self, name: impl ToString) -> Self {
+        self.name = name.to_string();
+        self
+    }
+
+    /// Highlight the gauge.
     #[inline]
     pub fn highlight(mut self, highlight: bool) -> Self {
         self.highlight = highlight;
@@ -1842,27 +5397,131 @@ impl BoxPlot {
         self
     }
 
-    /// Add a custom way to format an element.
-    /// Can be used to display a set number of decimals or custom labels.
-    #[inline]
-    pub fn element_formatter(mut self, formatter: Box<dyn Fn(&BoxElem, &Self) -> String>) -> Self {
-        self.element_formatter = Some(formatter);
-        self
-    }
-
-    /// Set the box plot's id which is used to identify it in the plot's response.
+    /// Set the gauge's id which is used to identify it in the plot's response.
     #[inline]
     pub fn id(mut self, id: Id) -> Self {
         self.id = Some(id);
         self
     }
+
+    /// The angle of the given value, in radians counter-clockwise from the positive x axis.
+    pub(crate) fn angle_of(&self, value: f64) -> f64 {
+        let t = remap_clamp(value, self.range.clone(), 0.0..=1.0);
+        lerp(self.start_angle..=self.end_angle, t)
+    }
+
+    fn point_at(&self, angle: f64, radius: f64) -> PlotPoint {
+        PlotPoint::new(
+            self.center.x + radius * angle.cos(),
+            self.center.y + radius * angle.sin(),
+        )
+    }
+
+    fn arc_points(&self, from: f64, to: f64, transform: &PlotTransform) -> Vec<Pos2> {
+        let segments = ((to - from).abs() / std::f64::consts::TAU * 128.0).ceil() as usize;
+        let segments = segments.max(1);
+        (0..=segments)
+            .map(|i| {
+                let angle = lerp(from..=to, i as f64 / segments as f64);
+                transform.position_from_point(&self.point_at(angle, self.radius))
+            })
+            .collect()
+    }
+
+    /// The value to draw, which lags behind [`Self::value`] while animating.
+    fn displayed_value(&self, ui: &Ui) -> f64 {
+        // The range may be reversed, e.g. for a countdown, and `clamp` panics on NaN ends:
+        let (start, end) = (*self.range.start(), *self.range.end());
+        #[allow(clippy::manual_clamp)]
+        let value = self.value.max(start.min(end)).min(start.max(end));
+        let Some((duration, easing)) = &self.animation else {
+            return value;
+        };
+        let id = ui
+            .id()
+            .with("egui_plot_gauge")
+            .with(self.id.unwrap_or_else(|| Id::new(&self.name)));
+        ui.ctx()
+            .animate_value_with_time_and_easing(id, value as f32, *duration, easing.clone())
+            as f64
+    }
+
+    fn value_color(&self) -> Color32 {
+        if self.value_stroke.color == Color32::TRANSPARENT {
+            self.default_color
+        } else {
+            self.value_stroke.color
+        }
+    }
+
+    fn add_value_shapes(
+        &self,
+        value: f64,
+        transform: &PlotTransform,
+        highlighted: bool,
+        shapes: &mut Vec<Shape>,
+    ) {
+        let mut stroke = Stroke::new(self.value_stroke.width, self.value_color());
+        if highlighted {
+            stroke.width *= 2.0;
+        }
+        let value_angle = self.angle_of(value);
+        let points = self.arc_points(self.start_angle, value_angle, transform);
+        shapes.push(Shape::line(points, stroke));
+
+        if self.needle {
+            let center = transform.position_from_point(&self.center);
+            let tip = transform.position_from_point(&self.point_at(value_angle, 0.9 * self.radius));
+            let needle_stroke = Stroke::new(stroke.width / 4.0, stroke.color);
+            shapes.push(Shape::line_segment([center, tip], needle_stroke));
+            shapes.push(Shape::circle_filled(
+                center,
+                stroke.width / 2.0,
+                stroke.color,
+            ));
+        }
+    }
 }
 
-impl PlotItem for BoxPlot {
-    fn shapes(&self, _ui: &Ui, transform: &PlotTransform, shapes: &mut Vec<Shape>) {
-        for b in &self.boxes {
-            b.add_shapes(transform, self.highlight, shapes);
+impl PlotItem for Gauge {
+    fn shapes(&self, ui: &Ui, transform: &PlotTransform, shapes: &mut Vec<Shape>) {
+        let mut background_stroke = self.background_stroke;
+        if background_stroke.color == Color32::TRANSPARENT {
+            background_stroke.color = ui.visuals().faint_bg_color;
+        }
+        let points = self.arc_points(self.start_angle, self.end_angle, transform);
+        shapes.push(Shape::line(points, background_stroke));
+
+        if 2 <= self.ticks {
+            let tick_color = ui.visuals().text_color();
+            let outer = self.radius;
+            let inner = self.radius * 0.85;
+            let font_id = TextStyle::Small.resolve(ui.style());
+            for i in 0..self.ticks {
+                let value = lerp(self.range.clone(), i as f64 / (self.ticks - 1) as f64);
+                let angle = self.angle_of(value);
+                let a = transform.position_from_point(&self.point_at(angle, inner));
+                let b = transform.position_from_point(&self.point_at(angle, outer));
+                shapes.push(Shape::line_segment([a, b], (1.0, tick_color)));
+
+                if let Some(formatter) = &self.tick_formatter {
+                    let pos = transform.position_from_point(&self.point_at(angle, 0.7 * outer));
+                    ui.fonts(|f| {
+                        shapes.push(Shape::text(
+                            f,
+                            pos,
+                            Align2::CENTER_CENTER,
+                            formatter(value),
+                            font_id.clone(),
+                            tick_color,
+                        ));
+                    });
+                }
+            }
         }
+
+        let value = self.displayed_value(ui);
+        self.add_value_shapes(value, transform, self.highlight, shapes);
     }
 
     fn initialize(&mut self, _x_range: RangeInclusive<f64>) {
@@ -1874,7 +5533,7 @@ impl PlotItem for BoxPlot {
     }
 
     fn color(&self) -> Color32 {
-        self.default_color
+        self.value_color()
     }
 
     fn highlight(&mut self) {
@@ -1893,30 +5552,52 @@ impl PlotItem for BoxPlot {
         PlotGeometry::Rects
     }
 
+    /// A square around the center, big enough for the full circle.
     fn bounds(&self) -> PlotBounds {
-        let mut bounds = PlotBounds::NOTHING;
-        for b in &self.boxes {
-            bounds.merge(&b.bounds());
-        }
-        bounds
+        PlotBounds::from_min_max(
+            [self.center.x - self.radius, self.center.y - self.radius],
+            [self.center.x + self.radius, self.center.y + self.radius],
+        )
     }
 
     fn find_closest(&self, point: Pos2, transform: &PlotTransform) -> Option<ClosestElem> {
-        find_closest_rect(&self.boxes, point, transform)
+        self.arc_points(self.start_angle, self.end_angle, transform)
+            .into_iter()
+            .map(|pos| ClosestElem {
+                index: 0,
+                dist_sq: point.distance_sq(pos),
+            })
+            .min_by_key(|e| e.dist_sq.ord())
     }
 
     fn on_hover(
         &self,
-        elem: ClosestElem,
+        _elem: ClosestElem,
         shapes: &mut Vec<Shape>,
-        cursors: &mut Vec<Cursor>,
+        _cursors: &mut Vec<Cursor>,
         plot: &PlotConfig<'_>,
         _: &LabelFormatter<'_>,
     ) {
-        let box_plot = &self.boxes[elem.index];
+        let value = self.displayed_value(plot.ui);
+        self.add_value_shapes(value, plot.transform, true, shapes);
 
-        box_plot.add_shapes(plot.transform, true, shapes);
-        box_plot.add_rulers_and_text(self, plot, shapes, cursors);
+        let mut text = self.name.clone();
+        if !text.is_empty() {
+            text.push('\n');
+        }
+        text.push_str(&crate::format_number(self.value, 3));
+
+        let font_id = TextStyle::Body.resolve(plot.ui.style());
+        plot.ui.fonts(|f| {
+            shapes.push(Shape::text(
+                f,
+                plot.transform.position_from_point(&self.center),
+                Align2::CENTER_CENTER,
+                text,
+                font_id,
+                plot.ui.visuals().text_color(),
+            ));
+        });
     }
 
     fn id(&self) -> Option<Id> {
@@ -2050,21 +5731,22 @@ pub(super) fn rulers_at_value(
         format!("{name}\n")
     };
 
+    let y_name = match plot.y_axis {
+        YAxis::Primary => "y",
+        YAxis::Secondary => "y2",
+    };
+
     let text = {
-        let scale = plot.transform.dvalue_dpos();
-        let x_decimals = ((-scale[0].abs().log10()).ceil().at_least(0.0) as usize).clamp(1, 6);
-        let y_decimals = ((-scale[1].abs().log10()).ceil().at_least(0.0) as usize).clamp(1, 6);
+        let y_decimals = value_decimals(plot.transform, Axis::Y);
+        let x_text = x_value_text(plot, value.x);
         if let Some(custom_label) = label_formatter {
             custom_label(name, &value)
         } else if plot.show_x && plot.show_y {
-            format!(
-                "{}x = {:.*}\ny = {:.*}",
-                prefix, x_decimals, value.x, y_decimals, value.y
-            )
+            format!("{prefix}{x_text}\n{y_name} = {:.*}", y_decimals, value.y)
         } else if plot.show_x {
-            format!("{}x = {:.*}", prefix, x_decimals, value.x)
+            format!("{prefix}{x_text}")
         } else if plot.show_y {
-            format!("{}y = {:.*}", prefix, y_decimals, value.y)
+            format!("{}{y_name} = {:.*}", prefix, y_decimals, value.y)
         } else {
             unreachable!()
         }
@@ -2083,6 +5765,23 @@ pub(super) fn rulers_at_value(
     });
 }
 
+/// How many decimals to show for hovered values, depending on the zoom level.
+fn value_decimals(transform: &PlotTransform, axis: Axis) -> usize {
+    let scale = transform.dvalue_dpos()[usize::from(axis)];
+    ((-scale.abs().log10()).ceil().at_least(0.0) as usize).clamp(1, 6)
+}
+
+/// The hover text of an x value, e.g. `x = 1.5`, a category or a timestamp.
+pub(crate) fn x_value_text(plot: &PlotConfig<'_>, x: f64) -> String {
+    if let Some(category) = crate::category_at(plot.x_categories, x) {
+        category.to_owned()
+    } else if let Some(time_axis) = plot.x_time {
+        time_axis.format_timestamp(x)
+    } else {
+        format!("x = {:.*}", value_decimals(plot.transform, Axis::X), x)
+    }
+}
+
 fn find_closest_rect<'a, T>(
     rects: impl IntoIterator<Item = &'a T>,
     point: Pos2,
@@ -2102,3 +5801,328 @@ where
         })
         .min_by_key(|e| e.dist_sq.ord())
 }
+
+#[cfg(test)]
+mod tests {
+    use egui::CentralPanel;
+
+    use super::*;
+
+    #[test]
+    fn test_gauge_displayed_value() {
+        let ctx = egui::Context::default();
+        let _ = ctx.run(Default::default(), |ctx| {
+            CentralPanel::default().show(ctx, |ui| {
+                // A countdown, and a range with NaN:
+                let countdown = Gauge::new([0.0, 0.0], 1.0, 100.0..=0.0, 120.0);
+                assert_eq!(countdown.displayed_value(ui), 100.0);
+                let gauge = Gauge::new([0.0, 0.0], 1.0, 0.0..=f64::NAN, 5.0);
+                assert!(gauge.displayed_value(ui).is_finite());
+
+                // Unnamed gauges in different plots don't share their animation:
+                let gauge = |value| {
+                    Gauge::new([0.0, 0.0], 1.0, 0.0..=100.0, value)
+                        .animate(1.0, emath::easing::linear)
+                };
+                let first = ui
+                    .push_id("first", |ui| gauge(80.0).displayed_value(ui))
+                    .inner;
+                let second = ui
+                    .push_id("second", |ui| gauge(20.0).displayed_value(ui))
+                    .inner;
+                assert_eq!((first, second), (80.0, 20.0));
+            });
+        });
+    }
+
+    #[test]
+    fn test_rose_chart() {
+        use std::f64::consts::{FRAC_PI_2, PI};
+
+        let chart = RoseChart::new(vec![1.0, 4.0, 9.0, -1.0]).scale(2.0);
+        assert_eq!(chart.radius_of(4.0), 8.0);
+        assert_eq!(chart.radius_of(-1.0), 0.0, "negative values are empty");
+
+        // With sqrt scaling the area (∝ r²) is proportional to the value:
+        let chart = chart.sqrt_scaling(true).center([1.0, -1.0]);
+        for value in [1.0, 4.0, 9.0] {
+            let area = chart.radius_of(value).powi(2);
+            assert!((area / value - 4.0).abs() < 1e-12, "{value}: {area}");
+        }
+
+        let bounds = chart.bounds();
+        assert_eq!(bounds.min(), [-5.0, -7.0]);
+        assert_eq!(bounds.max(), [7.0, 5.0]);
+        assert!(!RoseChart::new(vec![]).bounds().is_valid());
+
+        // Clockwise from 12 o'clock by default:
+        assert_eq!(chart.sector_angles(0), (FRAC_PI_2, 0.0));
+        assert_eq!(chart.sector_angles(2), (-FRAC_PI_2, -PI));
+        let chart = chart.clockwise(false).start_angle(0.0);
+        assert_eq!(chart.sector_angles(1), (FRAC_PI_2, PI));
+    }
+
+    #[test]
+    fn test_gauge_angles() {
+        let gauge = Gauge::new([0.0, 0.0], 1.0, 0.0..=100.0, 50.0);
+        let (start, end) = (210_f64.to_radians(), (-30_f64).to_radians());
+        let approx_eq = |a: f64, b: f64| (a - b).abs() < 1e-12;
+        assert!(approx_eq(gauge.angle_of(0.0), start));
+        assert!(approx_eq(gauge.angle_of(100.0), end));
+        assert!(approx_eq(gauge.angle_of(50.0), 90_f64.to_radians()));
+        assert!(approx_eq(gauge.angle_of(-5.0), start), "clamped");
+        assert!(approx_eq(gauge.angle_of(500.0), end), "clamped");
+
+        let gauge = gauge.start_angle(0.0).end_angle(std::f64::consts::PI);
+        assert!(approx_eq(gauge.angle_of(25.0), std::f64::consts::FRAC_PI_4));
+    }
+
+    #[test]
+    fn test_band_samples() {
+        // The curves cross at x = 1, which gets its own sample so the mesh doesn't fold over:
+        let band = Band::new(vec![[0.0, 1.0], [2.0, -1.0]], vec![[0.0, 0.0], [2.0, 0.0]]);
+        assert_eq!(
+            band.samples(),
+            vec![[0.0, 1.0, 0.0], [1.0, 0.0, 0.0], [2.0, -1.0, 0.0]]
+        );
+
+        // Mismatched x grids are interpolated, and cut to the common x range:
+        let band = Band::new(
+            vec![[0.0, 2.0], [1.0, 4.0], [2.0, 2.0], [3.0, 2.0]],
+            vec![[0.5, 0.0], [2.5, 1.0]],
+        );
+        assert_eq!(
+            band.samples(),
+            vec![
+                [0.5, 3.0, 0.0],
+                [1.0, 4.0, 0.25],
+                [2.0, 2.0, 0.75],
+                [2.5, 2.0, 1.0]
+            ]
+        );
+
+        // The bounds cover both series:
+        let bounds = band.bounds();
+        assert_eq!(bounds.min, [0.0, 0.0]);
+        assert_eq!(bounds.max, [3.0, 4.0]);
+    }
+
+    #[test]
+    fn test_error_bars() {
+        let error_bars = ErrorBars::new(
+            vec![[1.0, 5.0], [2.0, 3.0]],
+            [ErrorValue::Symmetric(1.0), ErrorValue::from((0.5, 2.0))],
+        )
+        .x_errors([0.25, 0.0])
+        .cap_width(4.0);
+
+        // The bounds include the whiskers, so auto-bounds doesn't clip them:
+        let bounds = error_bars.bounds();
+        assert_eq!(bounds.min, [0.75, 2.5]);
+        assert_eq!(bounds.max, [2.0, 6.0]);
+
+        // One unit is 10 points on both axes:
+        let frame = Rect::from_min_size(Pos2::ZERO, vec2(100.0, 100.0));
+        let bounds = PlotBounds::from_min_max([0.0, 0.0], [10.0, 10.0]);
+        let transform = PlotTransform::new(frame, bounds, false, false);
+        let segments = error_bars.segments(&transform);
+
+        // The second point goes 0.5 down and 2.0 up:
+        let [bar, low_cap, high_cap] = [segments[6], segments[7], segments[8]];
+        assert_eq!(bar, [pos2(20.0, 75.0), pos2(20.0, 50.0)]);
+        assert_eq!(low_cap, [pos2(18.0, 75.0), pos2(22.0, 75.0)]);
+        assert_eq!(high_cap, [pos2(18.0, 50.0), pos2(22.0, 50.0)]);
+    }
+
+    #[test]
+    fn test_heat_map() {
+        // Two rows of three cells, over x in 0..=3 and y in 0..=1:
+        let heat_map = HeatMap::new(
+            0.0..=3.0,
+            0.0..=1.0,
+            3,
+            2,
+            vec![0.0, 1.0, 2.0, 3.0, f64::NAN, 5.0],
+        );
+        assert_eq!(heat_map.cell_at(PlotPoint::new(0.2, 0.2)), Some((0, 0)));
+        assert_eq!(heat_map.cell_at(PlotPoint::new(2.5, 0.7)), Some((2, 1)));
+        assert_eq!(heat_map.cell_at(PlotPoint::new(3.0, 1.0)), Some((2, 1)));
+        assert_eq!(heat_map.cell_at(PlotPoint::new(3.5, 0.5)), None);
+
+        // Hovering reports the value of the cell under the pointer:
+        let frame = Rect::from_min_size(Pos2::ZERO, vec2(300.0, 100.0));
+        let transform = PlotTransform::new(frame, heat_map.bounds(), false, false);
+        let elem = heat_map
+            .find_closest(pos2(250.0, 30.0), &transform)
+            .unwrap();
+        assert_eq!(heat_map.values[elem.index], 5.0);
+
+        // The top row comes first in the image, and NaN is transparent:
+        let image = heat_map.color_image();
+        assert_eq!(image.size, [3, 2]);
+        assert_eq!(image.pixels[0], Colormap::Viridis.color(0.6));
+        assert_eq!(image.pixels[1], Color32::TRANSPARENT);
+        assert_eq!(image.pixels[3], Colormap::Viridis.color(0.0));
+
+        // The texture is only rebuilt when something in it changes:
+        let with_values =
+            |values: Vec<f64>| HeatMap::new(0.0..=3.0, 0.0..=1.0, 3, 2, values).texture_hash();
+        let hash = heat_map.texture_hash();
+        assert_eq!(with_values(heat_map.values.clone()), hash);
+        assert_ne!(with_values(vec![0.0, 1.0, 2.0, 3.0, 4.0, 5.0]), hash);
+        assert_ne!(heat_map.value_range(0.0..=10.0).texture_hash(), hash);
+    }
+
+    #[test]
+    fn test_stacked_bars() {
+        let layer = |name: &str, values: [f64; 2]| {
+            BarChart::new(vec![Bar::new(0.0, values[0]), Bar::new(1.0, values[1])]).name(name)
+        };
+        let bases = |stacked: &StackedBars| -> Vec<[Option<f64>; 2]> {
+            stacked
+                .charts
+                .iter()
+                .map(|chart| [chart.bars[0].base_offset, chart.bars[1].base_offset])
+                .collect()
+        };
+        let new_stack = || {
+            StackedBars::new(vec![
+                layer("a", [1.0, -1.0]),
+                layer("b", [2.0, -2.0]),
+                layer("c", [-3.0, 3.0]),
+            ])
+        };
+
+        // Positive values stack upward and negative values downward from zero:
+        let mut stacked = new_stack();
+        stacked.stack(&Default::default());
+        assert_eq!(
+            bases(&stacked),
+            vec![
+                [Some(0.0), Some(0.0)],
+                [Some(1.0), Some(-1.0)],
+                [Some(0.0), Some(0.0)],
+            ]
+        );
+        let mut bounds = PlotBounds::NOTHING;
+        for chart in &stacked.charts {
+            bounds.merge(&chart.bounds());
+        }
+        assert_eq!(bounds.min[1], -3.0);
+        assert_eq!(bounds.max[1], 3.0);
+
+        // Hidden layers are left out of the stack:
+        let mut stacked = new_stack();
+        stacked.stack(&std::iter::once("a".to_owned()).collect());
+        assert_eq!(bases(&stacked)[1], [Some(0.0), Some(0.0)]);
+    }
+
+    #[test]
+    fn test_stems() {
+        let values: Vec<[f64; 2]> = (0..10_000)
+            .map(|i| [i as f64, 2.0 + (i as f64 * 0.1).sin()])
+            .collect();
+
+        // The bounds include the baseline, even if all points are above it:
+        let stems = Stems::new(values.clone());
+        let bounds = PlotItem::bounds(&stems);
+        assert_eq!(bounds.min()[1], 0.0);
+        assert_eq!(bounds.max()[0], 9999.0);
+        let bounds = PlotItem::bounds(&Stems::new(values.clone()).baseline(5.0));
+        assert_eq!(bounds.max()[1], 5.0);
+        assert!(!PlotItem::bounds(&Stems::new(Vec::<[f64; 2]>::new())).is_finite());
+
+        // All stems and markers are painted as a single mesh:
+        let ctx = egui::Context::default();
+        let _ = ctx.run(RawInput::default(), |ctx| {
+            CentralPanel::default().show(ctx, |ui| {
+                let transform = PlotTransform::new(
+                    Rect::from_min_size(Pos2::ZERO, vec2(800.0, 400.0)),
+                    PlotBounds::from_min_max([0.0, 0.0], [10_000.0, 3.0]),
+                    false,
+                    false,
+                );
+                for stems in [
+                    Stems::new(values.clone()).color(Color32::RED),
+                    Stems::new(values.clone())
+                        .color(Color32::RED)
+                        .shape(MarkerShape::Cross)
+                        .filled(false),
+                ] {
+                    let mut shapes = Vec::new();
+                    stems.shapes(ui, &transform, &mut shapes);
+                    assert_eq!(shapes.len(), 1);
+                    let Shape::Mesh(mesh) = &shapes[0] else {
+                        panic!("expected a mesh");
+                    };
+                    assert!(mesh.vertices.len() > 10_000 * 4);
+                    // The stems reach down to the baseline:
+                    let bottom = mesh.vertices.iter().map(|v| v.pos.y).fold(0.0, f32::max);
+                    assert!((bottom - 400.0).abs() < 1.0, "{bottom}");
+                }
+            });
+        });
+    }
+
+    #[test]
+    fn test_annotation() {
+        let frame = Rect::from_min_size(pos2(0.0, 0.0), vec2(200.0, 100.0));
+        let transform = |min_x: f64| {
+            PlotTransform::new(
+                frame,
+                PlotBounds::from_min_max([min_x, 0.0], [min_x + 10.0, 10.0]),
+                false,
+                false,
+            )
+        };
+        // The text box and the tip of the arrow, if any:
+        let layout = |annotation: &Annotation, transform: &PlotTransform| {
+            let ctx = egui::Context::default();
+            let mut shapes = Vec::new();
+            let _ = ctx.run(RawInput::default(), |ctx| {
+                CentralPanel::default()
+                    .show(ctx, |ui| annotation.shapes(ui, transform, &mut shapes));
+            });
+            let text = shapes.iter().find_map(|shape| match shape {
+                Shape::Text(text) => Some(Rect::from_min_size(text.pos, text.galley.size())),
+                _ => None,
+            });
+            let tip = shapes.iter().find_map(|shape| match shape {
+                Shape::LineSegment { points, .. } => Some(points[1]),
+                _ => None,
+            });
+            (text, tip)
+        };
+
+        // The text box follows the anchor when panning:
+        let annotation = Annotation::new([5.0, 5.0], "deploy").offset_in_points(vec2(30.0, -20.0));
+        let (before, tip) = layout(&annotation, &transform(0.0));
+        let (after, _) = layout(&annotation, &transform(2.0));
+        let (before, after) = (before.unwrap(), after.unwrap());
+        assert_eq!(tip, Some(pos2(100.0, 50.0)));
+        assert!(
+            (before.center().x - 130.0).abs() < 0.01 && (before.center().y - 30.0).abs() < 0.01
+        );
+        assert!((before.center().x - after.center().x - 40.0).abs() < 0.01);
+        assert_eq!(before.center().y, after.center().y);
+
+        // Out of view, the annotation is hidden by default:
+        let outside = transform(20.0);
+        assert_eq!(layout(&annotation, &outside), (None, None));
+
+        // Or clamped to the edge, pointing at the anchor:
+        let clamped = annotation.clone().clip(AnnotationClip::ClampToEdge);
+        let (text, tip) = layout(&clamped, &outside);
+        let (text, tip) = (text.unwrap(), tip.unwrap());
+        assert!(frame.contains_rect(text), "{text:?}");
+        assert_eq!(tip, pos2(0.0, 50.0));
+        assert!(text.left() > tip.x);
+        let (_, no_arrow) = layout(&clamped.clone().arrow(false), &outside);
+        assert_eq!(no_arrow, None);
+
+        // The anchor only extends the bounds on request:
+        assert!(!PlotItem::bounds(&annotation).is_valid());
+        let included = annotation.include_in_auto_bounds(true);
+        assert_eq!(PlotItem::bounds(&included).min(), [5.0, 5.0]);
+    }
+}
@@ -0,0 +1,299 @@
+//! Triangulation of polygons with holes, see [`crate::Polygon::holes`].
+
+use egui::{Pos2, Vec2};
+
+/// Triangulate a simple polygon with holes by ear clipping.
+///
+/// The vertices are numbered as if `outer` and all `holes` were concatenated.
+/// Returns three indices per triangle. Regions inside holes are not covered.
+///
+/// Each hole is first connected to the outer ring by a bridge of two edges,
+/// which turns the polygon into a single ring that ear clipping can handle.
+pub(super) fn triangulate(outer: &[Pos2], holes: &[Vec<Pos2>]) -> Vec<u32> {
+    let positions: Vec<Pos2> = outer
+        .iter()
+        .chain(holes.iter().flatten())
+        .copied()
+        .collect();
+
+    // The outer ring with a positive area, the holes with a negative one.
+    let mut ring: Vec<u32> = (0..outer.len() as u32).collect();
+    if signed_area(&positions, &ring) < 0.0 {
+        ring.reverse();
+    }
+
+    let mut hole_rings = Vec::new();
+    let mut start = outer.len() as u32;
+    for hole in holes {
+        let mut hole_ring: Vec<u32> = (start..start + hole.len() as u32).collect();
+        start += hole.len() as u32;
+        if hole.len() < 3 {
+            continue;
+        }
+        if signed_area(&positions, &hole_ring) > 0.0 {
+            hole_ring.reverse();
+        }
+        hole_rings.push(hole_ring);
+    }
+
+    // Bridge the holes from right to left, so that later holes don't block the bridges.
+    let rightmost = |hole_ring: &Vec<u32>| {
+        hole_ring
+            .iter()
+            .map(|&i| positions[i as usize].x)
+            .fold(f32::NEG_INFINITY, f32::max)
+    };
+    hole_rings.sort_by(|a, b| rightmost(b).total_cmp(&rightmost(a)));
+    for hole_ring in &hole_rings {
+        bridge_hole(&positions, &mut ring, hole_ring);
+    }
+
+    clip_ears(&positions, &ring)
+}
+
+/// Twice the signed area of the ring, positive if counter-clockwise.
+fn signed_area(positions: &[Pos2], ring: &[u32]) -> f32 {
+    (0..ring.len())
+        .map(|i| {
+            let a = positions[ring[i] as usize];
+            let b = positions[ring[(i + 1) % ring.len()] as usize];
+            a.x * b.y - b.x * a.y
+        })
+        .sum()
+}
+
+/// Connect the hole to the ring, from the rightmost vertex of the hole to a ring vertex it can see.
+///
+/// This is the method of David Eberly, "Triangulation by Ear Clipping":
+/// cast a ray from the hole to the right, and take the ring edge it hits first.
+/// Its rightmost vertex is visible, unless a reflex vertex of the ring is in the way.
+/// Then the reflex vertex closest in angle to the ray is visible instead.
+fn bridge_hole(positions: &[Pos2], ring: &mut Vec<u32>, hole: &[u32]) {
+    let pos = |i: u32| positions[i as usize];
+    let n = ring.len();
+
+    let Some(h) = (0..hole.len()).max_by(|&a, &b| pos(hole[a]).x.total_cmp(&pos(hole[b]).x)) else {
+        return;
+    };
+    let m = pos(hole[h]);
+
+    // The closest hit of the ray, and the ring vertex to bridge to if nothing is in the way.
+    let mut hit: Option<(f32, usize)> = None;
+    for r in 0..n {
+        let (a, b) = (pos(ring[r]), pos(ring[(r + 1) % n]));
+        let x = if a.y == m.y {
+            a.x
+        } else if (a.y < m.y) != (b.y < m.y) && b.y != m.y {
+            a.x + (m.y - a.y) / (b.y - a.y) * (b.x - a.x)
+        } else {
+            continue;
+        };
+        if m.x <= x && hit.map_or(true, |(best, _)| x < best) {
+            let vertex = if a.y == m.y || b.x < a.x {
+                r
+            } else {
+                (r + 1) % n
+            };
+            hit = Some((x, vertex));
+        }
+    }
+    let Some((x, mut r)) = hit else {
+        return; // Degenerate, e.g. the hole is outside of the polygon.
+    };
+
+    let p = pos(ring[r]);
+    if p.y != m.y {
+        let i = Pos2::new(x, m.y);
+        let is_reflex = |k: usize| {
+            let (a, b, c) = (ring[(k + n - 1) % n], ring[k], ring[(k + 1) % n]);
+            cross(pos(b) - pos(a), pos(c) - pos(b)) <= 0.0
+        };
+        let mut best_tan = f32::INFINITY;
+        for k in 0..n {
+            let v = pos(ring[k]);
+            if v == p || !is_reflex(k) || !(in_triangle(v, m, i, p) || in_triangle(v, m, p, i)) {
+                continue;
+            }
+            let tan = (v.y - m.y).abs() / (v.x - m.x);
+            if tan < best_tan || (tan == best_tan && v.x < pos(ring[r]).x) {
+                best_tan = tan;
+                r = k;
+            }
+        }
+    }
+
+    // ring[..=r], hole[h..], hole[..=h], ring[r..]
+    let mut merged = Vec::with_capacity(ring.len() + hole.len() + 2);
+    merged.extend_from_slice(&ring[..=r]);
+    merged.extend_from_slice(&hole[h..]);
+    merged.extend_from_slice(&hole[..=h]);
+    merged.extend_from_slice(&ring[r..]);
+    *ring = merged;
+}
+
+/// Cut off convex corners which contain no other vertex, until only one triangle is left.
+///
+/// Only reflex vertices can be inside an ear, so only those are tested.
+fn clip_ears(positions: &[Pos2], ring: &[u32]) -> Vec<u32> {
+    let n = ring.len();
+    if n < 3 {
+        return Vec::new();
+    }
+    let pos = |k: usize| positions[ring[k] as usize];
+    let mut indices = Vec::with_capacity(3 * (n - 2));
+
+    // The ring as a linked list of slots in `ring`:
+    let mut prev: Vec<usize> = (0..n).map(|k| (k + n - 1) % n).collect();
+    let mut next: Vec<usize> = (0..n).map(|k| (k + 1) % n).collect();
+    let corner = |prev: &[usize], next: &[usize], k: usize| {
+        cross(pos(k) - pos(prev[k]), pos(next[k]) - pos(k))
+    };
+    let mut is_reflex: Vec<bool> = (0..n).map(|k| corner(&prev, &next, k) <= 0.0).collect();
+    let reflex: Vec<usize> = (0..n).filter(|&k| is_reflex[k]).collect();
+
+    let mut remaining = n;
+    let mut k = 0;
+    let mut stalled = 0; // Corners visited since the last cut.
+    while remaining > 3 {
+        let (a, c) = (prev[k], next[k]);
+        let (pa, pk, pc) = (pos(a), pos(k), pos(c));
+        let is_ear = || {
+            corner(&prev, &next, k) > 0.0
+                && !reflex.iter().any(|&v| {
+                    let p = pos(v);
+                    is_reflex[v] && p != pa && p != pk && p != pc && in_triangle(p, pa, pk, pc)
+                })
+        };
+        // Degenerate rings may have no ears, then we cut off any convex corner, or any corner at all.
+        let cut = is_ear()
+            || (remaining <= stalled && corner(&prev, &next, k) >= 0.0)
+            || 2 * remaining <= stalled;
+        if cut {
+            indices.extend_from_slice(&[ring[a], ring[k], ring[c]]);
+            next[a] = c;
+            prev[c] = a;
+            is_reflex[k] = false;
+            remaining -= 1;
+            for v in [a, c] {
+                if is_reflex[v] && corner(&prev, &next, v) > 0.0 {
+                    is_reflex[v] = false;
+                }
+            }
+            stalled = 0;
+            k = c;
+        } else {
+            stalled += 1;
+            k = next[k];
+        }
+    }
+    indices.extend_from_slice(&[ring[prev[k]], ring[k], ring[next[k]]]);
+    indices
+}
+
+fn cross(a: Vec2, b: Vec2) -> f32 {
+    a.x * b.y - a.y * b.x
+}
+
+/// Is `p` inside or on the triangle `a`, `b`, `c` with positive area?
+fn in_triangle(p: Pos2, a: Pos2, b: Pos2, c: Pos2) -> bool {
+    cross(b - a, p - a) >= 0.0 && cross(c - b, p - b) >= 0.0 && cross(a - c, p - c) >= 0.0
+}
+
+/// Is the point inside the polygon? Uses the even-odd rule.
+pub(super) fn contains(polygon: &[Pos2], p: Pos2) -> bool {
+    let ring: Vec<u32> = (0..polygon.len() as u32).collect();
+    is_inside(polygon, &ring, p)
+}
+
+/// Is the point inside the ring? Uses the even-odd rule.
+fn is_inside(positions: &[Pos2], ring: &[u32], p: Pos2) -> bool {
+    let mut inside = false;
+    for i in 0..ring.len() {
+        let a = positions[ring[i] as usize];
+        let b = positions[ring[(i + 1) % ring.len()] as usize];
+        if (a.y > p.y) != (b.y > p.y) && p.x < a.x + (p.y - a.y) / (b.y - a.y) * (b.x - a.x) {
+            inside = !inside;
+        }
+    }
+    inside
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_triangulate_many_vertices() {
+        let circle = |center: Pos2, radius: f32, n: usize| -> Vec<Pos2> {
+            (0..n)
+                .map(|i| {
+                    let angle = i as f32 / n as f32 * std::f32::consts::TAU;
+                    center + radius * Vec2::angled(angle)
+                })
+                .collect()
+        };
+        // A star, with deep notches that make half of its vertices reflex:
+        let outer: Vec<Pos2> = (0..2000)
+            .map(|i| {
+                let angle = i as f32 / 2000.0 * std::f32::consts::TAU;
+                let radius = if i % 2 == 0 { 12.0 } else { 10.0 };
+                Pos2::ZERO + radius * Vec2::angled(angle)
+            })
+            .collect();
+        let holes: Vec<Vec<Pos2>> = [(5.0, 0.0), (-5.0, 0.0), (0.0, 5.0), (0.0, -5.0)]
+            .map(|(x, y)| circle(Pos2::new(x, y), 1.5, 500))
+            .into();
+
+        let indices = triangulate(&outer, &holes);
+        let positions: Vec<Pos2> = outer
+            .iter()
+            .chain(holes.iter().flatten())
+            .copied()
+            .collect();
+        let num_vertices = positions.len();
+        assert_eq!(indices.len(), 3 * (num_vertices + 2 * holes.len() - 2));
+
+        // The triangles don't overlap and cover the star, except for the holes:
+        let ring = |n: usize| (0..n as u32).collect::<Vec<_>>();
+        let expected = signed_area(&outer, &ring(outer.len()))
+            - holes
+                .iter()
+                .map(|hole| signed_area(hole, &ring(hole.len())))
+                .sum::<f32>();
+        let mut area = 0.0;
+        for triangle in indices.chunks_exact(3) {
+            let [a, b, c] = [0, 1, 2].map(|i| positions[triangle[i] as usize]);
+            let twice_area = cross(b - a, c - a);
+            assert!(-1e-4 <= twice_area, "{a:?} {b:?} {c:?}");
+            area += twice_area;
+        }
+        assert!(
+            (area - expected).abs() < 1e-3 * expected,
+            "{area} != {expected}"
+        );
+    }
+
+    #[test]
+    fn test_triangulate_square_with_hole() {
+        let outer = [(0.0, 0.0), (10.0, 0.0), (10.0, 10.0), (0.0, 10.0)].map(Pos2::from);
+        let hole = [(4.0, 4.0), (6.0, 4.0), (6.0, 6.0), (4.0, 6.0)].map(Pos2::from);
+        let indices = triangulate(&outer, &[hole.to_vec()]);
+        let positions: Vec<Pos2> = outer.iter().chain(&hole).copied().collect();
+
+        // The triangles cover the square except for the hole:
+        let mut area = 0.0;
+        for triangle in indices.chunks_exact(3) {
+            let [a, b, c] = [0, 1, 2].map(|i| positions[triangle[i] as usize]);
+            area += cross(b - a, c - a).abs() / 2.0;
+            let centroid = Pos2::new((a.x + b.x + c.x) / 3.0, (a.y + b.y + c.y) / 3.0);
+            assert!(
+                !contains(&hole, centroid),
+                "Triangle {a:?} {b:?} {c:?} is in the hole"
+            );
+        }
+        assert!(
+            (area - (10.0 * 10.0 - 2.0 * 2.0)).abs() < 1e-3,
+            "area: {area}"
+        );
+    }
+}
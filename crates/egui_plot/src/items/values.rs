@@ -1,6 +1,6 @@
 use std::ops::{Bound, RangeBounds, RangeInclusive};
 
-use egui::{Pos2, Shape, Stroke, Vec2};
+use egui::{Color32, Pos2, Shape, Stroke, Vec2};
 
 use crate::transform::PlotBounds;
 
@@ -135,6 +135,220 @@ impl std::fmt::Display for LineStyle {
 
 // ----------------------------------------------------------------------------
 
+/// Where the label of an [`crate::HLine`] or [`crate::VLine`] is placed.
+///
+/// For an [`crate::HLine`], left and right are its ends, above and below its sides.
+/// For a [`crate::VLine`], above and below are its ends, left and right its sides.
+#[derive(Debug, Default, PartialEq, Eq, Clone, Copy)]
+pub enum LabelPosition {
+    #[default]
+    LeftAbove,
+    LeftBelow,
+    RightAbove,
+    RightBelow,
+}
+
+impl LabelPosition {
+    pub(crate) fn is_left(self) -> bool {
+        matches!(self, Self::LeftAbove | Self::LeftBelow)
+    }
+
+    pub(crate) fn is_above(self) -> bool {
+        matches!(self, Self::LeftAbove | Self::RightAbove)
+    }
+}
+
+// ----------------------------------------------------------------------------
+
+/// Maps normalized values to colors, e.g. for a [`crate::HeatMap`] or [`crate::Line::gradient_by`].
+#[derive(Debug, Default, PartialEq, Eq, Hash, Clone)]
+pub enum Colormap {
+    /// Perceptually uniform, from dark blue over green to yellow.
+    #[default]
+    Viridis,
+
+    /// From black to white.
+    Grayscale,
+
+    /// A rainbow colormap from dark blue over green to dark red, with a high contrast.
+    Turbo,
+
+    /// Evenly spaced color stops, interpolated linearly in gamma space.
+    Custom(Vec<Color32>),
+}
+
+impl Colormap {
+    /// The color of `t`, which is clamped to `0.0..=1.0`.
+    pub fn color(&self, t: f32) -> Color32 {
+        let t = t.clamp(0.0, 1.0);
+        let [r, g, b] = match self {
+            Self::Grayscale => [t; 3],
+            Self::Viridis => VIRIDIS.map(|coefficients| poly(t, coefficients)),
+            Self::Turbo => TURBO.map(|coefficients| poly(t, coefficients)),
+            Self::Custom(stops) => return custom_color(stops, t),
+        };
+        let to_u8 = |c: f32| (c.clamp(0.0, 1.0) * 255.0).round() as u8;
+        Color32::from_rgb(to_u8(r), to_u8(g), to_u8(b))
+    }
+}
+
+// Polynomial approximations of the sRGB channels, lowest order first.
+const VIRIDIS: [[f32; 7]; 3] = [
+    [0.2777, 0.1051, -0.3309, -4.6342, 6.2283, 4.7764, -5.4355],
+    [0.0054, 1.4046, 0.2148, -5.7991, 14.1799, -13.7451, 4.6459],
+    [0.3341, 1.3846, 0.0951, -19.3324, 56.6906, -65.3530, 26.3124],
+];
+const TURBO: [[f32; 7]; 3] = [
+    [0.1357, 4.6154, -42.6603, 132.1311, -152.9424, 59.2864, 0.0],
+    [0.0914, 2.1942, 4.8430, -14.1850, 4.2773, 2.8296, 0.0],
+    [0.1067, 12.6419, -60.5820, 110.3628, -89.9031, 27.3482, 0.0],
+];
+
+fn custom_color(stops: &[Color32], t: f32) -> Color32 {
+    match stops {
+        [] => Color32::TRANSPARENT,
+        [color] => *color,
+        _ => {
+            let position = t * (stops.len() - 1) as f32;
+            let i = (position.floor() as usize).min(stops.len() - 2);
+            stops[i].lerp_to_gamma(stops[i + 1], position - i as f32)
+        }
+    }
+}
+
+fn poly(t: f32, coefficients: [f32; 7]) -> f32 {
+    coefficients.iter().rev().fold(0.0, |acc, c| acc * t + c)
+}
+
+// ----------------------------------------------------------------------------
+
+/// The error of a single value, see [`crate::ErrorBars`].
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub enum ErrorValue {
+    /// The value is known up to `± error`.
+    Symmetric(f64),
+
+    /// The value lies between `value - low` and `value + high`.
+    Asymmetric { low: f64, high: f64 },
+}
+
+impl ErrorValue {
+    /// The extents `(low, high)` below and above the value.
+    pub fn extents(self) -> (f64, f64) {
+        match self {
+            Self::Symmetric(error) => (error, error),
+            Self::Asymmetric { low, high } => (low, high),
+        }
+    }
+}
+
+impl From<f64> for ErrorValue {
+    fn from(error: f64) -> Self {
+        Self::Symmetric(error)
+    }
+}
+
+impl From<(f64, f64)> for ErrorValue {
+    /// `(low, high)`
+    fn from((low, high): (f64, f64)) -> Self {
+        Self::Asymmetric { low, high }
+    }
+}
+
+// ----------------------------------------------------------------------------
+
+/// Which Y axis a plot item is drawn against, see [`crate::Plot::secondary_y_axis`].
+#[derive(Debug, Default, PartialEq, Eq, Clone, Copy)]
+pub enum YAxis {
+    /// The main Y axis of the plot.
+    #[default]
+    Primary,
+
+    /// The secondary Y axis, with its own bounds, shown on the right side of the plot.
+    Secondary,
+}
+
+// ----------------------------------------------------------------------------
+
+/// How to reduce the number of points of a [`crate::Line`] before it is drawn.
+#[derive(Debug, Default, PartialEq, Eq, Clone, Copy)]
+pub enum DownsampleMode {
+    /// Draw every point.
+    #[default]
+    None,
+
+    /// For each pixel column, only keep the first, lowest, highest and last point.
+    ///
+    /// The drawn envelope looks the same as with all points, but lines with millions of points
+    /// become much cheaper to draw. Only kicks in when there are several points per pixel column.
+    MinMax,
+}
+
+impl DownsampleMode {
+    /// Only downsample when there are more than this many points per pixel column on average.
+    const MIN_POINTS_PER_COLUMN: f32 = 4.0;
+
+    /// Reduce the given screen-space line, which spans a plot frame of `frame_width` points.
+    pub(crate) fn downsample(self, line: Vec<Pos2>, frame_width: f32) -> Vec<Pos2> {
+        match self {
+            Self::None => line,
+            Self::MinMax => {
+                if (line.len() as f32) < Self::MIN_POINTS_PER_COLUMN * frame_width {
+                    line
+                } else {
+                    downsample_min_max(&line)
+                }
+            }
+        }
+    }
+}
+
+/// Replace each run of consecutive points in the same pixel column
+/// with its first, lowest, highest and last point, in their original order.
+///
+/// Points that are not finite are kept on their own, and end the run they are in.
+fn downsample_min_max(line: &[Pos2]) -> Vec<Pos2> {
+    let mut out = Vec::new();
+    let mut rest = line;
+    while let Some(first) = rest.first() {
+        let column = first.x.floor();
+        let run_len = rest
+            .iter()
+            .position(|p| !p.is_finite() || p.x.floor() != column)
+            .unwrap_or(rest.len())
+            .max(1); // Always make progress, e.g. on a point that is not finite.
+        let (run, tail) = rest.split_at(run_len);
+        rest = tail;
+
+        if run.len() <= 4 {
+            out.extend_from_slice(run);
+            continue;
+        }
+
+        let (mut min, mut max) = (0, 0);
+        for (i, p) in run.iter().enumerate() {
+            if p.y < run[min].y {
+                min = i;
+            }
+            if run[max].y < p.y {
+                max = i;
+            }
+        }
+        let mut indices = [0, min, max, run.len() - 1];
+        indices.sort_unstable();
+        let mut last_index = None;
+        for i in indices {
+            if last_index != Some(i) {
+                out.push(run[i]);
+                last_index = Some(i);
+            }
+        }
+    }
+    out
+}
+
+// ----------------------------------------------------------------------------
+
 /// Determines whether a plot element is vertically or horizontally oriented.
 #[derive(Copy, Clone, Debug, PartialEq, Eq)]
 pub enum Orientation {
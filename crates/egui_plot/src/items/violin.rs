@@ -0,0 +1,406 @@
+use egui::emath::NumExt as _;
+use egui::epaint::{Color32, Mesh, RectShape, Rounding, Shape, Stroke};
+
+use crate::{BoxSpread, Cursor, PlotPoint, PlotTransform, Violin};
+
+use super::{
+    add_rulers_and_text, highlighted_color, histogram::quantile, Orientation, PlotConfig,
+    RectElement,
+};
+
+/// The number of values at which the density of a [`ViolinElem`] is estimated.
+const DENSITY_POINTS: usize = 101;
+
+/// How far the density is estimated beyond the smallest and largest sample, in bandwidths.
+const DENSITY_CUT: f64 = 3.0;
+
+/// How the violins of a [`Violin`] plot are scaled to their width.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum ViolinScale {
+    /// All violins have the same area, so the widest density of all violins is as wide as its [`ViolinElem::width`].
+    #[default]
+    Area,
+
+    /// Each violin is as wide as its [`ViolinElem::width`].
+    Width,
+}
+
+/// A violin in a [`Violin`] diagram, showing the distribution of some samples.
+///
+/// The density is a Gaussian kernel density estimate of the samples,
+/// see [`Self::bandwidth`]. Samples that are not finite are ignored.
+#[derive(Clone, Debug, PartialEq)]
+pub struct ViolinElem {
+    /// Name of plot element in the diagram (annotated by default formatter).
+    pub name: String,
+
+    /// Which direction the violin faces in the diagram.
+    pub orientation: Orientation,
+
+    /// Position on the argument (input) axis -- X if vertical, Y if horizontal.
+    pub argument: f64,
+
+    /// The largest thickness of the violin, see [`ViolinScale`].
+    pub width: f64,
+
+    /// Line width and color
+    pub stroke: Stroke,
+
+    /// Fill color
+    pub fill: Color32,
+
+    samples: Vec<f64>,
+    bandwidth: f64,
+    density: Vec<[f64; 2]>,
+    spread: BoxSpread,
+}
+
+impl ViolinElem {
+    /// Create a violin of the `samples`. Its `orientation` is set by its [`Violin`] parent.
+    pub fn new(argument: f64, samples: &[f64]) -> Self {
+        let mut samples: Vec<f64> = samples.iter().copied().filter(|x| x.is_finite()).collect();
+        samples.sort_by(f64::total_cmp);
+        let spread = if samples.is_empty() {
+            BoxSpread::new(0.0, 0.0, 0.0, 0.0, 0.0)
+        } else {
+            BoxSpread::new(
+                samples[0],
+                quantile(&samples, 0.25),
+                quantile(&samples, 0.5),
+                quantile(&samples, 0.75),
+                samples[samples.len() - 1],
+            )
+        };
+        let mut elem = Self {
+            name: String::default(),
+            orientation: Orientation::default(),
+            argument,
+            width: 0.5,
+            stroke: Stroke::new(1.0, Color32::TRANSPARENT),
+            fill: Color32::TRANSPARENT,
+            bandwidth: silverman_bandwidth(&samples, &spread),
+            samples,
+            density: Vec::new(),
+            spread,
+        };
+        elem.estimate_density();
+        elem
+    }
+
+    /// Name of this violin.
+    #[allow(clippy::needless_pass_by_value)]
+    #[inline]
+    pub fn name(mut self, name: impl ToString) -> Self {
+        self.name = name.to_string();
+        self
+    }
+
+    /// Set the bandwidth of the Gaussian kernel, i.e. its standard deviation.
+    ///
+    /// Default: Silverman's rule of thumb, `0.9 * min(σ, IQR / 1.34) * n^(-1/5)`.
+    pub fn bandwidth(mut self, bandwidth: f64) -> Self {
+        if bandwidth > 0.0 && bandwidth.is_finite() {
+            self.bandwidth = bandwidth;
+            self.estimate_density();
+        }
+        self
+    }
+
+    /// Add a custom stroke.
+    #[inline]
+    pub fn stroke(mut self, stroke: impl Into<Stroke>) -> Self {
+        self.stroke = stroke.into();
+        self
+    }
+
+    /// Add a custom fill color.
+    #[inline]
+    pub fn fill(mut self, color: impl Into<Color32>) -> Self {
+        self.fill = color.into();
+        self
+    }
+
+    /// Set the largest thickness of the violin. Default: `0.5`.
+    #[inline]
+    pub fn width(mut self, width: f64) -> Self {
+        self.width = width;
+        self
+    }
+
+    /// Set orientation of the element as vertical. Argument axis is X.
+    #[inline]
+    pub fn vertical(mut self) -> Self {
+        self.orientation = Orientation::Vertical;
+        self
+    }
+
+    /// Set orientation of the element as horizontal. Argument axis is Y.
+    #[inline]
+    pub fn horizontal(mut self) -> Self {
+        self.orientation = Orientation::Horizontal;
+        self
+    }
+
+    /// The number of finite samples.
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.samples.len()
+    }
+
+    /// Whether there are no finite samples.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.samples.is_empty()
+    }
+
+    /// The minimum, quartiles, median and maximum of the samples.
+    #[inline]
+    pub fn spread(&self) -> &BoxSpread {
+        &self.spread
+    }
+
+    /// The estimated density as `[value, density]` pairs, sorted by value.
+    ///
+    /// The density integrates to one.
+    #[inline]
+    pub fn density(&self) -> &[[f64; 2]] {
+        &self.density
+    }
+
+    pub(super) fn max_density(&self) -> f64 {
+        self.density.iter().map(|d| d[1]).fold(0.0, f64::max)
+    }
+
+    fn estimate_density(&mut self) {
+        self.density.clear();
+        let (Some(&min), Some(&max)) = (self.samples.first(), self.samples.last()) else {
+            return;
+        };
+        let h = self.bandwidth;
+        let (start, end) = (min - DENSITY_CUT * h, max + DENSITY_CUT * h);
+        let norm = 1.0 / (self.samples.len() as f64 * h * std::f64::consts::TAU.sqrt());
+        self.density = (0..DENSITY_POINTS)
+            .map(|i| {
+                let value = start + (end - start) * i as f64 / (DENSITY_POINTS - 1) as f64;
+                let sum: f64 = self
+                    .samples
+                    .iter()
+                    .map(|x| (-0.5 * ((value - x) / h).powi(2)).exp())
+                    .sum();
+                [value, sum * norm]
+            })
+            .collect();
+    }
+
+    /// `scale` converts densities to half the thickness of the violin.
+    pub(super) fn add_shapes(
+        &self,
+        transform: &PlotTransform,
+        scale: f64,
+        inner_box: bool,
+        highlighted: bool,
+        shapes: &mut Vec<Shape>,
+    ) {
+        if self.density.is_empty() {
+            return;
+        }
+        let (stroke, fill) = if highlighted {
+            highlighted_color(self.stroke, self.fill)
+        } else {
+            (self.stroke, self.fill)
+        };
+        let position =
+            |argument, value| transform.position_from_point(&self.point_at(argument, value));
+
+        // The area between both sides, as quads between neighboring values:
+        let mut mesh = Mesh::default();
+        for &[value, density] in &self.density {
+            let half = density * scale;
+            let i = mesh.vertices.len() as u32;
+            mesh.colored_vertex(position(self.argument - half, value), fill);
+            mesh.colored_vertex(position(self.argument + half, value), fill);
+            if i > 0 {
+                mesh.add_triangle(i - 2, i - 1, i);
+                mesh.add_triangle(i - 1, i + 1, i);
+            }
+        }
+        shapes.push(Shape::Mesh(mesh));
+
+        let outline = self
+            .density
+            .iter()
+            .map(|&[value, density]| position(self.argument + density * scale, value))
+            .chain(
+                self.density
+                    .iter()
+                    .rev()
+                    .map(|&[value, density]| position(self.argument - density * scale, value)),
+            )
+            .collect();
+        shapes.push(Shape::closed_line(outline, stroke));
+
+        if inner_box {
+            let half = self.width / 20.0;
+            let rect = transform.rect_from_values(
+                &self.point_at(self.argument - half, self.spread.quartile1),
+                &self.point_at(self.argument + half, self.spread.quartile3),
+            );
+            shapes.push(Shape::Rect(RectShape::filled(
+                rect,
+                Rounding::ZERO,
+                stroke.color,
+            )));
+            let median = self.spread.median;
+            let half = half * 2.0;
+            shapes.push(Shape::line_segment(
+                [
+                    position(self.argument - half, median),
+                    position(self.argument + half, median),
+                ],
+                Stroke::new(stroke.width.at_least(1.0) * 2.0, stroke.color),
+            ));
+        }
+    }
+
+    pub(super) fn add_rulers_and_text(
+        &self,
+        parent: &Violin,
+        plot: &PlotConfig<'_>,
+        shapes: &mut Vec<Shape>,
+        cursors: &mut Vec<Cursor>,
+    ) {
+        let text: Option<String> = parent
+            .element_formatter
+            .as_ref()
+            .map(|fmt| fmt(self, parent));
+
+        add_rulers_and_text(self, plot, text, shapes, cursors);
+    }
+}
+
+impl RectElement for ViolinElem {
+    fn name(&self) -> &str {
+        self.name.as_str()
+    }
+
+    fn bounds_min(&self) -> PlotPoint {
+        let value = self
+            .density
+            .first()
+            .map_or(self.spread.lower_whisker, |d| d[0]);
+        self.point_at(self.argument - self.width / 2.0, value)
+    }
+
+    fn bounds_max(&self) -> PlotPoint {
+        let value = self
+            .density
+            .last()
+            .map_or(self.spread.upper_whisker, |d| d[0]);
+        self.point_at(self.argument + self.width / 2.0, value)
+    }
+
+    fn values_with_ruler(&self) -> Vec<PlotPoint> {
+        let median = self.point_at(self.argument, self.spread.median);
+        let q1 = self.point_at(self.argument, self.spread.quartile1);
+        let q3 = self.point_at(self.argument, self.spread.quartile3);
+
+        vec![median, q1, q3]
+    }
+
+    fn orientation(&self) -> Orientation {
+        self.orientation
+    }
+
+    fn corner_value(&self) -> PlotPoint {
+        self.point_at(self.argument, self.spread.upper_whisker)
+    }
+
+    fn default_values_format(&self, transform: &PlotTransform) -> String {
+        let scale = transform.dvalue_dpos();
+        let scale = match self.orientation {
+            Orientation::Horizontal => scale[0],
+            Orientation::Vertical => scale[1],
+        };
+        let y_decimals = ((-scale.abs().log10()).ceil().at_least(0.0) as usize)
+            .at_most(6)
+            .at_least(1);
+        format!(
+            "n = {n}\
+             \nQuartile 3 = {q3:.decimals$}\
+             \nMedian = {med:.decimals$}\
+             \nQuartile 1 = {q1:.decimals$}",
+            n = self.samples.len(),
+            q3 = self.spread.quartile3,
+            med = self.spread.median,
+            q1 = self.spread.quartile1,
+            decimals = y_decimals
+        )
+    }
+}
+
+/// Silverman's rule of thumb for the bandwidth of a Gaussian kernel.
+///
+/// Falls back to the standard deviation, or one, if the samples are too alike.
+fn silverman_bandwidth(samples: &[f64], spread: &BoxSpread) -> f64 {
+    let n = samples.len() as f64;
+    if samples.len() < 2 {
+        return 1.0;
+    }
+    let mean = samples.iter().sum::<f64>() / n;
+    let std_dev = (samples.iter().map(|x| (x - mean).powi(2)).sum::<f64>() / (n - 1.0)).sqrt();
+    let iqr = spread.quartile3 - spread.quartile1;
+    let sigma = if iqr > 0.0 {
+        std_dev.min(iqr / 1.34)
+    } else {
+        std_dev
+    };
+    if sigma > 0.0 {
+        0.9 * sigma * n.powf(-0.2)
+    } else {
+        1.0
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_violin_density() {
+        // The density of symmetric samples is symmetric, and integrates to one:
+        let violin = ViolinElem::new(0.0, &[-3.0, -1.0, 0.0, 1.0, 3.0, f64::NAN]);
+        assert_eq!(violin.len(), 5);
+        assert_eq!(violin.spread().median, 0.0);
+        let density = violin.density();
+        for (low, high) in density.iter().zip(density.iter().rev()) {
+            assert!((low[0] + high[0]).abs() < 1e-9);
+            assert!((low[1] - high[1]).abs() < 1e-12);
+        }
+        let integral: f64 = density
+            .windows(2)
+            .map(|d| (d[1][0] - d[0][0]) * (d[0][1] + d[1][1]) / 2.0)
+            .sum();
+        assert!((integral - 1.0).abs() < 0.01, "{integral}");
+
+        // A manual bandwidth smooths the peaks:
+        let bimodal = [0.0, 0.1, -0.1, 5.0, 5.1, 4.9];
+        let peak = |violin: &ViolinElem| violin.density().iter().map(|d| d[1]).fold(0.0, f64::max);
+        let sharp = ViolinElem::new(0.0, &bimodal);
+        let smooth = ViolinElem::new(0.0, &bimodal).bandwidth(3.0);
+        assert!(peak(&smooth) < peak(&sharp));
+    }
+
+    #[test]
+    fn test_silverman_bandwidth() {
+        let samples = [-3.0, -1.0, 0.0, 1.0, 3.0];
+        let violin = ViolinElem::new(0.0, &samples);
+        let bandwidth = silverman_bandwidth(&samples, violin.spread());
+        // sigma = min(std_dev = 2.236, iqr / 1.34 = 1.493):
+        assert!((bandwidth - 0.9 * 2.0 / 1.34 * 5f64.powf(-0.2)).abs() < 1e-9);
+
+        // Too few, or too alike, samples:
+        assert_eq!(silverman_bandwidth(&[1.0], violin.spread()), 1.0);
+        let constant = ViolinElem::new(0.0, &[2.0; 4]);
+        assert_eq!(silverman_bandwidth(&[2.0; 4], constant.spread()), 1.0);
+    }
+}
@@ -26,6 +26,16 @@ impl Corner {
     }
 }
 
+/// Where to place the plot legend outside of the plot, see [`Legend::position_outside`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum OuterPosition {
+    /// To the right of the plot, with the entries below each other.
+    Right,
+
+    /// Below the plot, with the entries wrapped into columns.
+    Bottom,
+}
+
 /// The configuration for a plot legend.
 #[derive(Clone, PartialEq)]
 pub struct Legend {
@@ -33,8 +43,15 @@ pub struct Legend {
     pub background_alpha: f32,
     pub position: Corner,
 
-    /// Used for overriding the `hidden_items` set in [`LegendWidget`].
-    hidden_items: Option<ahash::HashSet<String>>,
+    /// The maximum height of the legend, see [`Self::max_height`].
+    pub max_height: Option<f32>,
+
+    /// Place the legend outside of the plot instead of in [`Self::position`],
+    /// see [`Self::position_outside`].
+    pub outside: Option<OuterPosition>,
+
+    /// Used for overriding the `hidden_items` set in the plot memory.
+    pub(crate) hidden_items: Option<ahash::HashSet<String>>,
 }
 
 impl Default for Legend {
@@ -43,6 +60,8 @@ impl Default for Legend {
             text_style: TextStyle::Body,
             background_alpha: 0.75,
             position: Corner::RightTop,
+            max_height: None,
+            outside: None,
 
             hidden_items: None,
         }
@@ -71,8 +90,30 @@ impl Legend {
         self
     }
 
+    /// Place the legend next to or below the plot, so it doesn't hide any data.
+    ///
+    /// The space for it is taken from the plot, so [`crate::PlotResponse::response`]
+    /// covers only the remaining plot area. This overrides [`Self::position`].
+    #[inline]
+    pub fn position_outside(mut self, position: OuterPosition) -> Self {
+        self.outside = Some(position);
+        self
+    }
+
+    /// The maximum height of the legend, beyond which its entries can be scrolled.
+    ///
+    /// Default: three quarters of the plot height.
+    #[inline]
+    pub fn max_height(mut self, max_height: f32) -> Self {
+        self.max_height = Some(max_height);
+        self
+    }
+
     /// Specifies hidden items in the legend configuration to override the existing ones. This
     /// allows the legend traces' visibility to be controlled from the application code.
+    ///
+    /// To keep clicking the legend entries working, store [`crate::PlotResponse::hidden_items`]
+    /// in your app and pass it back here the next frame.
     #[inline]
     pub fn hidden_items<I>(mut self, hidden_items: I) -> Self
     where
@@ -88,33 +129,56 @@ struct LegendEntry {
     color: Color32,
     checked: bool,
     hovered: bool,
+
+    /// Is the item plotted against the secondary Y axis?
+    secondary: bool,
+
+    /// Shown as a gradient instead of the color, see [`crate::Line::gradient_by`].
+    colormap: Option<Colormap>,
 }
 
 impl LegendEntry {
-    fn new(color: Color32, checked: bool) -> Self {
+    fn new(color: Color32, checked: bool, secondary: bool, colormap: Option<Colormap>) -> Self {
         Self {
             color,
             checked,
             hovered: false,
+            secondary,
+            colormap,
         }
     }
 
+    fn galley(&self, ui: &Ui, text: String, text_style: &TextStyle) -> Arc<Galley> {
+        let text = if self.secondary {
+            format!("{text} (y2)")
+        } else {
+            text
+        };
+        let font_id = text_style.resolve(ui.style());
+        ui.fonts(|f| f.layout_delayed_color(text, font_id, f32::INFINITY))
+    }
+
+    /// The size of the entry: the text and a round checkbox icon as high as the text.
+    fn desired_size(galley: &Galley) -> Vec2 {
+        let icon_size = galley.size().y;
+        let icon_spacing = icon_size / 5.0;
+        vec2(icon_size + icon_spacing, 0.0) + galley.size()
+    }
+
     fn ui(&self, ui: &mut Ui, text: String, text_style: &TextStyle) -> Response {
         let Self {
             color,
             checked,
-            hovered: _,
+            colormap,
+            ..
         } = self;
 
-        let font_id = text_style.resolve(ui.style());
-
-        let galley = ui.fonts(|f| f.layout_delayed_color(text, font_id, f32::INFINITY));
+        let galley = self.galley(ui, text, text_style);
 
         let icon_size = galley.size().y;
         let icon_spacing = icon_size / 5.0;
-        let total_extra = vec2(icon_size + icon_spacing, 0.0);
 
-        let desired_size = total_extra + galley.size();
+        let desired_size = Self::desired_size(&galley);
         let (rect, response) = ui.allocate_exact_size(desired_size, Sense::click());
 
         response
@@ -140,7 +204,11 @@ impl LegendEntry {
             stroke: visuals.bg_stroke,
         });
 
-        if *checked {
+        if let (true, Some(colormap)) = (*checked, colormap) {
+            let bar_rect =
+                Rect::from_center_size(icon_rect.center(), vec2(icon_size * 0.8, icon_size * 0.4));
+            painter.add(gradient_bar(bar_rect, colormap));
+        } else if *checked {
             let fill = if *color == Color32::TRANSPARENT {
                 ui.visuals().noninteractive().fg_stroke.color
             } else {
@@ -166,25 +234,52 @@ impl LegendEntry {
     }
 }
 
+/// A horizontal bar going through the colormap from left to right.
+fn gradient_bar(rect: Rect, colormap: &Colormap) -> Mesh {
+    const SEGMENTS: u32 = 8;
+    let mut mesh = Mesh::default();
+    for i in 0..=SEGMENTS {
+        let t = i as f32 / SEGMENTS as f32;
+        let x = lerp(rect.x_range(), t);
+        let color = colormap.color(t);
+        mesh.colored_vertex(pos2(x, rect.top()), color);
+        mesh.colored_vertex(pos2(x, rect.bottom()), color);
+        if i > 0 {
+            let v = 2 * i;
+            mesh.add_triangle(v - 2, v - 1, v);
+            mesh.add_triangle(v - 1, v, v + 1);
+        }
+    }
+    mesh
+}
+
 #[derive(Clone)]
 pub(super) struct LegendWidget {
     rect: Rect,
     entries: BTreeMap<String, LegendEntry>,
     config: Legend,
+
+    /// Used for the id of the scroll area.
+    id: Id,
+    scroll_offset: f32,
 }
 
+/// The space between the legend and the edges of its rect.
+const LEGEND_PAD: f32 = 4.0;
+
 impl LegendWidget {
     /// Create a new legend from items, the names of items that are hidden and the style of the
     /// text. Returns `None` if the legend has no entries.
+    ///
+    /// `scroll_offset` is where the entries were scrolled to the previous frame.
     pub(super) fn try_new(
         rect: Rect,
         config: Legend,
         items: &[Box<dyn PlotItem>],
-        hidden_items: &ahash::HashSet<String>, // Existing hiddent items in the plot memory.
+        hidden_items: &ahash::HashSet<String>,
+        id: Id,
+        scroll_offset: f32,
     ) -> Option<Self> {
-        // If `config.hidden_items` is not `None`, it is used.
-        let hidden_items = config.hidden_items.as_ref().unwrap_or(hidden_items);
-
         // Collect the legend entries. If multiple items have the same name, they share a
         // checkbox. If their colors don't match, we pick a neutral color for the checkbox.
         let mut entries: BTreeMap<String, LegendEntry> = BTreeMap::new();
@@ -199,17 +294,24 @@ impl LegendWidget {
                             // Multiple items with different colors
                             entry.color = Color32::TRANSPARENT;
                         }
+                        if entry.colormap.as_ref() != item.legend_colormap() {
+                            entry.colormap = None;
+                        }
                     })
                     .or_insert_with(|| {
                         let color = item.color();
                         let checked = !hidden_items.contains(item.name());
-                        LegendEntry::new(color, checked)
+                        let secondary = item.y_axis() == YAxis::Secondary;
+                        let colormap = item.legend_colormap().cloned();
+                        LegendEntry::new(color, checked, secondary, colormap)
                     });
             });
         (!entries.is_empty()).then_some(Self {
             rect,
             entries,
             config,
+            id,
+            scroll_offset,
         })
     }
 
@@ -222,6 +324,11 @@ impl LegendWidget {
             .collect()
     }
 
+    // Get the vertical scroll offset of the entries.
+    pub fn scroll_offset(&self) -> f32 {
+        self.scroll_offset
+    }
+
     // Get the name of the hovered items.
     pub fn hovered_item_name(&self) -> Option<String> {
         self.entries
@@ -229,14 +336,118 @@ impl LegendWidget {
             .find(|(_, entry)| entry.hovered)
             .map(|(name, _)| name.to_string())
     }
+
+    /// The width (or height) to reserve for a legend outside the plot, given the response of
+    /// its [`Widget::ui`].
+    pub fn outside_size(&self, response: &Response) -> Option<f32> {
+        let size = match self.config.outside? {
+            OuterPosition::Right => response.rect.width(),
+            OuterPosition::Bottom => response.rect.height(),
+        };
+        Some(size + 2.0 * LEGEND_PAD)
+    }
+
+    fn outside_ui(&mut self, ui: &mut Ui, position: OuterPosition) -> Response {
+        let Self {
+            rect,
+            entries,
+            config,
+            id,
+            scroll_offset,
+        } = self;
+
+        let legend_rect = rect.shrink(LEGEND_PAD);
+        let mut legend_ui = ui.child_ui(legend_rect, Layout::top_down(Align::LEFT), None);
+        let background_frame = background_frame(&legend_ui, config.background_alpha);
+        let max_height = legend_rect.height() - background_frame.inner_margin.sum().y;
+
+        background_frame
+            .show(&mut legend_ui, |ui| match position {
+                OuterPosition::Right => {
+                    let scroll_output = ScrollArea::vertical()
+                        .id_source(*id)
+                        .max_height(max_height.max(0.0))
+                        .vertical_scroll_offset(*scroll_offset)
+                        .show(ui, |ui| {
+                            let mut focus_on_item = None;
+                            for (name, entry) in entries.iter_mut() {
+                                let response = entry.ui(ui, name.clone(), &config.text_style);
+                                handle_interaction_on_legend_item(&response, entry);
+                                if response.clicked() && ui.input(|r| r.modifiers.alt) {
+                                    focus_on_item = Some(name.clone());
+                                }
+                            }
+                            focus_on_item
+                        });
+                    *scroll_offset = scroll_output.state.offset.y;
+                    if let Some(focus_on_item) = scroll_output.inner {
+                        handle_focus_on_legend_item(&focus_on_item, entries);
+                    }
+                }
+                OuterPosition::Bottom => {
+                    // As many columns of the widest entry as fit.
+                    let column_width = entries
+                        .iter()
+                        .map(|(name, entry)| {
+                            let galley = entry.galley(ui, name.clone(), &config.text_style);
+                            LegendEntry::desired_size(&galley).x
+                        })
+                        .fold(0.0, f32::max);
+                    let spacing = ui.spacing().item_spacing.x;
+                    let num_columns = ((ui.available_width() + spacing) / (column_width + spacing))
+                        .floor()
+                        .max(1.0) as usize;
+
+                    let mut focus_on_item = None;
+                    Grid::new(*id)
+                        .num_columns(num_columns)
+                        .min_col_width(column_width)
+                        .show(ui, |ui| {
+                            for (i, (name, entry)) in entries.iter_mut().enumerate() {
+                                let response = entry.ui(ui, name.clone(), &config.text_style);
+                                handle_interaction_on_legend_item(&response, entry);
+                                if response.clicked() && ui.input(|r| r.modifiers.alt) {
+                                    focus_on_item = Some(name.clone());
+                                }
+                                if (i + 1) % num_columns == 0 {
+                                    ui.end_row();
+                                }
+                            }
+                        });
+                    if let Some(focus_on_item) = focus_on_item {
+                        handle_focus_on_legend_item(&focus_on_item, entries);
+                    }
+                }
+            })
+            .response
+    }
+}
+
+/// The frame around the legend entries.
+fn background_frame(ui: &Ui, background_alpha: f32) -> Frame {
+    Frame {
+        inner_margin: vec2(8.0, 4.0).into(),
+        rounding: ui.style().visuals.window_rounding,
+        shadow: epaint::Shadow::NONE,
+        fill: ui.style().visuals.extreme_bg_color,
+        stroke: ui.style().visuals.window_stroke(),
+        ..Default::default()
+    }
+    .multiply_with_opacity(background_alpha)
 }
 
 impl Widget for &mut LegendWidget {
     fn ui(self, ui: &mut Ui) -> Response {
+        if let Some(position) = self.config.outside {
+            return self.outside_ui(ui, position);
+        }
+
         let LegendWidget {
             rect,
             entries,
             config,
+            id,
+            scroll_offset,
         } = self;
 
         let main_dir = match config.position {
@@ -248,48 +459,71 @@ impl Widget for &mut LegendWidget {
             Corner::RightTop | Corner::RightBottom => Align::RIGHT,
         };
         let layout = Layout::from_main_dir_and_cross_align(main_dir, cross_align);
-        let legend_pad = 4.0;
-        let legend_rect = rect.shrink(legend_pad);
+        let legend_rect = rect.shrink(LEGEND_PAD);
         let mut legend_ui = ui.child_ui(legend_rect, layout, None);
         legend_ui
             .scope(|ui| {
-                let background_frame = Frame {
-                    inner_margin: vec2(8.0, 4.0).into(),
-                    rounding: ui.style().visuals.window_rounding,
-                    shadow: epaint::Shadow::NONE,
-                    fill: ui.style().visuals.extreme_bg_color,
-                    stroke: ui.style().visuals.window_stroke(),
-                    ..Default::default()
+                let background_frame = background_frame(ui, config.background_alpha);
+
+                // Measure the entries, so that the scroll area can be placed in the corner.
+                let galleys: Vec<_> = entries
+                    .iter()
+                    .map(|(name, entry)| entry.galley(ui, name.clone(), &config.text_style))
+                    .collect();
+                let item_spacing = ui.spacing().item_spacing.y;
+                let content_size = galleys
+                    .iter()
+                    .map(|galley| LegendEntry::desired_size(galley))
+                    .fold(vec2(0.0, -item_spacing), |size, entry_size| {
+                        vec2(
+                            size.x.max(entry_size.x),
+                            size.y + entry_size.y + item_spacing,
+                        )
+                    });
+                let max_height = config.max_height.unwrap_or(0.75 * legend_rect.height())
+                    - background_frame.inner_margin.sum().y;
+                let mut size = content_size;
+                if max_height < content_size.y {
+                    size.x += ui.spacing().scroll.allocated_width();
+                    size.y = max_height.max(0.0);
                 }
-                .multiply_with_opacity(config.background_alpha);
+
                 background_frame
                     .show(ui, |ui| {
-                        let mut focus_on_item = None;
-
-                        let response_union = entries
-                            .iter_mut()
-                            .map(|(name, entry)| {
-                                let response = entry.ui(ui, name.clone(), &config.text_style);
-
-                                // Handle interactions. Alt-clicking must be deferred to end of loop
-                                // since it may affect all entries.
-                                handle_interaction_on_legend_item(&response, entry);
-                                if response.clicked() && ui.input(|r| r.modifiers.alt) {
-                                    focus_on_item = Some(name.clone());
-                                }
-
-                                response
-                            })
-                            .reduce(|r1, r2| r1.union(r2))
-                            .unwrap();
-
-                        if let Some(focus_on_item) = focus_on_item {
-                            handle_focus_on_legend_item(&focus_on_item, entries);
-                        }
-
-                        response_union
+                        let entries_layout = Layout::top_down(cross_align);
+                        ui.allocate_ui_with_layout(size, entries_layout, |ui| {
+                            let scroll_output = ScrollArea::vertical()
+                                .id_source(*id)
+                                .max_height(size.y)
+                                .vertical_scroll_offset(*scroll_offset)
+                                .show(ui, |ui| {
+                                    let mut focus_on_item = None;
+
+                                    // Entries at the bottom are listed from the bottom up.
+                                    let mut entries: Vec<_> = entries.iter_mut().collect();
+                                    if main_dir == Direction::BottomUp {
+                                        entries.reverse();
+                                    }
+                                    for (name, entry) in entries {
+                                        let response =
+                                            entry.ui(ui, name.clone(), &config.text_style);
+
+                                        // Handle interactions. Alt-clicking must be deferred to end of loop
+                                        // since it may affect all entries.
+                                        handle_interaction_on_legend_item(&response, entry);
+                                        if response.clicked() && ui.input(|r| r.modifiers.alt) {
+                                            focus_on_item = Some(name.clone());
+                                        }
+                                    }
+                                    focus_on_item
+                                });
+                            *scroll_offset = scroll_output.state.offset.y;
+                            if let Some(focus_on_item) = scroll_output.inner {
+                                handle_focus_on_legend_item(&focus_on_item, entries);
+                            }
+                        });
                     })
-                    .inner
+                    .response
             })
             .inner
     }
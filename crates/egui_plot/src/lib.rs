@@ -13,38 +13,54 @@ mod items;
 mod legend;
 mod memory;
 mod plot_ui;
+mod svg;
+mod time;
 mod transform;
 
-use std::{cmp::Ordering, ops::RangeInclusive, sync::Arc};
+use std::{
+    cmp::Ordering,
+    ops::{Range, RangeInclusive},
+    sync::Arc,
+};
 
 use ahash::HashMap;
 use egui::*;
-use emath::Float as _;
+use emath::{easing::BoxedCurve, Float as _, Tween};
 use epaint::Hsva;
 
 pub use crate::{
     axis::{Axis, AxisHints, HPlacement, Placement, VPlacement},
     items::{
-        Arrows, Bar, BarChart, BoxElem, BoxPlot, BoxSpread, ClosestElem, HLine, Line, LineStyle,
-        MarkerShape, Orientation, PlotConfig, PlotGeometry, PlotImage, PlotItem, PlotPoint,
-        PlotPoints, Points, Polygon, Text, VLine,
+        Annotation, AnnotationClip, Arrows, Band, Bar, BarChart, BinSpec, BoxElem, BoxPlot,
+        BoxSpread, ClosestElem, Colormap, DownsampleMode, EditablePoints, ErrorBars, ErrorValue,
+        Gauge, HLine, HeatMap, Histogram, LabelPosition, Line, LineStyle, MarkerShape, Orientation,
+        PlotConfig, PlotGeometry, PlotImage, PlotItem, PlotPoint, PlotPoints, Points, Polygon,
+        RoseChart, StackedBars, Stems, StreamingLine, Text, VLine, Violin, ViolinElem, ViolinScale,
+        YAxis,
     },
-    legend::{Corner, Legend},
+    legend::{Corner, Legend, OuterPosition},
     memory::PlotMemory,
     plot_ui::PlotUi,
-    transform::{PlotBounds, PlotTransform},
+    time::TimeAxis,
+    transform::{AxisScale, PlotBounds, PlotTransform},
 };
 
-use axis::AxisWidget;
+use axis::{AxisFormatterFn, AxisWidget};
 use items::{horizontal_line, rulers_color, vertical_line};
 use legend::LegendWidget;
+use memory::DraggedPoint;
 
 type LabelFormatterFn<'a> = dyn Fn(&str, &PlotPoint) -> String + 'a;
 pub type LabelFormatter<'a> = Option<Box<LabelFormatterFn<'a>>>;
 
+type HoverFormatterFn = dyn Fn(&PlotPoint) -> String;
+
 type GridSpacerFn<'a> = dyn Fn(GridInput) -> Vec<GridMark> + 'a;
 type GridSpacer<'a> = Box<GridSpacerFn<'a>>;
 
+/// Boxes smaller than this (in points) are ignored when boxed zooming, as they are most likely accidental.
+const MIN_BOXED_ZOOM_SIZE: f32 = 5.0;
+
 type CoordinatesFormatterFn<'a> = dyn Fn(&PlotPoint, &PlotBounds) -> String + 'a;
 
 /// Specifies the coordinates formatting when passed to [`Plot::coordinates_formatter`].
@@ -89,11 +105,38 @@ pub enum Cursor {
     Vertical { x: f64 },
 }
 
+/// A crosshair following the pointer, see [`Plot::crosshair`].
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum Crosshair {
+    /// No crosshair.
+    #[default]
+    None,
+
+    /// Dashed lines through the pointer, across the whole plot.
+    Lines,
+
+    /// Like [`Self::Lines`], with the coordinates labeled at the edges of the plot.
+    LinesWithLabels,
+}
+
+/// The shape of the region selected by dragging, see [`Plot::selection`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum SelectionMode {
+    /// A rectangle between the start and the end of the drag.
+    Rectangle,
+
+    /// The X range of the drag, including all Y values.
+    Horizontal,
+}
+
 /// Contains the cursors drawn for a plot widget in a single frame.
 #[derive(PartialEq, Clone)]
 struct PlotFrameCursors {
     id: Id,
     cursors: Vec<Cursor>,
+
+    /// The hovered coordinate, if the plot shows a [`Crosshair`].
+    crosshair: Option<PlotPoint>,
 }
 
 #[derive(Default, Clone)]
@@ -108,6 +151,43 @@ struct LinkedBounds {
 #[derive(Default, Clone)]
 struct BoundsLinkGroups(HashMap<Id, LinkedBounds>);
 
+/// The category hovered in a plot of the group.
+#[derive(Clone)]
+struct LinkedCategoryHover {
+    plot_id: Id,
+    index: usize,
+
+    /// The frame in which the hovering plot was last shown.
+    frame_nr: u64,
+}
+
+#[derive(Default, Clone)]
+struct CategoryHoverLinkGroups(HashMap<Id, LinkedCategoryHover>);
+
+impl CategoryHoverLinkGroups {
+    /// The category hovered in another plot of the group, in this or the previous frame.
+    ///
+    /// Older hovers are removed, as the plot that published them is no longer shown.
+    fn hovered_by_other(&mut self, group_id: Id, plot_id: Id, frame_nr: u64) -> Option<usize> {
+        let hover = self.0.get(&group_id)?;
+        if hover.frame_nr + 1 < frame_nr {
+            self.0.remove(&group_id);
+            return None;
+        }
+        (hover.plot_id != plot_id).then_some(hover.index)
+    }
+}
+
+/// The selection last made or cleared in a plot of the group.
+#[derive(Clone)]
+struct LinkedSelection {
+    plot_id: Id,
+    selection: Option<PlotBounds>,
+}
+
+#[derive(Default, Clone)]
+struct SelectionLinkGroups(HashMap<Id, LinkedSelection>);
+
 // ----------------------------------------------------------------------------
 
 /// What [`Plot::show`] returns.
@@ -119,12 +199,92 @@ pub struct PlotResponse<R> {
     pub response: Response,
 
     /// The transform between screen coordinates and plot coordinates.
+    ///
+    /// Use it to paint at data coordinates after the plot was shown, e.g. with [`Self::painter`].
+    /// It is only valid for this frame.
     pub transform: PlotTransform,
 
+    /// The transform used by items on the secondary Y axis, if there are any.
+    ///
+    /// See [`Plot::secondary_y_axis`].
+    pub secondary_transform: Option<PlotTransform>,
+
+    /// The names of the items hidden via the legend or [`PlotUi::set_item_visibility`].
+    ///
+    /// Pass these to [`Legend::hidden_items`] to persist the visibility in your app.
+    pub hidden_items: ahash::HashSet<String>,
+
+    /// The region selected last, see [`Plot::selection`].
+    ///
+    /// For [`SelectionMode::Horizontal`] the Y range is infinite.
+    pub selection: Option<PlotBounds>,
+
     /// The id of a currently hovered item if any.
     ///
     /// This is `None` if either no item was hovered, or the hovered item didn't provide an id.
     pub hovered_plot_item: Option<Id>,
+
+    /// The points the user dragged this frame, see [`EditablePoints`].
+    ///
+    /// Each entry is the id of the item (or its name as [`Id`], if it has no id),
+    /// the index of the point and where it should be now.
+    /// Several points move if the dragged point passes others with [`EditablePoints::keep_sorted`].
+    pub moved_points: Vec<(Id, usize, PlotPoint)>,
+
+    /// The rect of the plot including its axes.
+    complete_rect: Rect,
+
+    /// The shapes of the plot in the layer it was painted to.
+    painted_shapes: (LayerId, Range<usize>),
+}
+
+impl<R> PlotResponse<R> {
+    /// A painter for the plot area, i.e. clipped to [`PlotTransform::frame`].
+    ///
+    /// Combined with [`Self::transform`] this paints custom shapes at data coordinates
+    /// on top of the plot items:
+    ///
+    /// ```
+    /// # egui::__run_test_ui(|ui| {
+    /// use egui_plot::{Plot, PlotPoint};
+    ///
+    /// let response = Plot::new("my_plot").show(ui, |_plot_ui| {});
+    /// let pos = response.transform.position_from_point(&PlotPoint::new(1.0, 2.0));
+    /// response.painter().circle_filled(pos, 4.0, egui::Color32::RED);
+    /// # });
+    /// ```
+    pub fn painter(&self) -> Painter {
+        Painter::new(
+            self.response.ctx.clone(),
+            self.painted_shapes.0,
+            // Also clip to the parent, e.g. when the plot is partially scrolled out of view.
+            self.transform
+                .frame()
+                .intersect(self.response.interact_rect),
+        )
+    }
+
+    /// The plot as it was painted this frame as an SVG document, including axes and legend.
+    ///
+    /// This must be called in the same frame as [`Plot::show`], before the painted shapes
+    /// are handed over to the integration.
+    ///
+    /// Text uses the font family of egui, e.g. `sans-serif` for [`FontFamily::Proportional`].
+    /// Meshes, like the one of [`HeatMap`], are exported as triangles without their texture.
+    pub fn export_svg(&self) -> String {
+        let (layer_id, range) = &self.painted_shapes;
+        let shapes: Vec<epaint::ClippedShape> = self.response.ctx.graphics(|graphics| {
+            graphics.get(*layer_id).map_or_else(Vec::new, |paint_list| {
+                paint_list
+                    .all_entries()
+                    .skip(range.start)
+                    .take(range.len())
+                    .cloned()
+                    .collect()
+            })
+        });
+        svg::shapes_to_svg(self.complete_rect, &shapes)
+    }
 }
 
 // ----------------------------------------------------------------------------
@@ -151,16 +311,26 @@ pub struct Plot<'a> {
 
     center_axis: Vec2b,
     allow_zoom: Vec2b,
+    pinch_zoom_axes: Vec2b,
     allow_drag: Vec2b,
     allow_scroll: Vec2b,
     allow_double_click_reset: bool,
+    allow_data_export: bool,
     allow_boxed_zoom: bool,
     default_auto_bounds: Vec2b,
     min_auto_bounds: PlotBounds,
     margin_fraction: Vec2,
+    min_margin: Vec2,
+    bounds_constraint: Option<PlotBounds>,
+    zoom_ranges: [Option<RangeInclusive<f64>>; 2],
     boxed_zoom_pointer_button: PointerButton,
+    boxed_zoom_modifier: Modifiers,
+    selection_mode: Option<SelectionMode>,
+    selection_modifier: Modifiers,
     linked_axes: Option<(Id, Vec2b)>,
     linked_cursors: Option<(Id, Vec2b)>,
+    linked_category_hover: Option<Id>,
+    linked_selection: Option<(Id, bool)>,
 
     min_size: Vec2,
     width: Option<f32>,
@@ -174,8 +344,14 @@ pub struct Plot<'a> {
     show_y: bool,
     label_formatter: LabelFormatter<'a>,
     coordinates_formatter: Option<(Corner, CoordinatesFormatter<'a>)>,
+    crosshair: Crosshair,
     x_axes: Vec<AxisHints<'a>>, // default x axes
     y_axes: Vec<AxisHints<'a>>, // default y axes
+    secondary_y_axis: Option<AxisHints<'a>>,
+    lock_secondary_y: bool,
+    x_categories: Vec<String>,
+    x_time: Option<TimeAxis>,
+    axis_scales: [AxisScale; 2],
     legend_config: Option<Legend>,
     show_background: bool,
     show_axes: Vec2b,
@@ -198,16 +374,26 @@ impl<'a> Plot<'a> {
 
             center_axis: false.into(),
             allow_zoom: true.into(),
+            pinch_zoom_axes: true.into(),
             allow_drag: true.into(),
             allow_scroll: true.into(),
             allow_double_click_reset: true,
+            allow_data_export: false,
             allow_boxed_zoom: true,
             default_auto_bounds: true.into(),
             min_auto_bounds: PlotBounds::NOTHING,
             margin_fraction: Vec2::splat(0.05),
+            min_margin: Vec2::ZERO,
+            bounds_constraint: None,
+            zoom_ranges: [None, None],
             boxed_zoom_pointer_button: PointerButton::Secondary,
+            boxed_zoom_modifier: Modifiers::NONE,
+            selection_mode: None,
+            selection_modifier: Modifiers::ALT,
             linked_axes: None,
             linked_cursors: None,
+            linked_category_hover: None,
+            linked_selection: None,
 
             min_size: Vec2::splat(64.0),
             width: None,
@@ -221,8 +407,14 @@ impl<'a> Plot<'a> {
             show_y: true,
             label_formatter: None,
             coordinates_formatter: None,
+            crosshair: Crosshair::None,
             x_axes: vec![AxisHints::new(Axis::X)],
             y_axes: vec![AxisHints::new(Axis::Y)],
+            secondary_y_axis: None,
+            lock_secondary_y: false,
+            x_categories: Vec::new(),
+            x_time: None,
+            axis_scales: [AxisScale::Linear; 2],
             legend_config: None,
             show_background: true,
             show_axes: true.into(),
@@ -331,6 +523,21 @@ impl<'a> Plot<'a> {
         self
     }
 
+    /// Which axes a pinch gesture on a touch screen zooms. Default: `true`.
+    ///
+    /// E.g. `[true, false]` lets pinching zoom only the time axis of a time series, while mouse
+    /// zooming still follows [`Self::allow_zoom`]. Axes not allowed by [`Self::allow_zoom`] are
+    /// never zoomed. Panning with two fingers follows [`Self::allow_drag`], and rotating the
+    /// fingers is ignored.
+    #[inline]
+    pub fn pinch_zoom_axes<T>(mut self, on: T) -> Self
+    where
+        T: Into<Vec2b>,
+    {
+        self.pinch_zoom_axes = on.into();
+        self
+    }
+
     /// Whether to allow scrolling in the plot. Default: `true`.
     #[inline]
     pub fn allow_scroll<T>(mut self, on: T) -> Self
@@ -349,6 +556,17 @@ impl<'a> Plot<'a> {
         self
     }
 
+    /// Whether to add "Copy visible data as CSV" to the context menu of the plot.
+    /// Default: `false`.
+    ///
+    /// This copies `series,x,y` rows of the points within the plot bounds,
+    /// see [`PlotItem::export_points`].
+    #[inline]
+    pub fn allow_data_export(mut self, on: bool) -> Self {
+        self.allow_data_export = on;
+        self
+    }
+
     /// Set the side margin as a fraction of the plot size. Only used for auto bounds.
     ///
     /// For instance, a value of `0.1` will add 10% space on both sides.
@@ -358,6 +576,18 @@ impl<'a> Plot<'a> {
         self
     }
 
+    /// Set the minimum side margin in ui points. Only used for auto bounds.
+    ///
+    /// Use this to keep markers at the edges of the data from being clipped,
+    /// no matter how large the data span is.
+    ///
+    /// Default: `Vec2::ZERO`.
+    #[inline]
+    pub fn set_min_margin(mut self, min_margin: Vec2) -> Self {
+        self.min_margin = min_margin;
+        self
+    }
+
     /// Whether to allow zooming in the plot by dragging out a box with the secondary mouse button.
     ///
     /// Default: `true`.
@@ -374,6 +604,39 @@ impl<'a> Plot<'a> {
         self
     }
 
+    /// Modifier keys that must be held to start a boxed zoom. Default: [`Modifiers::NONE`].
+    ///
+    /// This allows boxed zooming with the primary button, e.g. while holding shift,
+    /// and still panning with plain drags. Pressing escape cancels a boxed zoom.
+    #[inline]
+    pub fn boxed_zoom_modifier(mut self, modifier: Modifiers) -> Self {
+        self.boxed_zoom_modifier = modifier;
+        self
+    }
+
+    /// Allow selecting a region by dragging with the primary button while holding
+    /// [`Self::selection_modifier`], e.g. to pick outliers.
+    ///
+    /// The selection is returned in [`PlotResponse::selection`] and stays until a new one is made,
+    /// escape is pressed, or [`PlotUi::clear_selection`] is called.
+    /// Use [`PlotUi::points_in_bounds`] to find the selected points.
+    ///
+    /// Default: `None`, i.e. no selection.
+    #[inline]
+    pub fn selection(mut self, mode: impl Into<Option<SelectionMode>>) -> Self {
+        self.selection_mode = mode.into();
+        self
+    }
+
+    /// Modifier keys that must be held to start a selection. Default: [`Modifiers::ALT`].
+    ///
+    /// Starting a selection takes precedence over boxed zooming and panning.
+    #[inline]
+    pub fn selection_modifier(mut self, modifier: Modifiers) -> Self {
+        self.selection_modifier = modifier;
+        self
+    }
+
     /// Whether to allow dragging in the plot to move the bounds. Default: `true`.
     #[inline]
     pub fn allow_drag<T>(mut self, on: T) -> Self
@@ -423,6 +686,19 @@ impl<'a> Plot<'a> {
         self
     }
 
+    /// Show a crosshair through the pointer while the plot is hovered.
+    ///
+    /// With [`Crosshair::LinesWithLabels`], the coordinates are labeled using the formatters
+    /// of the first X and Y axis.
+    /// Plots in the same [`Self::link_cursor`] group show the crosshair at the shared coordinate.
+    ///
+    /// Default: [`Crosshair::None`].
+    #[inline]
+    pub fn crosshair(mut self, crosshair: Crosshair) -> Self {
+        self.crosshair = crosshair;
+        self
+    }
+
     /// Configure how the grid in the background is spaced apart along the X axis.
     ///
     /// Default is a log-10 grid, i.e. every plot unit is divided into 10 other units.
@@ -513,6 +789,40 @@ impl<'a> Plot<'a> {
         self
     }
 
+    /// Keep the visible region within these bounds, e.g. to keep users from panning away from
+    /// the data.
+    ///
+    /// If the plot is zoomed out further than the constraint, it shows the whole constraint.
+    /// Use infinite values to leave an axis unconstrained.
+    /// This applies to all ways of changing the bounds, including [`PlotUi::set_plot_bounds`].
+    ///
+    /// These are in data values, also on a logarithmic axis (see [`AxisScale`]).
+    #[inline]
+    pub fn bounds_constraint(mut self, bounds: PlotBounds) -> Self {
+        self.bounds_constraint = Some(bounds);
+        self
+    }
+
+    /// Limit the width of the visible region, i.e. how far the X axis can be zoomed in and out.
+    ///
+    /// On a logarithmic axis the width is in decades.
+    /// Note: this may lead to unexpected results if used in combination with `data_aspect`.
+    #[inline]
+    pub fn zoom_range_x(mut self, width: RangeInclusive<f64>) -> Self {
+        self.zoom_ranges[0] = Some(width);
+        self
+    }
+
+    /// Limit the height of the visible region, i.e. how far the Y axis can be zoomed in and out.
+    ///
+    /// On a logarithmic axis the height is in decades.
+    /// Note: this may lead to unexpected results if used in combination with `data_aspect`.
+    #[inline]
+    pub fn zoom_range_y(mut self, height: RangeInclusive<f64>) -> Self {
+        self.zoom_ranges[1] = Some(height);
+        self
+    }
+
     /// Set whether the bounds should be automatically set based on data by default.
     ///
     /// This is enabled by default.
@@ -601,6 +911,29 @@ impl<'a> Plot<'a> {
         self
     }
 
+    /// Add this plot to a category hover link group.
+    ///
+    /// Hovering an element of a categorical item (e.g. bar 3 of a [`BarChart`]) then highlights
+    /// the element with the same category index in the other plots of the group.
+    /// A plot cannot belong to more than one category hover group.
+    #[inline]
+    pub fn link_category_hover(mut self, group_id: impl Into<Id>) -> Self {
+        self.linked_category_hover = Some(group_id.into());
+        self
+    }
+
+    /// Add this plot to a selection link group, so that a [`Self::selection`] made in one plot
+    /// of the group is shown in all of them, and returned in their [`PlotResponse::selection`].
+    ///
+    /// If `link_y` is `false` only the X range is shared, and the other plots select all Y values.
+    /// Clearing the selection in one plot clears it in all of them.
+    /// A plot cannot belong to more than one selection group.
+    #[inline]
+    pub fn link_selection(mut self, group_id: impl Into<Id>, link_y: bool) -> Self {
+        self.linked_selection = Some((group_id.into(), link_y));
+        self
+    }
+
     /// Round grid positions to full pixels to avoid aliasing. Improves plot appearance but might have an
     /// undesired effect when shifting the plot bounds. Enabled by default.
     #[inline]
@@ -671,6 +1004,113 @@ impl<'a> Plot<'a> {
         self
     }
 
+    /// Use a categorical X-axis, where category `i` is centered on `x = i`.
+    ///
+    /// This places one tick per category on the main X-axis, labeled with the category name
+    /// (slanted, and truncated if even that doesn't fit), and never shows fractional ticks.
+    /// Panning is kept within the categories (plus [`Self::set_margin_fraction`]),
+    /// and the hover readout shows the category name instead of the x value
+    /// (or the x value itself outside the categories).
+    ///
+    /// Each category is one unit wide, so use e.g. [`BarChart::width`] to size bars to it.
+    ///
+    /// This replaces the [`Self::x_grid_spacer`] and [`Self::x_axis_formatter`].
+    ///
+    /// ```
+    /// # egui::__run_test_ui(|ui| {
+    /// use egui_plot::{Bar, BarChart, Plot};
+    /// let bars = vec![Bar::new(0.0, 3.0), Bar::new(1.0, 5.0), Bar::new(2.0, 2.0)];
+    /// Plot::new("fruit")
+    ///     .x_axis_categories(vec!["Apples".into(), "Bananas".into(), "Cherries".into()])
+    ///     .show(ui, |plot_ui| plot_ui.bar_chart(BarChart::new(bars)));
+    /// # });
+    /// ```
+    pub fn x_axis_categories(mut self, categories: Vec<String>) -> Self {
+        let num_categories = categories.len();
+        self.grid_spacers[0] = category_grid_spacer(num_categories);
+        if let Some(main) = self.x_axes.first_mut() {
+            let names = categories.clone();
+            main.formatter = Arc::new(move |mark, _range| {
+                category_at(&names, mark.value)
+                    .unwrap_or_default()
+                    .to_owned()
+            });
+            main.truncate_labels = true;
+            main.rotate_crowded_labels = true;
+        }
+        self.x_categories = categories;
+        self
+    }
+
+    /// Show the X values as timestamps, in seconds since the Unix epoch.
+    ///
+    /// Grid lines are placed at natural boundaries (seconds, minutes, hours, days, months, years)
+    /// depending on the zoom level, and labeled like `14:05`, `Mar 3` or `2024`.
+    /// The hover readout shows the full date and time.
+    ///
+    /// This replaces the [`Self::x_grid_spacer`] and [`Self::x_axis_formatter`].
+    ///
+    /// ```
+    /// # egui::__run_test_ui(|ui| {
+    /// use egui_plot::{Line, Plot, TimeAxis};
+    /// let start = 1_710_000_000.0;
+    /// let line = Line::new(vec![[start, 1.0], [start + 3600.0, 2.0]]);
+    /// Plot::new("temperature")
+    ///     .x_axis_time(TimeAxis::Utc)
+    ///     .show(ui, |plot_ui| plot_ui.line(line));
+    /// # });
+    /// ```
+    pub fn x_axis_time(mut self, time_axis: TimeAxis) -> Self {
+        self.grid_spacers[0] = time_axis.grid_spacer();
+        if let Some(main) = self.x_axes.first_mut() {
+            main.formatter = Arc::new(move |mark, range| time_axis.format_mark(mark, range));
+        }
+        self.x_time = Some(time_axis);
+        self
+    }
+
+    /// How data values are mapped along the X axis, e.g. [`AxisScale::Log10`]. Default: [`AxisScale::Linear`].
+    ///
+    /// Item data stays in linear units, while positions, grid lines, zooming and panning
+    /// work in the scaled space.
+    ///
+    /// For [`AxisScale::Log10`] this also replaces the [`Self::x_grid_spacer`] with [`log10_grid_spacer`]
+    /// and the [`Self::x_axis_formatter`] with one that writes large values as powers of ten.
+    ///
+    /// ```
+    /// # egui::__run_test_ui(|ui| {
+    /// use egui_plot::{AxisScale, Line, Plot};
+    /// let latencies: Vec<[f64; 2]> = (1..100).map(|i| [i as f64, 1.5_f64.powi(i)]).collect();
+    /// Plot::new("latency")
+    ///     .y_axis_scale(AxisScale::Log10 { min: 1e-3 })
+    ///     .show(ui, |plot_ui| plot_ui.line(Line::new(latencies)));
+    /// # });
+    /// ```
+    pub fn x_axis_scale(self, scale: AxisScale) -> Self {
+        self.axis_scale(Axis::X, scale)
+    }
+
+    /// How data values are mapped along the Y axis. See [`Self::x_axis_scale`].
+    pub fn y_axis_scale(self, scale: AxisScale) -> Self {
+        self.axis_scale(Axis::Y, scale)
+    }
+
+    fn axis_scale(mut self, axis: Axis, scale: AxisScale) -> Self {
+        let index = usize::from(axis);
+        self.axis_scales[index] = scale;
+        if let AxisScale::Log10 { .. } = scale {
+            self.grid_spacers[index] = log10_grid_spacer();
+            let axes = match axis {
+                Axis::X => &mut self.x_axes,
+                Axis::Y => &mut self.y_axes,
+            };
+            if let Some(main) = axes.first_mut() {
+                main.formatter = Arc::new(log10_axis_formatter);
+            }
+        }
+        self
+    }
+
     /// Specify custom formatter for ticks on the main Y-axis.
     ///
     /// Arguments of `fmt`:
@@ -722,6 +1162,27 @@ impl<'a> Plot<'a> {
         self
     }
 
+    /// Add a secondary Y axis on the right side of the plot.
+    ///
+    /// Items plotted against [`YAxis::Secondary`] (see e.g. [`Line::y_axis`]) use this axis,
+    /// which has bounds independent of the primary Y axis. When the Y axis uses automatic bounds,
+    /// both axes are fitted to their own items.
+    ///
+    /// Zooming and panning scale both Y axes proportionally, unless [`Self::lock_secondary_y`] is set.
+    pub fn secondary_y_axis(mut self, hints: AxisHints<'a>) -> Self {
+        self.secondary_y_axis = Some(hints.placement(HPlacement::Right));
+        self
+    }
+
+    /// Keep the range of the secondary Y axis fixed while zooming and panning. Default: `false`.
+    ///
+    /// The secondary axis is still fitted to its items when the plot uses automatic bounds.
+    #[inline]
+    pub fn lock_secondary_y(mut self, lock: bool) -> Self {
+        self.lock_secondary_y = lock;
+        self
+    }
+
     /// Interact with and add items to the plot and finally draw it.
     pub fn show<R>(
         self,
@@ -741,14 +1202,22 @@ impl<'a> Plot<'a> {
             id,
             center_axis,
             allow_zoom,
+            pinch_zoom_axes,
             allow_drag,
             allow_scroll,
             allow_double_click_reset,
+            allow_data_export,
             allow_boxed_zoom,
             boxed_zoom_pointer_button,
+            boxed_zoom_modifier,
+            selection_mode,
+            selection_modifier,
             default_auto_bounds,
             min_auto_bounds,
             margin_fraction,
+            min_margin,
+            bounds_constraint,
+            zoom_ranges,
             width,
             height,
             mut min_size,
@@ -758,8 +1227,14 @@ impl<'a> Plot<'a> {
             mut show_y,
             label_formatter,
             coordinates_formatter,
+            crosshair,
             x_axes,
-            y_axes,
+            mut y_axes,
+            secondary_y_axis,
+            lock_secondary_y,
+            x_categories,
+            x_time,
+            axis_scales,
             legend_config,
             reset,
             show_background,
@@ -768,6 +1243,8 @@ impl<'a> Plot<'a> {
             grid_spacing,
             linked_axes,
             linked_cursors,
+            linked_category_hover,
+            linked_selection,
 
             clamp_grid,
             grid_spacers,
@@ -818,15 +1295,43 @@ impl<'a> Plot<'a> {
 
         let plot_id = id.unwrap_or_else(|| ui.make_persistent_id(id_source));
 
-        let ([x_axis_widgets, y_axis_widgets], plot_rect) = axis_widgets(
-            PlotMemory::load(ui.ctx(), plot_id).as_ref(), // TODO(emilk): avoid loading plot memory twice
-            show_axes,
-            complete_rect,
-            [&x_axes, &y_axes],
-        );
+        // The secondary axis goes last, so it ends up outermost on the right.
+        let has_secondary_axis = secondary_y_axis.is_some();
+        y_axes.extend(secondary_y_axis);
+
+        let last_mem = PlotMemory::load(ui.ctx(), plot_id); // TODO(emilk): avoid loading plot memory twice
+
+        // A legend outside the plot takes the space it needed the previous frame.
+        let mut axes_rect = complete_rect;
+        let outside_legend_rect = legend_config
+            .as_ref()
+            .and_then(|config| config.outside)
+            .map(|position| {
+                let size = last_mem.as_ref().map_or(0.0, |mem| mem.legend_outside_size);
+                match position {
+                    OuterPosition::Right => {
+                        axes_rect.max.x -= size.at_most(0.5 * complete_rect.width());
+                        Rect::from_min_max(
+                            pos2(axes_rect.max.x, axes_rect.min.y),
+                            complete_rect.max,
+                        )
+                    }
+                    OuterPosition::Bottom => {
+                        axes_rect.max.y -= size.at_most(0.5 * complete_rect.height());
+                        Rect::from_min_max(
+                            pos2(axes_rect.min.x, axes_rect.max.y),
+                            complete_rect.max,
+                        )
+                    }
+                }
+            });
+
+        let ([x_axis_widgets, y_axis_widgets], plot_rect) =
+            axis_widgets(last_mem.as_ref(), show_axes, axes_rect, [&x_axes, &y_axes]);
 
         // Allocate the plot window.
         let response = ui.allocate_rect(plot_rect, sense);
+        let first_painted_shape = num_painted_shapes(ui);
 
         // Load or initialize the memory.
         ui.ctx().check_for_id_clash(plot_id, plot_rect, "Plot");
@@ -847,13 +1352,47 @@ impl<'a> Plot<'a> {
             hovered_legend_item: None,
             hidden_items: Default::default(),
             transform: PlotTransform::new(plot_rect, min_auto_bounds, center_axis.x, center_axis.y),
+            secondary_y: None,
+            legend_scroll_offset: 0.0,
+            bounds_animation: None,
             last_click_pos_for_zoom: None,
+            selection_start: None,
+            selection: None,
             x_axis_thickness: Default::default(),
             y_axis_thickness: Default::default(),
+            legend_outside_size: 0.0,
+            dragged_point: None,
         });
+        mem.transform.set_axis_scales(axis_scales);
+        let min_auto_bounds = mem.transform.bounds_from_data(&min_auto_bounds);
 
         let last_plot_transform = mem.transform;
 
+        // Hidden items set by the app take precedence over the ones in memory.
+        if let Some(hidden_items) = legend_config
+            .as_ref()
+            .and_then(|config| config.hidden_items.clone())
+        {
+            mem.hidden_items = hidden_items;
+        }
+
+        // Take over the selection made or cleared in another plot of our group.
+        if let Some((id, link_y)) = linked_selection {
+            ui.data_mut(|data| {
+                let groups: &mut SelectionLinkGroups = data.get_temp_mut_or_default(Id::NULL);
+                if let Some(linked) = groups.0.get(&id).filter(|linked| linked.plot_id != plot_id) {
+                    mem.selection = linked.selection.map(|mut bounds| {
+                        if !link_y {
+                            bounds.min[1] = f64::NEG_INFINITY;
+                            bounds.max[1] = f64::INFINITY;
+                        }
+                        bounds
+                    });
+                }
+            });
+        }
+        let linked_selection_before = mem.selection;
+
         // Call the plot build function.
         let mut plot_ui = PlotUi {
             ctx: ui.ctx().clone(),
@@ -861,6 +1400,8 @@ impl<'a> Plot<'a> {
             next_auto_color_idx: 0,
             last_plot_transform,
             last_auto_bounds: mem.auto_bounds,
+            hidden_items: mem.hidden_items.clone(),
+            selection: mem.selection,
             response,
             bounds_modifications: Vec::new(),
         };
@@ -869,9 +1410,13 @@ impl<'a> Plot<'a> {
             mut items,
             mut response,
             last_plot_transform,
+            hidden_items,
+            selection,
             bounds_modifications,
             ..
         } = plot_ui;
+        mem.hidden_items = hidden_items;
+        mem.selection = selection;
 
         // Background
         if show_background {
@@ -886,8 +1431,16 @@ impl<'a> Plot<'a> {
         }
 
         // --- Legend ---
-        let legend = legend_config
-            .and_then(|config| LegendWidget::try_new(plot_rect, config, &items, &mem.hidden_items));
+        let legend = legend_config.and_then(|config| {
+            LegendWidget::try_new(
+                outside_legend_rect.unwrap_or(plot_rect),
+                config,
+                &items,
+                &mem.hidden_items,
+                plot_id.with("legend"),
+                mem.legend_scroll_offset,
+            )
+        });
         // Don't show hover cursor when hovering over legend.
         if mem.hovered_legend_item.is_some() {
             show_x = false;
@@ -905,11 +1458,14 @@ impl<'a> Plot<'a> {
         // Move highlighted items to front.
         items.sort_by_key(|item| item.highlighted());
 
+        let has_secondary =
+            has_secondary_axis || items.iter().any(|item| item.y_axis() == YAxis::Secondary);
+
         // --- Bound computation ---
         let mut bounds = *last_plot_transform.bounds();
 
         // Find the cursors from other plots we need to draw
-        let draw_cursors: Vec<Cursor> = if let Some((id, _)) = linked_cursors.as_ref() {
+        let (draw_cursors, linked_crosshair) = if let Some((id, _)) = linked_cursors.as_ref() {
             ui.data_mut(|data| {
                 let frames: &mut CursorLinkGroups = data.get_temp_mut_or_default(Id::NULL);
                 let cursors = frames.0.entry(*id).or_default();
@@ -927,15 +1483,26 @@ impl<'a> Plot<'a> {
 
                 // Gather all cursors of the remaining frames. This will be all the cursors of the
                 // other plots in the group. We want to draw these in the current plot too.
-                cursors
+                let draw_cursors: Vec<Cursor> = cursors
                     .iter()
                     .flat_map(|frame| frame.cursors.iter().copied())
-                    .collect()
+                    .collect();
+                let linked_crosshair = cursors.iter().rev().find_map(|frame| frame.crosshair);
+                (draw_cursors, linked_crosshair)
             })
         } else {
-            Vec::new()
+            (Vec::new(), None)
         };
 
+        // Find the category hovered in another plot of our group.
+        let frame_nr = ui.ctx().frame_nr();
+        let linked_category = linked_category_hover.and_then(|id| {
+            ui.data_mut(|data| {
+                let groups: &mut CategoryHoverLinkGroups = data.get_temp_mut_or_default(Id::NULL);
+                groups.hovered_by_other(id, plot_id, frame_nr)
+            })
+        });
+
         // Transfer the bounds from a link group.
         if let Some((id, axes)) = linked_axes.as_ref() {
             ui.data_mut(|data| {
@@ -956,15 +1523,41 @@ impl<'a> Plot<'a> {
         // Allow double-clicking to reset to the initial bounds.
         if allow_double_click_reset && response.double_clicked() {
             mem.auto_bounds = true.into();
+            mem.bounds_animation = None;
         }
 
         // Apply bounds modifications.
+        let time = ui.input(|i| i.time);
         for modification in bounds_modifications {
+            // Any other change of the bounds cancels an animation.
+            mem.bounds_animation = None;
             match modification {
+                BoundsModification::Animate(target, duration, easing) => {
+                    let target = last_plot_transform.bounds_from_data(&target);
+                    mem.bounds_animation = Some(
+                        Tween::new(bounds, target, duration)
+                            .starting_at(time)
+                            .with_easing(easing),
+                    );
+                    mem.auto_bounds = false.into();
+                }
                 BoundsModification::Set(new_bounds) => {
-                    bounds = new_bounds;
+                    bounds = last_plot_transform.bounds_from_data(&new_bounds);
                     mem.auto_bounds = false.into();
                 }
+                BoundsModification::SetX(range) => {
+                    // Any positive y works, also on a logarithmic axis.
+                    let new_bounds =
+                        PlotBounds::from_min_max([*range.start(), 1.0], [*range.end(), 1.0]);
+                    bounds.set_x(&last_plot_transform.bounds_from_data(&new_bounds));
+                    mem.auto_bounds.x = false;
+                }
+                BoundsModification::SetY(range) => {
+                    let new_bounds =
+                        PlotBounds::from_min_max([1.0, *range.start()], [1.0, *range.end()]);
+                    bounds.set_y(&last_plot_transform.bounds_from_data(&new_bounds));
+                    mem.auto_bounds.y = false;
+                }
                 BoundsModification::Translate(delta) => {
                     let delta = (delta.x as f64, delta.y as f64);
                     bounds.translate(delta);
@@ -974,12 +1567,25 @@ impl<'a> Plot<'a> {
                     mem.auto_bounds = new_auto_bounds;
                 }
                 BoundsModification::Zoom(zoom_factor, center) => {
+                    let center = PlotPoint::new(
+                        axis_scales[0].scaled(center.x),
+                        axis_scales[1].scaled(center.y),
+                    );
                     bounds.zoom(zoom_factor, center);
                     mem.auto_bounds = false.into();
                 }
             }
         }
 
+        if let Some(animation) = &mem.bounds_animation {
+            bounds = animation.value_at(time);
+            if animation.is_finished(time) {
+                mem.bounds_animation = None;
+            } else {
+                ui.ctx().request_repaint();
+            }
+        }
+
         // Reset bounds to initial bounds if they haven't been modified.
         if mem.auto_bounds.x {
             bounds.set_x(&min_auto_bounds);
@@ -995,24 +1601,50 @@ impl<'a> Plot<'a> {
         if auto_x || auto_y {
             for item in &items {
                 let item_bounds = item.bounds();
+                #[cfg(feature = "log")]
+                for (axis, scale) in axis_scales.iter().enumerate() {
+                    if let AxisScale::Log10 { min } = scale {
+                        if item_bounds.is_valid() && item_bounds.min[axis] < *min {
+                            log::debug!(
+                                "Plot item {:?} has values below {min} on a logarithmic axis, they are clamped",
+                                item.name()
+                            );
+                        }
+                    }
+                }
+                let item_bounds = last_plot_transform.bounds_from_data(&item_bounds);
                 if auto_x {
                     bounds.merge_x(&item_bounds);
                 }
-                if auto_y {
+                // Items on the secondary Y axis must not distort the primary one.
+                if auto_y && item.y_axis() == YAxis::Primary {
                     bounds.merge_y(&item_bounds);
                 }
             }
 
             if auto_x {
-                bounds.add_relative_margin_x(margin_fraction);
+                let margin = auto_margin(
+                    bounds.width(),
+                    margin_fraction.x,
+                    min_margin.x,
+                    plot_rect.width(),
+                );
+                bounds.expand_x(margin);
             }
 
             if auto_y {
-                bounds.add_relative_margin_y(margin_fraction);
+                let margin = auto_margin(
+                    bounds.height(),
+                    margin_fraction.y,
+                    min_margin.y,
+                    plot_rect.height(),
+                );
+                bounds.expand_y(margin);
             }
         }
 
-        mem.transform = PlotTransform::new(plot_rect, bounds, center_axis.x, center_axis.y);
+        mem.transform = PlotTransform::new(plot_rect, bounds, center_axis.x, center_axis.y)
+            .with_axis_scales(axis_scales);
 
         // Enforce aspect ratio
         if let Some(data_aspect) = data_aspect {
@@ -1030,8 +1662,113 @@ impl<'a> Plot<'a> {
             }
         }
 
+        // Fit the secondary Y axis. It is stored as a linear mapping from the primary Y axis,
+        // so that zooming and panning scale both axes proportionally.
+        let mut locked_secondary_y = None;
+        if has_secondary {
+            let primary_y = y_range(mem.transform.bounds());
+            if auto_y || mem.secondary_y.is_none() {
+                let mut secondary_bounds = PlotBounds::NOTHING;
+                for item in items
+                    .iter()
+                    .filter(|item| item.y_axis() == YAxis::Secondary)
+                {
+                    secondary_bounds.merge_y(&item.bounds());
+                }
+                secondary_bounds.expand_y(auto_margin(
+                    secondary_bounds.height(),
+                    margin_fraction.y,
+                    min_margin.y,
+                    plot_rect.height(),
+                ));
+                secondary_bounds.set_x(mem.transform.bounds());
+                let secondary_bounds =
+                    *PlotTransform::new(plot_rect, secondary_bounds, false, false).bounds();
+                mem.secondary_y = Some(secondary_y_mapping(primary_y, y_range(&secondary_bounds)));
+            } else if lock_secondary_y {
+                if let Some(mapping) = mem.secondary_y {
+                    let last_secondary_y =
+                        secondary_y_range(mapping, y_range(last_plot_transform.bounds()));
+                    mem.secondary_y = Some(secondary_y_mapping(primary_y, last_secondary_y));
+                }
+            }
+            if lock_secondary_y {
+                locked_secondary_y = mem
+                    .secondary_y
+                    .map(|mapping| secondary_y_range(mapping, primary_y));
+            }
+        } else {
+            mem.secondary_y = None;
+        }
+
+        // Dragging the points of editable items takes precedence over panning and zooming.
+        let mut moved_points = Vec::new();
+        let mut point_cursor = None;
+        if response.drag_started_by(PointerButton::Primary) {
+            mem.dragged_point = ui
+                .input(|i| i.pointer.press_origin())
+                .and_then(|origin| draggable_point_at(&items, &mem.transform, origin));
+        }
+        if let Some(dragged) = &mut mem.dragged_point {
+            if let (true, Some(pointer)) = (
+                response.dragged_by(PointerButton::Primary),
+                response.interact_pointer_pos(),
+            ) {
+                let to = mem.transform.value_from_position(pointer + dragged.offset);
+                if let Some(item) = items.iter_mut().find(|item| {
+                    item.points_draggable() && draggable_item_id(&***item) == dragged.item
+                }) {
+                    let mut moved = Vec::new();
+                    dragged.index = item.drag_point(dragged.index, to, &mut moved);
+                    moved_points.extend(
+                        moved
+                            .into_iter()
+                            .map(|(index, point)| (dragged.item, index, point)),
+                    );
+                }
+                ui.ctx().set_cursor_icon(CursorIcon::Grabbing);
+            }
+        } else if let Some(hover_pos) = response.hover_pos() {
+            if draggable_point_at(&items, &mem.transform, hover_pos).is_some() {
+                point_cursor = Some(CursorIcon::Grab);
+            }
+        }
+        let dragging_point = mem.dragged_point.is_some();
+
+        // Start a selection or a boxed zoom where the drag started.
+        let start_selection = selection_mode.is_some()
+            && !dragging_point
+            && response.drag_started_by(PointerButton::Primary)
+            && ui.input(|i| i.modifiers.contains(selection_modifier));
+        if start_selection {
+            mem.selection_start = ui.input(|i| i.pointer.press_origin());
+        }
+        if allow_boxed_zoom
+            && !start_selection
+            && !dragging_point
+            && response.drag_started_by(boxed_zoom_pointer_button)
+            && ui.input(|i| i.modifiers.contains(boxed_zoom_modifier))
+        {
+            mem.last_click_pos_for_zoom = ui.input(|i| i.pointer.press_origin());
+        }
+        if ui.input(|i| i.key_pressed(Key::Escape)) {
+            mem.last_click_pos_for_zoom = None;
+            mem.selection_start = None;
+            mem.selection = None;
+        }
+        let boxed_zooming = mem.last_click_pos_for_zoom.is_some();
+        let selecting = mem.selection_start.is_some();
+        // During a pinch gesture the fingers pan and zoom together, see below.
+        let multi_touch = ui.input(|i| i.multi_touch());
+
         // Dragging
-        if allow_drag.any() && response.dragged_by(PointerButton::Primary) {
+        if allow_drag.any()
+            && response.dragged_by(PointerButton::Primary)
+            && !boxed_zooming
+            && !selecting
+            && !dragging_point
+            && multi_touch.is_none()
+        {
             response = response.on_hover_cursor(CursorIcon::Grabbing);
             let mut delta = -response.drag_delta();
             if !allow_drag.x {
@@ -1043,60 +1780,85 @@ impl<'a> Plot<'a> {
             mem.transform
                 .translate_bounds((delta.x as f64, delta.y as f64));
             mem.auto_bounds = mem.auto_bounds.and(!allow_drag);
+            mem.bounds_animation = None;
         }
 
-        // Zooming
+        // Boxed zooming
         let mut boxed_zoom_rect = None;
-        if allow_boxed_zoom {
-            // Save last click to allow boxed zooming
-            if response.drag_started() && response.dragged_by(boxed_zoom_pointer_button) {
-                // it would be best for egui that input has a memory of the last click pos because it's a common pattern
-                mem.last_click_pos_for_zoom = response.hover_pos();
+        if let (Some(box_start_pos), Some(box_end_pos)) =
+            (mem.last_click_pos_for_zoom, response.hover_pos())
+        {
+            let rect = Rect::from_two_pos(box_start_pos, box_end_pos);
+            // while dragging prepare the rect and draw it later on top of the plot
+            if response.dragged_by(boxed_zoom_pointer_button) {
+                response = response.on_hover_cursor(CursorIcon::ZoomIn);
+                boxed_zoom_rect = Some(rect);
             }
-            let box_start_pos = mem.last_click_pos_for_zoom;
-            let box_end_pos = response.hover_pos();
-            if let (Some(box_start_pos), Some(box_end_pos)) = (box_start_pos, box_end_pos) {
-                // while dragging prepare a Shape and draw it later on top of the plot
-                if response.dragged_by(boxed_zoom_pointer_button) {
-                    response = response.on_hover_cursor(CursorIcon::ZoomIn);
-                    let rect = epaint::Rect::from_two_pos(box_start_pos, box_end_pos);
-                    boxed_zoom_rect = Some((
-                        epaint::RectShape::stroke(
-                            rect,
-                            0.0,
-                            epaint::Stroke::new(4., Color32::DARK_BLUE),
-                        ), // Outer stroke
-                        epaint::RectShape::stroke(
-                            rect,
-                            0.0,
-                            epaint::Stroke::new(2., Color32::WHITE),
-                        ), // Inner stroke
-                    ));
+            // when the click is released perform the zoom, unless the box is too small
+            // to be intentional
+            if response.drag_stopped()
+                && rect.width() >= MIN_BOXED_ZOOM_SIZE
+                && rect.height() >= MIN_BOXED_ZOOM_SIZE
+            {
+                let box_start_pos = mem.transform.value_from_position(box_start_pos);
+                let box_end_pos = mem.transform.value_from_position(box_end_pos);
+                let new_bounds = PlotBounds {
+                    min: [
+                        box_start_pos.x.min(box_end_pos.x),
+                        box_start_pos.y.min(box_end_pos.y),
+                    ],
+                    max: [
+                        box_start_pos.x.max(box_end_pos.x),
+                        box_start_pos.y.max(box_end_pos.y),
+                    ],
+                };
+                let new_bounds = mem.transform.bounds_from_data(&new_bounds);
+                if new_bounds.is_valid() {
+                    mem.transform.set_bounds(new_bounds);
+                    mem.auto_bounds = false.into();
+                    mem.bounds_animation = None;
                 }
-                // when the click is release perform the zoom
-                if response.drag_stopped() {
-                    let box_start_pos = mem.transform.value_from_position(box_start_pos);
-                    let box_end_pos = mem.transform.value_from_position(box_end_pos);
-                    let new_bounds = PlotBounds {
-                        min: [
-                            box_start_pos.x.min(box_end_pos.x),
-                            box_start_pos.y.min(box_end_pos.y),
-                        ],
-                        max: [
-                            box_start_pos.x.max(box_end_pos.x),
-                            box_start_pos.y.max(box_end_pos.y),
-                        ],
-                    };
-                    if new_bounds.is_valid() {
-                        mem.transform.set_bounds(new_bounds);
-                        mem.auto_bounds = false.into();
-                    }
-                    // reset the boxed zoom state
-                    mem.last_click_pos_for_zoom = None;
+            }
+        }
+        // Selecting
+        let mut selection_rect = None;
+        if let (Some(mode), Some(start_pos), Some(end_pos)) =
+            (selection_mode, mem.selection_start, response.hover_pos())
+        {
+            let mut rect = Rect::from_two_pos(start_pos, end_pos);
+            if mode == SelectionMode::Horizontal {
+                rect.set_top(plot_rect.top());
+                rect.set_bottom(plot_rect.bottom());
+            }
+            if response.dragged_by(PointerButton::Primary) {
+                selection_rect = Some(rect);
+            }
+            // Like boxed zooms, tiny selections are most likely accidental.
+            if response.drag_stopped()
+                && rect.width() >= MIN_BOXED_ZOOM_SIZE
+                && rect.height() >= MIN_BOXED_ZOOM_SIZE
+            {
+                let start = mem.transform.value_from_position(start_pos);
+                let end = mem.transform.value_from_position(end_pos);
+                let mut bounds = PlotBounds::from_min_max(
+                    [start.x.min(end.x), start.y.min(end.y)],
+                    [start.x.max(end.x), start.y.max(end.y)],
+                );
+                if mode == SelectionMode::Horizontal {
+                    bounds.min[1] = f64::NEG_INFINITY;
+                    bounds.max[1] = f64::INFINITY;
                 }
+                mem.selection = Some(bounds);
             }
         }
 
+        if response.drag_stopped() {
+            // reset the boxed zoom, selection and point dragging state
+            mem.last_click_pos_for_zoom = None;
+            mem.selection_start = None;
+            mem.dragged_point = None;
+        }
+
         // Note: we catch zoom/pan if the response contains the pointer, even if it isn't hovered.
         // For instance: The user is painting another interactive widget on top of the plot
         // but they still want to be able to pan/zoom the plot.
@@ -1104,6 +1866,11 @@ impl<'a> Plot<'a> {
             response.contains_pointer,
             ui.input(|i| i.pointer.hover_pos()),
         ) {
+            let allow_zoom = if multi_touch.is_some() {
+                allow_zoom.and(pinch_zoom_axes)
+            } else {
+                allow_zoom
+            };
             if allow_zoom.any() {
                 let mut zoom_factor = if data_aspect.is_some() {
                     Vec2::splat(ui.input(|i| i.zoom_delta()))
@@ -1119,6 +1886,22 @@ impl<'a> Plot<'a> {
                 if zoom_factor != Vec2::splat(1.0) {
                     mem.transform.zoom(zoom_factor, hover_pos);
                     mem.auto_bounds = mem.auto_bounds.and(!allow_zoom);
+                    mem.bounds_animation = None;
+                }
+            }
+            if let Some(touch) = multi_touch.filter(|_| allow_drag.any()) {
+                let mut delta = -touch.translation_delta;
+                if !allow_drag.x {
+                    delta.x = 0.0;
+                }
+                if !allow_drag.y {
+                    delta.y = 0.0;
+                }
+                if delta != Vec2::ZERO {
+                    mem.transform
+                        .translate_bounds((delta.x as f64, delta.y as f64));
+                    mem.auto_bounds = mem.auto_bounds.and(!allow_drag);
+                    mem.bounds_animation = None;
                 }
             }
             if allow_scroll.any() {
@@ -1133,29 +1916,75 @@ impl<'a> Plot<'a> {
                     mem.transform
                         .translate_bounds((-scroll_delta.x as f64, -scroll_delta.y as f64));
                     mem.auto_bounds = false.into();
+                    mem.bounds_animation = None;
                 }
             }
         }
 
-        // --- transform initialized
-
-        // Add legend widgets to plot
-        let bounds = mem.transform.bounds();
-        let x_axis_range = bounds.range_x();
-        let x_steps = Arc::new({
-            let input = GridInput {
-                bounds: (bounds.min[0], bounds.max[0]),
-                base_step_size: mem.transform.dvalue_dpos()[0].abs() * grid_spacing.min as f64,
-            };
-            (grid_spacers[0])(input)
-        });
-        let y_axis_range = bounds.range_y();
-        let y_steps = Arc::new({
-            let input = GridInput {
-                bounds: (bounds.min[1], bounds.max[1]),
-                base_step_size: mem.transform.dvalue_dpos()[1].abs() * grid_spacing.min as f64,
-            };
-            (grid_spacers[1])(input)
+        // Keep the view within the constraints, whichever way it was changed.
+        let mut limits = bounds_constraint.map_or([None; 2], |constraint| {
+            let constraint = mem.transform.bounds_from_data(&constraint);
+            [0, 1].map(|axis| Some([constraint.min[axis], constraint.max[axis]]))
+        });
+        // Keep a categorical x-axis within its categories.
+        if !x_categories.is_empty() {
+            let num_categories = x_categories.len() as f64;
+            let margin = 0.5 + margin_fraction.x as f64 * num_categories;
+            let (min_x, max_x) = (-margin, num_categories - 1.0 + margin);
+            limits[0] = Some(limits[0].map_or([min_x, max_x], |[min, max]| {
+                [min.max(min_x), max.min(max_x)]
+            }));
+        }
+        let bounds = constrain_bounds(*mem.transform.bounds(), limits, &zoom_ranges);
+        if bounds != *mem.transform.bounds() {
+            mem.transform.set_bounds(bounds);
+        }
+
+        if let Some(locked_secondary_y) = locked_secondary_y {
+            mem.secondary_y = Some(secondary_y_mapping(
+                y_range(mem.transform.bounds()),
+                locked_secondary_y,
+            ));
+        }
+        let secondary_transform = mem.secondary_y.map(|mapping| {
+            let mut bounds = *mem.transform.bounds();
+            [bounds.min[1], bounds.max[1]] = secondary_y_range(mapping, y_range(&bounds));
+            PlotTransform::new(plot_rect, bounds, center_axis.x, false)
+                .with_axis_scales([axis_scales[0], AxisScale::Linear])
+        });
+
+        // --- transform initialized
+
+        // Add legend widgets to plot
+        let data_bounds = mem.transform.data_bounds();
+        let x_axis_range = data_bounds.range_x();
+        let x_steps = Arc::new(grid_marks(
+            &grid_spacers[0],
+            &mem.transform,
+            Axis::X,
+            grid_spacing.min,
+        ));
+        let y_axis_range = data_bounds.range_y();
+        let y_steps = Arc::new(grid_marks(
+            &grid_spacers[1],
+            &mem.transform,
+            Axis::Y,
+            grid_spacing.min,
+        ));
+
+        // The crosshair labels are formatted like the ticks of the first axes,
+        // with the precision of the finest grid lines.
+        let axis_formatters = [(&x_axes, Axis::X), (&y_axes, Axis::Y)].map(|(axes, axis)| {
+            axes.first().map_or_else(
+                || AxisHints::new(axis).formatter,
+                |hints| hints.formatter.clone(),
+            )
+        });
+        let crosshair_step_sizes = [&x_steps, &y_steps].map(|steps| {
+            steps
+                .iter()
+                .map(|step| step.step_size)
+                .fold(f64::INFINITY, f64::min)
         });
         for (i, mut widget) in x_axis_widgets.into_iter().enumerate() {
             widget.range = x_axis_range.clone();
@@ -1165,50 +1994,130 @@ impl<'a> Plot<'a> {
             mem.x_axis_thickness.insert(i, thickness);
         }
         for (i, mut widget) in y_axis_widgets.into_iter().enumerate() {
-            widget.range = y_axis_range.clone();
-            widget.transform = Some(mem.transform);
-            widget.steps = y_steps.clone();
+            // The widgets are in reverse order, so the secondary axis is the first one.
+            if let (true, 0, Some(secondary_transform)) =
+                (has_secondary_axis, i, secondary_transform.as_ref())
+            {
+                widget.range = secondary_transform.bounds().range_y();
+                widget.transform = Some(*secondary_transform);
+                widget.steps = Arc::new(grid_marks(
+                    &log_grid_spacer(10),
+                    secondary_transform,
+                    Axis::Y,
+                    grid_spacing.min,
+                ));
+            } else {
+                widget.range = y_axis_range.clone();
+                widget.transform = Some(mem.transform);
+                widget.steps = y_steps.clone();
+            }
             let (_response, thickness) = widget.ui(ui, Axis::Y);
             mem.y_axis_thickness.insert(i, thickness);
         }
 
         // Initialize values from functions.
         for item in &mut items {
-            item.initialize(mem.transform.bounds().range_x());
+            item.initialize(data_bounds.range_x());
+        }
+
+        if allow_data_export {
+            response.context_menu(|ui| {
+                if ui.button("Copy visible data as CSV").clicked() {
+                    let csv =
+                        visible_data_csv(&items, &mem.transform, secondary_transform.as_ref());
+                    ui.ctx().copy_text(csv);
+                    ui.close_menu();
+                }
+            });
         }
 
+        // Not shown while hovering the legend, like the hover cursors.
+        let crosshair_point = (crosshair != Crosshair::None && mem.hovered_legend_item.is_none())
+            .then(|| response.hover_pos())
+            .flatten()
+            .map(|pointer| mem.transform.value_from_position(pointer));
+
         let prepared = PreparedPlot {
             items,
             show_x,
             show_y,
             label_formatter,
             coordinates_formatter,
+            crosshair,
             show_grid,
             grid_spacing,
             transform: mem.transform,
+            secondary_transform,
             draw_cursor_x: linked_cursors.as_ref().map_or(false, |group| group.1.x),
             draw_cursor_y: linked_cursors.as_ref().map_or(false, |group| group.1.y),
             draw_cursors,
+            crosshair_point,
+            linked_crosshair,
+            axis_formatters,
+            crosshair_step_sizes,
             grid_spacers,
             sharp_grid_lines,
             clamp_grid,
+            x_categories,
+            x_time,
+            linked_category,
         };
 
-        let (plot_cursors, hovered_plot_item) = prepared.ui(ui, &response);
+        let (plot_cursors, hovered_plot_item, hovered_category) = prepared.ui(ui, &response);
 
-        if let Some(boxed_zoom_rect) = boxed_zoom_rect {
-            ui.painter()
-                .with_clip_rect(plot_rect)
-                .add(boxed_zoom_rect.0);
-            ui.painter()
-                .with_clip_rect(plot_rect)
-                .add(boxed_zoom_rect.1);
+        // The selection being dragged out, or the last one.
+        let selection_rect = selection_rect.or_else(|| {
+            mem.selection.map(|bounds| {
+                let rect = mem.transform.rect_from_values(
+                    &PlotPoint::new(bounds.min[0], bounds.min[1]),
+                    &PlotPoint::new(bounds.max[0], bounds.max[1]),
+                );
+                rect.intersect(plot_rect)
+            })
+        });
+        if let Some(rect) = selection_rect {
+            let painter = ui.painter().with_clip_rect(plot_rect);
+            let selection = ui.visuals().selection;
+            painter.rect(
+                rect,
+                0.0,
+                selection.bg_fill.gamma_multiply(0.25),
+                selection.stroke,
+            );
+        }
+
+        if let Some(rect) = boxed_zoom_rect {
+            let painter = ui.painter().with_clip_rect(plot_rect);
+            painter.rect_filled(
+                rect,
+                0.0,
+                ui.visuals().selection.bg_fill.gamma_multiply(0.25),
+            );
+            let outline = [
+                rect.left_top(),
+                rect.right_top(),
+                rect.right_bottom(),
+                rect.left_bottom(),
+                rect.left_top(),
+            ];
+            let stroke = Stroke::new(1.0, ui.visuals().strong_text_color());
+            painter.extend(Shape::dashed_line(&outline, stroke, 4.0, 4.0));
         }
 
         if let Some(mut legend) = legend {
-            ui.add(&mut legend);
+            let response = ui.add(&mut legend);
+            if let Some(size) = legend.outside_size(&response) {
+                if size != mem.legend_outside_size {
+                    ui.ctx().request_repaint();
+                }
+                mem.legend_outside_size = size;
+            }
             mem.hidden_items = legend.hidden_items();
             mem.hovered_legend_item = legend.hovered_item_name();
+            mem.legend_scroll_offset = legend.scroll_offset();
+        } else {
+            // Without entries there is no legend to make room for.
+            mem.legend_outside_size = 0.0;
         }
 
         if let Some((id, _)) = linked_cursors.as_ref() {
@@ -1219,10 +2128,46 @@ impl<'a> Plot<'a> {
                 cursors.push(PlotFrameCursors {
                     id: plot_id,
                     cursors: plot_cursors,
+                    crosshair: crosshair_point,
                 });
             });
         }
 
+        if let Some(id) = linked_category_hover {
+            // Publish our hovered category, or clear it if we were the one hovering.
+            ui.data_mut(|data| {
+                let groups: &mut CategoryHoverLinkGroups = data.get_temp_mut_or_default(Id::NULL);
+                if let Some(index) = hovered_category {
+                    groups.0.insert(
+                        id,
+                        LinkedCategoryHover {
+                            plot_id,
+                            index,
+                            frame_nr,
+                        },
+                    );
+                } else if groups.0.get(&id).map(|hover| hover.plot_id) == Some(plot_id) {
+                    groups.0.remove(&id);
+                }
+            });
+        }
+
+        if let Some((id, _)) = linked_selection {
+            // Publish our selection if we made or cleared it this frame.
+            if mem.selection != linked_selection_before {
+                ui.data_mut(|data| {
+                    let groups: &mut SelectionLinkGroups = data.get_temp_mut_or_default(Id::NULL);
+                    groups.0.insert(
+                        id,
+                        LinkedSelection {
+                            plot_id,
+                            selection: mem.selection,
+                        },
+                    );
+                });
+            }
+        }
+
         if let Some((id, _)) = linked_axes.as_ref() {
             // Save the linked bounds.
             ui.data_mut(|data| {
@@ -1238,23 +2183,120 @@ impl<'a> Plot<'a> {
         }
 
         let transform = mem.transform;
+        let hidden_items = mem.hidden_items.clone();
+        let selection = mem.selection;
         mem.store(ui.ctx(), plot_id);
 
-        let response = if show_x || show_y {
+        let response = if let Some(cursor) = point_cursor {
+            response.on_hover_cursor(cursor)
+        } else if show_x || show_y {
             response.on_hover_cursor(CursorIcon::Crosshair)
         } else {
             response
         };
 
         ui.advance_cursor_after_rect(complete_rect);
+        let painted_shapes = (ui.layer_id(), first_painted_shape..num_painted_shapes(ui));
 
         PlotResponse {
             inner,
             response,
             transform,
+            secondary_transform,
+            hidden_items,
+            selection,
             hovered_plot_item,
+            moved_points,
+            complete_rect,
+            painted_shapes,
+        }
+    }
+}
+
+/// How close, in ui points, the pointer needs to be to a point to drag it.
+const DRAG_POINT_RADIUS: f32 = 8.0;
+
+/// Identifies a draggable item across frames.
+fn draggable_item_id(item: &dyn PlotItem) -> Id {
+    item.id().unwrap_or_else(|| Id::new(item.name()))
+}
+
+/// The draggable point closest to `pos`, if any is close enough.
+fn draggable_point_at(
+    items: &[Box<dyn PlotItem>],
+    transform: &PlotTransform,
+    pos: Pos2,
+) -> Option<DraggedPoint> {
+    let mut closest: Option<(f32, DraggedPoint)> = None;
+    for item in items.iter().filter(|item| item.points_draggable()) {
+        let PlotGeometry::Points(points) = item.geometry() else {
+            continue;
+        };
+        for (index, point) in points.iter().enumerate() {
+            let offset = transform.position_from_point(point) - pos;
+            let dist_sq = offset.length_sq();
+            if dist_sq <= DRAG_POINT_RADIUS * DRAG_POINT_RADIUS
+                && closest.map_or(true, |(closest_sq, _)| dist_sq < closest_sq)
+            {
+                let item = draggable_item_id(item.as_ref());
+                closest = Some((
+                    dist_sq,
+                    DraggedPoint {
+                        item,
+                        index,
+                        offset,
+                    },
+                ));
+            }
+        }
+    }
+    closest.map(|(_, point)| point)
+}
+
+/// The points of the items within the plot bounds as CSV, with a `series,x,y` header.
+fn visible_data_csv(
+    items: &[Box<dyn PlotItem>],
+    transform: &PlotTransform,
+    secondary_transform: Option<&PlotTransform>,
+) -> String {
+    use std::fmt::Write as _;
+
+    let mut csv = String::from("series,x,y\n");
+    for item in items {
+        let transform = match (item.y_axis(), secondary_transform) {
+            (YAxis::Secondary, Some(secondary_transform)) => secondary_transform,
+            _ => transform,
+        };
+        let bounds = transform.data_bounds();
+        let series = csv_field(item.name());
+        for point in item.export_points() {
+            let visible = (bounds.min[0]..=bounds.max[0]).contains(&point.x)
+                && (bounds.min[1]..=bounds.max[1]).contains(&point.y);
+            if visible {
+                // `Display` of floats always uses `.` as decimal separator.
+                let _ = writeln!(csv, "{series},{},{}", point.x, point.y);
+            }
         }
     }
+    csv
+}
+
+/// Quote a CSV field if necessary.
+fn csv_field(field: &str) -> String {
+    if field.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", field.replace('"', "\"\""))
+    } else {
+        field.to_owned()
+    }
+}
+
+/// The number of shapes painted so far to the layer of the [`Ui`].
+fn num_painted_shapes(ui: &Ui) -> usize {
+    ui.ctx().graphics(|graphics| {
+        graphics
+            .get(ui.layer_id())
+            .map_or(0, |paint_list| paint_list.all_entries().len())
+    })
 }
 
 /// Returns the rect left after adding axes.
@@ -1371,7 +2413,10 @@ fn axis_widgets<'a>(
 /// User-requested modifications to the plot bounds. We collect them in the plot build function to later apply
 /// them at the right time, as other modifications need to happen first.
 enum BoundsModification {
+    Animate(PlotBounds, f32, BoxedCurve),
     Set(PlotBounds),
+    SetX(RangeInclusive<f64>),
+    SetY(RangeInclusive<f64>),
     Translate(Vec2),
     AutoBounds(Vec2b),
     Zoom(Vec2, PlotPoint),
@@ -1457,6 +2502,185 @@ pub fn uniform_grid_spacer<'a>(spacer: impl Fn(GridInput) -> [f64; 3] + 'a) -> G
     Box::new(get_marks)
 }
 
+/// Grid for an axis with [`AxisScale::Log10`]: a line per decade, and fainter lines at 2 and 5 times each decade.
+///
+/// When zoomed far out, only every 10th or 100th decade gets a line.
+/// Used by [`Plot::x_axis_scale`] and [`Plot::y_axis_scale`].
+pub fn log10_grid_spacer() -> GridSpacer<'static> {
+    let get_marks = |input: GridInput| -> Vec<GridMark> {
+        let (min, max) = input.bounds;
+        if !(min.is_finite() && max.is_finite()) || input.base_step_size <= 0.0 {
+            return Vec::new();
+        }
+
+        // The bounds are in decades, so never use less than one decade:
+        let decade_step = next_power(input.base_step_size.at_least(1.0), 10.0);
+        let mut marks = generate_marks(
+            [decade_step, 10.0 * decade_step, 100.0 * decade_step],
+            input.bounds,
+        );
+
+        if decade_step <= 1.0 {
+            let minor_step = 2.0_f64.log10();
+            for decade in (min.floor() as i64)..=(max.ceil() as i64) {
+                for factor in [2.0_f64, 5.0] {
+                    let value = decade as f64 + factor.log10();
+                    if min <= value && value <= max {
+                        marks.push(GridMark {
+                            value,
+                            step_size: minor_step,
+                        });
+                    }
+                }
+            }
+            marks.sort_by(|a, b| cmp_f64(a.value, b.value));
+        }
+        marks
+    };
+
+    Box::new(get_marks)
+}
+
+/// Tick labels for an axis with [`AxisScale::Log10`]: plain numbers below 10 000, powers of ten above.
+fn log10_axis_formatter(mark: GridMark, _range: &RangeInclusive<f64>) -> String {
+    let value = mark.value;
+    if !(value.is_finite() && 0.0 < value) {
+        return String::new();
+    }
+
+    // Marks are at 1, 2 or 5 times a power of ten, with some floating point error:
+    let exponent = (value.log10() + 1e-9).floor();
+    let mantissa = (value / 10.0_f64.powf(exponent)).round();
+    if exponent < 4.0 {
+        let decimals = (-exponent).at_least(0.0) as usize;
+        format_number(mantissa * 10.0_f64.powf(exponent), decimals)
+    } else if mantissa == 1.0 {
+        format!("10^{exponent}")
+    } else {
+        format!("{mantissa}×10^{exponent}")
+    }
+}
+
+/// Ask `spacer` for the grid marks of the given axis, and convert them to data values.
+///
+/// The spacer works in the space of [`PlotTransform::bounds`], which differs from
+/// data values on axes with an [`AxisScale`] other than [`AxisScale::Linear`].
+/// The step sizes are kept in that space, since they decide how strong the marks are drawn.
+fn grid_marks(
+    spacer: &GridSpacer<'_>,
+    transform: &PlotTransform,
+    axis: Axis,
+    min_spacing: f32,
+) -> Vec<GridMark> {
+    let iaxis = usize::from(axis);
+    let bounds = transform.bounds();
+    let input = GridInput {
+        bounds: (bounds.min[iaxis], bounds.max[iaxis]),
+        base_step_size: transform.dvalue_dpos()[iaxis].abs() * min_spacing as f64,
+    };
+    let scale = transform.axis_scales()[iaxis];
+    let mut marks = spacer(input);
+    if scale != AxisScale::Linear {
+        for mark in &mut marks {
+            mark.value = scale.unscaled(mark.value);
+        }
+    }
+    marks
+}
+
+fn y_range(bounds: &PlotBounds) -> [f64; 2] {
+    [bounds.min[1], bounds.max[1]]
+}
+
+/// The `(scale, offset)` that maps the primary Y range onto the secondary one.
+fn secondary_y_mapping(primary: [f64; 2], secondary: [f64; 2]) -> (f64, f64) {
+    let scale = (secondary[1] - secondary[0]) / (primary[1] - primary[0]);
+    (scale, secondary[0] - scale * primary[0])
+}
+
+fn secondary_y_range((scale, offset): (f64, f64), primary: [f64; 2]) -> [f64; 2] {
+    [scale * primary[0] + offset, scale * primary[1] + offset]
+}
+
+/// One mark per category, see [`Plot::x_axis_categories`].
+fn category_grid_spacer(num_categories: usize) -> GridSpacer<'static> {
+    let get_marks = move |input: GridInput| -> Vec<GridMark> {
+        // Skip categories when zoomed far out, but never produce fractional marks:
+        let step_size = input.base_step_size.ceil().at_least(1.0);
+        let last = num_categories as f64 - 1.0;
+        let first = (input.bounds.0.at_least(0.0) / step_size).ceil() * step_size;
+        let mut marks = vec![];
+        let mut value = first;
+        while value <= input.bounds.1.at_most(last) {
+            marks.push(GridMark { value, step_size });
+            value += step_size;
+        }
+        marks
+    };
+
+    Box::new(get_marks)
+}
+
+/// The name of the category centered on `x`, if any.
+pub(crate) fn category_at(categories: &[String], x: f64) -> Option<&str> {
+    let index = x.round();
+    if 0.0 <= index && index < categories.len() as f64 {
+        Some(categories[index as usize].as_str())
+    } else {
+        None
+    }
+}
+
+/// The margin on each side of an auto-bounded axis spanning `span` in data units.
+///
+/// It is `margin_fraction` of the span, but at least `min_margin` ui points on an axis
+/// which is `size` ui points long.
+fn auto_margin(span: f64, margin_fraction: f32, min_margin: f32, size: f32) -> f64 {
+    let span = span.max(0.0);
+    let relative = margin_fraction as f64 * span;
+    // With a margin of `m` on both sides, `m * size / (span + 2 * m) >= min_margin`:
+    let absolute = if min_margin > 0.0 && size > 2.0 * min_margin {
+        min_margin as f64 * span / (size - 2.0 * min_margin) as f64
+    } else {
+        0.0
+    };
+    relative.max(absolute)
+}
+
+/// Clamp the width and height of `bounds` into `zoom_ranges`, keeping the center,
+/// and then move them into the `[min, max]` `limits` of each axis.
+///
+/// If the bounds don't fit into the limits, they are set to the limits.
+fn constrain_bounds(
+    mut bounds: PlotBounds,
+    limits: [Option<[f64; 2]>; 2],
+    zoom_ranges: &[Option<RangeInclusive<f64>>; 2],
+) -> PlotBounds {
+    for axis in 0..2 {
+        let (mut min, mut max) = (bounds.min[axis], bounds.max[axis]);
+        if let Some(zoom_range) = &zoom_ranges[axis] {
+            let size = max - min;
+            let clamped = size.clamp(*zoom_range.start(), *zoom_range.end());
+            if clamped != size {
+                let center = 0.5 * (min + max);
+                (min, max) = (center - 0.5 * clamped, center + 0.5 * clamped);
+            }
+        }
+        if let Some([min_limit, max_limit]) = limits[axis].filter(|[min, max]| min < max) {
+            let size = max - min;
+            if size >= max_limit - min_limit {
+                (min, max) = (min_limit, max_limit);
+            } else if min < min_limit {
+                (min, max) = (min_limit, min_limit + size);
+            } else if max > max_limit {
+                (min, max) = (max_limit - size, max_limit);
+            }
+        }
+        (bounds.min[axis], bounds.max[axis]) = (min, max);
+    }
+    bounds
+}
+
 // ----------------------------------------------------------------------------
 
 struct PreparedPlot<'a> {
@@ -1465,8 +2689,9 @@ struct PreparedPlot<'a> {
     show_y: bool,
     label_formatter: LabelFormatter<'a>,
     coordinates_formatter: Option<(Corner, CoordinatesFormatter<'a>)>,
-    // axis_formatters: [AxisFormatter; 2],
+    crosshair: Crosshair,
     transform: PlotTransform,
+    secondary_transform: Option<PlotTransform>,
     show_grid: Vec2b,
     grid_spacing: Rangef,
     grid_spacers: [GridSpacer<'a>; 2],
@@ -1474,12 +2699,27 @@ struct PreparedPlot<'a> {
     draw_cursor_y: bool,
     draw_cursors: Vec<Cursor>,
 
+    /// Our hovered coordinate, if we show a crosshair.
+    crosshair_point: Option<PlotPoint>,
+
+    /// Hovered coordinate of another plot in our [`Plot::link_cursor`] group.
+    linked_crosshair: Option<PlotPoint>,
+    axis_formatters: [Arc<AxisFormatterFn<'a>>; 2],
+
+    /// Passed to the axis formatters for the crosshair labels.
+    crosshair_step_sizes: [f64; 2],
+
     sharp_grid_lines: bool,
     clamp_grid: bool,
+    x_categories: Vec<String>,
+    x_time: Option<TimeAxis>,
+
+    /// Category hovered in another plot of our [`Plot::link_category_hover`] group.
+    linked_category: Option<usize>,
 }
 
 impl<'a> PreparedPlot<'a> {
-    fn ui(self, ui: &mut Ui, response: &Response) -> (Vec<Cursor>, Option<Id>) {
+    fn ui(self, ui: &mut Ui, response: &Response) -> (Vec<Cursor>, Option<Id>, Option<usize>) {
         let mut axes_shapes = Vec::new();
 
         if self.show_grid.x {
@@ -1499,14 +2739,20 @@ impl<'a> PreparedPlot<'a> {
         let mut plot_ui = ui.child_ui(*transform.frame(), Layout::default(), None);
         plot_ui.set_clip_rect(transform.frame().intersect(ui.clip_rect()));
         for item in &self.items {
-            item.shapes(&plot_ui, transform, &mut shapes);
+            item.shapes(&plot_ui, self.item_transform(&**item), &mut shapes);
+        }
+
+        if let Some(index) = self.linked_category {
+            for item in &self.items {
+                item.highlight_category(index, self.item_transform(&**item), &mut shapes);
+            }
         }
 
         let hover_pos = response.hover_pos();
-        let (cursors, hovered_item_id) = if let Some(pointer) = hover_pos {
+        let (cursors, hovered_item_id, hovered_category) = if let Some(pointer) = hover_pos {
             self.hover(ui, pointer, &mut shapes)
         } else {
-            (Vec::new(), None)
+            (Vec::new(), None, None)
         };
 
         // Draw cursors
@@ -1540,9 +2786,29 @@ impl<'a> PreparedPlot<'a> {
         draw_cursor(&self.draw_cursors, false);
         draw_cursor(&cursors, true);
 
+        // Our own pointer, or the one of a linked plot on the linked axes.
+        let crosshair = if let Some(point) = self.crosshair_point {
+            Some((point, Vec2b::new(true, true)))
+        } else if response.hovered() {
+            None
+        } else {
+            self.linked_crosshair.map(|point| {
+                let linked = Vec2b::new(self.draw_cursor_x, self.draw_cursor_y);
+                (point, linked)
+            })
+        };
+        let crosshair = crosshair.filter(|_| self.crosshair != Crosshair::None);
+        if let Some((point, axes)) = crosshair {
+            self.crosshair_lines(ui, point, axes, &mut shapes);
+        }
+
         let painter = ui.painter().with_clip_rect(*transform.frame());
         painter.extend(shapes);
 
+        if let (Crosshair::LinesWithLabels, Some((point, axes))) = (self.crosshair, crosshair) {
+            self.crosshair_labels(ui, point, axes);
+        }
+
         if let Some((corner, formatter)) = self.coordinates_formatter.as_ref() {
             let hover_pos = response.hover_pos();
             if let Some(pointer) = hover_pos {
@@ -1560,7 +2826,83 @@ impl<'a> PreparedPlot<'a> {
             }
         }
 
-        (cursors, hovered_item_id)
+        (cursors, hovered_item_id, hovered_category)
+    }
+
+    /// Dashed lines through `point` across the plot, on the given axes.
+    fn crosshair_lines(&self, ui: &Ui, point: PlotPoint, axes: Vec2b, shapes: &mut Vec<Shape>) {
+        let frame = self.transform.frame();
+        let pos = self.transform.position_from_point(&point);
+        let stroke = Stroke::new(1.0, rulers_color(ui));
+        if axes.x {
+            let line = [pos2(pos.x, frame.top()), pos2(pos.x, frame.bottom())];
+            shapes.extend(Shape::dashed_line(&line, stroke, 4.0, 4.0));
+        }
+        if axes.y {
+            let line = [pos2(frame.left(), pos.y), pos2(frame.right(), pos.y)];
+            shapes.extend(Shape::dashed_line(&line, stroke, 4.0, 4.0));
+        }
+    }
+
+    /// The coordinates of `point`, at the bottom and left edge of the plot.
+    fn crosshair_labels(&self, ui: &Ui, point: PlotPoint, axes: Vec2b) {
+        let frame = *self.transform.frame();
+        let pos = self.transform.position_from_point(&point);
+        let bounds = self.transform.data_bounds();
+        let painter = ui.painter().with_clip_rect(frame);
+        let font_id = TextStyle::Small.resolve(ui.style());
+        let visuals = ui.visuals();
+
+        let label = |value: f64, axis: Axis, anchor: Align2, position: Pos2| {
+            let i = usize::from(axis);
+            let mark = GridMark {
+                value,
+                step_size: self.crosshair_step_sizes[i],
+            };
+            let range = [bounds.range_x(), bounds.range_y()][i].clone();
+            let text = (self.axis_formatters[i])(mark, &range);
+            if text.is_empty() {
+                return;
+            }
+            let galley = painter.layout_no_wrap(text, font_id.clone(), visuals.text_color());
+            let rect = anchor.anchor_size(position, galley.size()).expand(2.0);
+            // Keep the label within the plot.
+            let rect = rect.translate(vec2(
+                (frame.left() - rect.left()).max(0.0) - (rect.right() - frame.right()).max(0.0),
+                (frame.top() - rect.top()).max(0.0) - (rect.bottom() - frame.bottom()).max(0.0),
+            ));
+            painter.rect(
+                rect,
+                2.0,
+                visuals.extreme_bg_color,
+                visuals.widgets.noninteractive.bg_stroke,
+            );
+            painter.galley(rect.shrink(2.0).min, galley, visuals.text_color());
+        };
+        if axes.x {
+            label(
+                point.x,
+                Axis::X,
+                Align2::CENTER_BOTTOM,
+                pos2(pos.x, frame.bottom()),
+            );
+        }
+        if axes.y {
+            label(
+                point.y,
+                Axis::Y,
+                Align2::LEFT_CENTER,
+                pos2(frame.left(), pos.y),
+            );
+        }
+    }
+
+    /// The transform of the Y axis the item is plotted against.
+    fn item_transform(&self, item: &dyn PlotItem) -> &PlotTransform {
+        match (item.y_axis(), &self.secondary_transform) {
+            (YAxis::Secondary, Some(secondary_transform)) => secondary_transform,
+            _ => &self.transform,
+        }
     }
 
     fn paint_grid(&self, ui: &Ui, shapes: &mut Vec<(Shape, f32)>, axis: Axis, fade_range: Rangef) {
@@ -1579,11 +2921,7 @@ impl<'a> PreparedPlot<'a> {
         let bounds = transform.bounds();
         let value_cross = 0.0_f64.clamp(bounds.min[1 - iaxis], bounds.max[1 - iaxis]);
 
-        let input = GridInput {
-            bounds: (bounds.min[iaxis], bounds.max[iaxis]),
-            base_step_size: transform.dvalue_dpos()[iaxis].abs() * fade_range.min as f64,
-        };
-        let steps = (grid_spacers[iaxis])(input);
+        let steps = grid_marks(&grid_spacers[iaxis], transform, axis, fade_range.min);
 
         let clamp_range = clamp_grid.then(|| {
             let mut tight_bounds = PlotBounds::NOTHING;
@@ -1660,18 +2998,25 @@ impl<'a> PreparedPlot<'a> {
         }
     }
 
-    fn hover(&self, ui: &Ui, pointer: Pos2, shapes: &mut Vec<Shape>) -> (Vec<Cursor>, Option<Id>) {
+    fn hover(
+        &self,
+        ui: &Ui,
+        pointer: Pos2,
+        shapes: &mut Vec<Shape>,
+    ) -> (Vec<Cursor>, Option<Id>, Option<usize>) {
         let Self {
             transform,
             show_x,
             show_y,
             label_formatter,
             items,
+            x_categories,
+            x_time,
             ..
         } = self;
 
         if !show_x && !show_y {
-            return (Vec::new(), None);
+            return (Vec::new(), None, None);
         }
 
         let interact_radius_sq = ui.style().interaction.interact_radius.powi(2);
@@ -1681,7 +3026,7 @@ impl<'a> PreparedPlot<'a> {
             .filter(|entry| entry.allow_hover())
             .filter_map(|item| {
                 let item = &**item;
-                let closest = item.find_closest(pointer, transform);
+                let closest = item.find_closest(pointer, self.item_transform(item));
 
                 Some(item).zip(closest)
             });
@@ -1695,12 +3040,32 @@ impl<'a> PreparedPlot<'a> {
             transform,
             show_x: *show_x,
             show_y: *show_y,
+            x_categories,
+            x_time: *x_time,
+            y_axis: YAxis::Primary,
         };
 
         let mut cursors = Vec::new();
 
+        let mut hovered_category = None;
         let hovered_plot_item_id = if let Some((item, elem)) = closest {
+            hovered_category = item.category_index(&elem);
+            let item_transform = self.item_transform(item);
+            let plot = items::PlotConfig {
+                transform: item_transform,
+                y_axis: item.y_axis(),
+                ..plot
+            };
             item.on_hover(elem, shapes, &mut cursors, &plot, label_formatter);
+            if item.y_axis() == YAxis::Secondary {
+                // Cursors are shared in primary axis values.
+                for cursor in &mut cursors {
+                    if let Cursor::Horizontal { y } = cursor {
+                        let pos = item_transform.position_from_point(&PlotPoint::new(0.0, *y));
+                        *y = transform.value_from_position(pos).y;
+                    }
+                }
+            }
             item.id()
         } else {
             let value = transform.value_from_position(pointer);
@@ -1716,7 +3081,7 @@ impl<'a> PreparedPlot<'a> {
             None
         };
 
-        (cursors, hovered_plot_item_id)
+        (cursors, hovered_plot_item_id, hovered_category)
     }
 }
 
@@ -1768,6 +3133,67 @@ fn generate_marks(step_sizes: [f64; 3], bounds: (f64, f64)) -> Vec<GridMark> {
     deduplicated
 }
 
+fn cmp_f64(a: f64, b: f64) -> Ordering {
+    match a.partial_cmp(&b) {
+        Some(ord) => ord,
+        None => a.is_nan().cmp(&b.is_nan()),
+    }
+}
+
+/// Fill in all values between [min, max] which are a multiple of `step_size`
+fn fill_marks_between(out: &mut Vec<GridMark>, step_size: f64, (min, max): (f64, f64)) {
+    debug_assert!(min <= max, "Bad plot bounds: min: {min}, max: {max}");
+    let first = (min / step_size).ceil() as i64;
+    let last = (max / step_size).ceil() as i64;
+
+    let marks_iter = (first..last).map(|i| {
+        let value = (i as f64) * step_size;
+        GridMark { value, step_size }
+    });
+    out.extend(marks_iter);
+}
+
+/// Helper for formatting a number so that we always show at least a few decimals,
+/// unless it is an integer, in which case we never show any decimals.
+pub fn format_number(number: f64, num_decimals: usize) -> String {
+    let is_integral = number as i64 as f64 == number;
+    if is_integral {
+        // perfect integer - show it as such:
+        format!("{number:.0}")
+    } else {
+        // make sure we tell the user it is not an integer by always showing a decimal or two:
+        format!("{:.*}", num_decimals.at_least(1), number)
+    }
+}
+
+/// Determine a color from a 0-1 strength value.
+pub fn color_from_strength(ui: &Ui, strength: f32) -> Color32 {
+    let base_color = ui.visuals().text_color();
+    base_color.gamma_multiply(strength.sqrt())
+}
+
+// ----------------------------------------------------------------------------
+
+/// Run one frame of `ctx` on a screen of `screen_size` points, with the rest of the `input`,
+/// showing `add_contents` in a [`CentralPanel`] without margins.
+#[cfg(test)]
+fn run_frame(
+    ctx: &Context,
+    screen_size: Vec2,
+    input: RawInput,
+    add_contents: impl FnOnce(&mut Ui),
+) {
+    let input = RawInput {
+        screen_rect: Some(Rect::from_min_size(Pos2::ZERO, screen_size)),
+        ..input
+    };
+    let _ = ctx.run(input, |ctx| {
+        CentralPanel::default()
+            .frame(Frame::none())
+            .show(ctx, add_contents);
+    });
+}
+
 #[test]
 fn test_generate_marks() {
     fn approx_eq(a: &GridMark, b: &GridMark) -> bool {
@@ -1817,41 +3243,1985 @@ fn test_generate_marks() {
     }
 }
 
-fn cmp_f64(a: f64, b: f64) -> Ordering {
-    match a.partial_cmp(&b) {
-        Some(ord) => ord,
-        None => a.is_nan().cmp(&b.is_nan()),
-    }
+#[test]
+fn test_category_grid_spacer() {
+    let spacer = category_grid_spacer(5);
+    let values = |bounds, base_step_size| -> Vec<f64> {
+        spacer(GridInput {
+            bounds,
+            base_step_size,
+        })
+        .iter()
+        .map(|mark| mark.value)
+        .collect()
+    };
+
+    assert_eq!(values((-0.7, 4.7), 0.1), vec![0.0, 1.0, 2.0, 3.0, 4.0]);
+    assert_eq!(values((1.2, 2.9), 0.1), vec![2.0]);
+    assert_eq!(values((-10.0, 10.0), 1.5), vec![0.0, 2.0, 4.0]);
+
+    let categories = vec!["a".to_owned(), "b".to_owned()];
+    assert_eq!(category_at(&categories, 0.4), Some("a"));
+    assert_eq!(category_at(&categories, 1.2), Some("b"));
+    assert_eq!(category_at(&categories, -0.6), None);
+    assert_eq!(category_at(&categories, 1.5), None);
 }
 
-/// Fill in all values between [min, max] which are a multiple of `step_size`
-fn fill_marks_between(out: &mut Vec<GridMark>, step_size: f64, (min, max): (f64, f64)) {
-    debug_assert!(min <= max, "Bad plot bounds: min: {min}, max: {max}");
-    let first = (min / step_size).ceil() as i64;
-    let last = (max / step_size).ceil() as i64;
+#[test]
+fn test_category_hover_text() {
+    let ctx = egui::Context::default();
+    let _ = ctx.run(Default::default(), |ctx| {
+        CentralPanel::default().show(ctx, |ui| {
+            let transform = PlotTransform::new(
+                Rect::from_min_size(Pos2::ZERO, Vec2::splat(100.0)),
+                PlotBounds::from_min_max([-1.0, 0.0], [3.0, 1.0]),
+                false,
+                false,
+            );
+            let categories = vec!["a".to_owned(), "b".to_owned()];
+            let plot = items::PlotConfig {
+                ui,
+                transform: &transform,
+                show_x: true,
+                show_y: true,
+                x_categories: &categories,
+                x_time: None,
+                y_axis: YAxis::Primary,
+            };
+            assert_eq!(items::x_value_text(&plot, 1.2), "b");
 
-    let marks_iter = (first..last).map(|i| {
-        let value = (i as f64) * step_size;
-        GridMark { value, step_size }
+            // Outside the categories, fall back to the x value:
+            assert!(items::x_value_text(&plot, 2.5).starts_with("x = 2.5"));
+            assert!(items::x_value_text(&plot, -0.7).starts_with("x = -0.7"));
+        });
     });
-    out.extend(marks_iter);
 }
 
-/// Helper for formatting a number so that we always show at least a few decimals,
-/// unless it is an integer, in which case we never show any decimals.
-pub fn format_number(number: f64, num_decimals: usize) -> String {
-    let is_integral = number as i64 as f64 == number;
-    if is_integral {
-        // perfect integer - show it as such:
-        format!("{number:.0}")
-    } else {
-        // make sure we tell the user it is not an integer by always showing a decimal or two:
-        format!("{:.*}", num_decimals.at_least(1), number)
+#[test]
+fn test_downsample_min_max() {
+    // Many points per pixel column, with the extremes in the middle of each column:
+    let line: Vec<Pos2> = (0..10_000)
+        .map(|i| {
+            let x = i as f32 * 0.01;
+            pos2(x, (x * 7.3).sin() * 100.0 + (i % 13) as f32)
+        })
+        .collect();
+    let downsampled = DownsampleMode::MinMax.downsample(line.clone(), 1.0);
+    assert!(downsampled.len() <= 4 * 100, "{}", downsampled.len());
+    assert_eq!(downsampled.first(), line.first());
+    assert_eq!(downsampled.last(), line.last());
+
+    let extent = |points: &[Pos2], column: f32| {
+        points
+            .iter()
+            .filter(|p| p.x.floor() == column)
+            .fold((f32::INFINITY, f32::NEG_INFINITY), |(min, max), p| {
+                (min.min(p.y), max.max(p.y))
+            })
+    };
+    for column in 0..100 {
+        let column = column as f32;
+        assert_eq!(extent(&downsampled, column), extent(&line, column));
     }
+
+    // Short lines are left alone:
+    let short = vec![pos2(0.0, 0.0), pos2(0.5, 1.0), pos2(3.0, 2.0)];
+    assert_eq!(DownsampleMode::MinMax.downsample(short.clone(), 1.0), short);
+    assert_eq!(
+        DownsampleMode::MinMax.downsample(line.clone(), 10_000.0),
+        line
+    );
 }
 
-/// Determine a color from a 0-1 strength value.
-pub fn color_from_strength(ui: &Ui, strength: f32) -> Color32 {
-    let base_color = ui.visuals().text_color();
-    base_color.gamma_multiply(strength.sqrt())
+#[test]
+fn test_downsample_min_max_non_finite() {
+    let mut line: Vec<Pos2> = (0..1_000)
+        .map(|i| pos2(i as f32 * 0.01, (i % 7) as f32))
+        .collect();
+    line[10] = pos2(f32::NAN, 1.0);
+    line[20].y = f32::NAN;
+    line[500] = pos2(f32::INFINITY, f32::NAN);
+    line.push(pos2(f32::NAN, f32::NAN));
+
+    let downsampled = DownsampleMode::MinMax.downsample(line.clone(), 1.0);
+    assert!(downsampled.len() < line.len(), "{}", downsampled.len());
+    let non_finite = |points: &[Pos2]| points.iter().filter(|p| !p.is_finite()).count();
+    assert_eq!(non_finite(&downsampled), 4);
+    assert!(downsampled.last().unwrap().x.is_nan());
+}
+
+#[test]
+fn test_log10_axis_transform() {
+    let frame = Rect::from_min_size(Pos2::ZERO, vec2(600.0, 300.0));
+    let bounds = PlotBounds::from_min_max([-3.0, 0.0], [3.0, 6.0]); // decades on both axes
+    let scale = AxisScale::Log10 { min: 1e-6 };
+    let transform =
+        PlotTransform::new(frame, bounds, false, false).with_axis_scales([scale, scale]);
+
+    // One decade is 100 points along x and 50 points along y:
+    assert_eq!(
+        transform.position_from_point(&PlotPoint::new(1.0, 1.0)),
+        pos2(300.0, 300.0)
+    );
+    assert_eq!(
+        transform.position_from_point(&PlotPoint::new(10.0, 1e3)),
+        pos2(400.0, 150.0)
+    );
+
+    for value in [1e-3, 0.02, 0.5, 1.0, 7.0, 1234.5, 1e3] {
+        let point = PlotPoint::new(value, value * 10.0);
+        let back = transform.value_from_position(transform.position_from_point(&point));
+        assert!((back.x / point.x - 1.0).abs() < 1e-5, "{value}: {back:?}");
+        assert!((back.y / point.y - 1.0).abs() < 1e-5, "{value}: {back:?}");
+    }
+
+    // Zero and negative values are clamped to the minimum:
+    let clamped = transform.position_from_point(&PlotPoint::new(-5.0, 0.0));
+    assert_eq!(
+        clamped,
+        transform.position_from_point(&PlotPoint::new(1e-6, 1e-6))
+    );
+
+    let data_bounds = transform.data_bounds();
+    assert!((data_bounds.min()[0] - 1e-3).abs() < 1e-12);
+    assert!((data_bounds.max()[1] - 1e6).abs() < 1e-6);
+    assert_eq!(transform.bounds_from_data(&data_bounds), bounds);
+}
+
+#[test]
+fn test_log10_lines() {
+    let frame = Rect::from_min_size(Pos2::ZERO, vec2(600.0, 300.0));
+    let bounds = PlotBounds::from_min_max([0.0, 0.0], [3.0, 3.0]); // 1 to 1000 on both axes
+    let scale = AxisScale::Log10 { min: 1e-6 };
+
+    let ctx = egui::Context::default();
+    let _ = ctx.run(Default::default(), |ctx| {
+        CentralPanel::default().show(ctx, |ui| {
+            for scales in [[scale, AxisScale::Linear], [AxisScale::Linear, scale]] {
+                let transform =
+                    PlotTransform::new(frame, bounds, false, false).with_axis_scales(scales);
+
+                // The lines span the whole frame, whatever the scale along them:
+                let mut shapes = Vec::new();
+                HLine::new(2.0)
+                    .color(Color32::RED)
+                    .shapes(ui, &transform, &mut shapes);
+                let rect = Shape::Vec(shapes).visual_bounding_rect();
+                assert!(
+                    rect.x_range().contains(1.0) && rect.x_range().contains(599.0),
+                    "{rect:?}"
+                );
+
+                let mut shapes = Vec::new();
+                VLine::new(2.0)
+                    .color(Color32::RED)
+                    .shapes(ui, &transform, &mut shapes);
+                let rect = Shape::Vec(shapes).visual_bounding_rect();
+                assert!(
+                    rect.y_range().contains(1.0) && rect.y_range().contains(299.0),
+                    "{rect:?}"
+                );
+            }
+        });
+    });
+}
+
+#[test]
+fn test_log10_grid_spacer() {
+    let spacer = log10_grid_spacer();
+
+    // Six decades, zoomed in far enough for the minor lines:
+    let marks = spacer(GridInput {
+        bounds: (-2.5, 3.5),
+        base_step_size: 0.05,
+    });
+    let decades: Vec<f64> = marks
+        .iter()
+        .filter(|mark| mark.step_size >= 1.0)
+        .map(|mark| mark.value)
+        .collect();
+    assert_eq!(decades, vec![-2.0, -1.0, 0.0, 1.0, 2.0, 3.0]);
+    let minor_count = marks.iter().filter(|mark| mark.step_size < 1.0).count();
+    assert_eq!(minor_count, 2 * 6);
+
+    let labels: Vec<String> = marks
+        .iter()
+        .map(|&mark| {
+            let value = AxisScale::Log10 { min: 1e-9 }.unscaled(mark.value);
+            log10_axis_formatter(GridMark { value, ..mark }, &(0.0..=1.0))
+        })
+        .collect();
+    assert_eq!(
+        labels,
+        [
+            "0.005", "0.01", "0.02", "0.05", "0.1", "0.2", "0.5", "1", "2", "5", "10", "20", "50",
+            "100", "200", "500", "1000", "2000"
+        ]
+    );
+    let label = |value| {
+        log10_axis_formatter(
+            GridMark {
+                value,
+                step_size: 1.0,
+            },
+            &(0.0..=1.0),
+        )
+    };
+    assert_eq!(label(5e3), "5000");
+    assert_eq!(label(1e4), "10^4");
+    assert_eq!(label(2e6), "2×10^6");
+
+    // Zoomed out, only every 10th decade gets a line:
+    let marks = spacer(GridInput {
+        bounds: (-30.0, 45.0),
+        base_step_size: 5.0,
+    });
+    let values: Vec<f64> = marks.iter().map(|mark| mark.value).collect();
+    assert_eq!(
+        values,
+        vec![-30.0, -20.0, -10.0, 0.0, 10.0, 20.0, 30.0, 40.0]
+    );
+}
+
+#[test]
+fn test_secondary_y_axis() {
+    let ctx = egui::Context::default();
+    let mut transforms = None;
+    let _ = ctx.run(Default::default(), |ctx| {
+        CentralPanel::default().show(ctx, |ui| {
+            let primary = Line::new(vec![[0.0, 0.0], [1.0, 1.0]]);
+            let secondary = Line::new(vec![[0.0, 1000.0], [1.0, 5000.0]]).y_axis(YAxis::Secondary);
+            let response =
+                Plot::new("plot")
+                    .secondary_y_axis(AxisHints::new_y())
+                    .show(ui, |plot_ui| {
+                        plot_ui.line(primary);
+                        plot_ui.line(secondary);
+                    });
+            transforms = Some((response.transform, response.secondary_transform));
+        });
+    });
+    let (primary, secondary) = transforms.unwrap();
+
+    // The secondary line doesn't distort the primary bounds:
+    let primary = primary.bounds();
+    assert!(-0.1 < primary.min[1] && primary.min[1] < 0.0);
+    assert!(1.0 < primary.max[1] && primary.max[1] < 1.1);
+
+    // It gets its own bounds instead:
+    let secondary = secondary.unwrap();
+    let secondary = secondary.bounds();
+    assert!(700.0 < secondary.min[1] && secondary.min[1] < 1000.0);
+    assert!(5000.0 < secondary.max[1] && secondary.max[1] < 5300.0);
+    assert_eq!(primary.range_x(), secondary.range_x());
+
+    // Zooming and panning the primary axis moves the secondary one proportionally:
+    let mapping = secondary_y_mapping([0.0, 1.0], [1000.0, 5000.0]);
+    assert_eq!(secondary_y_range(mapping, [0.0, 0.5]), [1000.0, 3000.0]);
+    assert_eq!(secondary_y_range(mapping, [1.0, 2.0]), [5000.0, 9000.0]);
+}
+
+#[test]
+fn test_boxed_zoom() {
+    // Drag the pointer over the plot and return the bounds before and after.
+    fn drag(path: &[Pos2], modifiers: Modifiers, cancel: bool) -> (PlotTransform, PlotTransform) {
+        let ctx = egui::Context::default();
+        let run = |events: Vec<Event>| {
+            let mut transform = None;
+            let input = RawInput {
+                modifiers,
+                events,
+                ..Default::default()
+            };
+            run_frame(&ctx, vec2(400.0, 400.0), input, |ui| {
+                let response = Plot::new("plot")
+                    .show_axes(false)
+                    .include_x(0.0)
+                    .include_x(10.0)
+                    .include_y(0.0)
+                    .include_y(10.0)
+                    .boxed_zoom_pointer_button(PointerButton::Primary)
+                    .boxed_zoom_modifier(Modifiers::SHIFT)
+                    .show(ui, |_| {});
+                transform = Some(response.transform);
+            });
+            transform.unwrap()
+        };
+        let button = |pos, pressed| Event::PointerButton {
+            pos,
+            button: PointerButton::Primary,
+            pressed,
+            modifiers,
+        };
+
+        let before = run(vec![]);
+        run(vec![Event::PointerMoved(path[0]), button(path[0], true)]);
+        for &pos in &path[1..] {
+            run(vec![Event::PointerMoved(pos)]);
+        }
+        if cancel {
+            run(vec![Event::Key {
+                key: Key::Escape,
+                physical_key: None,
+                pressed: true,
+                repeat: false,
+                modifiers,
+            }]);
+        }
+        let after = run(vec![button(path[path.len() - 1], false)]);
+        (before, after)
+    }
+
+    let path = [pos2(100.0, 100.0), pos2(150.0, 150.0), pos2(200.0, 250.0)];
+
+    // Dragging with shift zooms to the box:
+    let (before, after) = drag(&path, Modifiers::SHIFT, false);
+    let corner_a = before.value_from_position(path[0]);
+    let corner_b = before.value_from_position(path[2]);
+    let bounds = after.bounds();
+    assert!((bounds.min[0] - corner_a.x).abs() < 1e-6);
+    assert!((bounds.max[0] - corner_b.x).abs() < 1e-6);
+    assert!((bounds.min[1] - corner_b.y).abs() < 1e-6);
+    assert!((bounds.max[1] - corner_a.y).abs() < 1e-6);
+
+    // Without the modifier the drag pans instead:
+    let (before, after) = drag(&path, Modifiers::NONE, false);
+    assert_eq!(before.bounds().width(), after.bounds().width());
+    assert_ne!(before.bounds().min[0], after.bounds().min[0]);
+
+    // Escape cancels the zoom:
+    let (before, after) = drag(&path, Modifiers::SHIFT, true);
+    assert_eq!(before.bounds(), after.bounds());
+
+    // Tiny boxes are ignored:
+    let path = [pos2(100.0, 100.0), pos2(120.0, 102.0)];
+    let (before, after) = drag(&path, Modifiers::SHIFT, false);
+    assert_eq!(before.bounds(), after.bounds());
+}
+
+#[test]
+fn test_animate_bounds() {
+    let ctx = egui::Context::default();
+    let target = PlotBounds::from_min_max([20.0, 20.0], [30.0, 40.0]);
+    let run = |time: f64, events: Vec<Event>, animate: bool| {
+        let mut bounds = None;
+        let input = RawInput {
+            time: Some(time),
+            events,
+            ..Default::default()
+        };
+        run_frame(&ctx, vec2(400.0, 400.0), input, |ui| {
+            let response = Plot::new("plot")
+                .include_x(0.0)
+                .include_x(10.0)
+                .include_y(0.0)
+                .include_y(10.0)
+                .show(ui, |plot_ui| {
+                    if animate {
+                        plot_ui.animate_bounds_to(target, 1.0, emath::easing::cubic_out);
+                    }
+                });
+            bounds = Some(*response.transform.bounds());
+        });
+        bounds.unwrap()
+    };
+    let assert_close = |a: PlotBounds, b: PlotBounds| {
+        for i in 0..2 {
+            assert!((a.min[i] - b.min[i]).abs() < 1e-6, "{a:?} != {b:?}");
+            assert!((a.max[i] - b.max[i]).abs() < 1e-6, "{a:?} != {b:?}");
+        }
+    };
+
+    let start = run(0.0, vec![], false);
+    assert_close(run(0.0, vec![], true), start);
+    for time in [0.25, 0.5, 0.75] {
+        let expected = emath::Lerp::lerp(start, target, emath::easing::cubic_out(time as f32));
+        assert_close(run(time, vec![], false), expected);
+    }
+    assert_close(run(1.0, vec![], false), target);
+    assert_close(run(2.0, vec![], false), target);
+
+    // Zooming cancels the animation:
+    run(3.0, vec![], true);
+    let zoomed = run(
+        3.5,
+        vec![Event::PointerMoved(pos2(200.0, 200.0)), Event::Zoom(2.0)],
+        false,
+    );
+    assert!(zoomed.width() < start.width() / 2.0);
+    assert_close(run(5.0, vec![], false), zoomed);
+}
+
+#[test]
+fn test_axis_placement() {
+    let complete_rect = Rect::from_min_size(Pos2::ZERO, vec2(400.0, 300.0));
+    let x_axes = [
+        AxisHints::new_x().min_thickness(20.0),
+        AxisHints::new_x()
+            .min_thickness(25.0)
+            .placement(VPlacement::Top),
+    ];
+    let y_axes = [
+        AxisHints::new_y().min_thickness(30.0),
+        AxisHints::new_y()
+            .min_thickness(40.0)
+            .placement(HPlacement::Right),
+    ];
+    let ([x_widgets, y_widgets], plot_rect) =
+        axis_widgets(None, true.into(), complete_rect, [&x_axes, &y_axes]);
+
+    // The plot shrinks on the side of each axis:
+    assert_eq!(
+        plot_rect,
+        Rect::from_min_max(pos2(30.0, 25.0), pos2(360.0, 280.0))
+    );
+
+    // The axes are next to the plot, without overlapping it:
+    for widget in x_widgets.iter().chain(&y_widgets) {
+        assert!(!widget.rect.intersects(plot_rect.shrink(0.5)));
+    }
+    let right_axis = y_widgets
+        .iter()
+        .find(|widget| widget.hints.placement == Placement::RightTop)
+        .unwrap();
+    assert_eq!(right_axis.rect.x_range(), Rangef::new(360.0, 400.0));
+    assert_eq!(right_axis.rect.y_range(), plot_rect.y_range());
+    let top_axis = x_widgets
+        .iter()
+        .find(|widget| widget.hints.placement == Placement::RightTop)
+        .unwrap();
+    assert_eq!(top_axis.rect.y_range(), Rangef::new(0.0, 25.0));
+    assert_eq!(top_axis.rect.x_range(), plot_rect.x_range());
+}
+
+#[test]
+fn test_hidden_items() {
+    let ctx = egui::Context::default();
+    let run = |id: &str, legend: Legend, events: Vec<Event>, hide_c: bool| {
+        let mut response = None;
+        let input = RawInput {
+            events,
+            ..Default::default()
+        };
+        run_frame(&ctx, vec2(400.0, 300.0), input, |ui| {
+            let plot_response = Plot::new(id)
+                .width(400.0)
+                .height(300.0)
+                .show_axes(false)
+                .legend(legend.clone())
+                .show(ui, |plot_ui| {
+                    plot_ui.line(Line::new(vec![[0.0, 0.0], [1.0, 1.0]]).name("a"));
+                    plot_ui.line(Line::new(vec![[0.0, 0.0], [1.0, 100.0]]).name("b"));
+                    plot_ui.line(Line::new(vec![[0.0, 0.0], [1.0, 1.0]]).name("c"));
+                    if hide_c {
+                        plot_ui.set_item_visibility("c", false);
+                    }
+                });
+            response = Some((
+                plot_response.hidden_items,
+                *plot_response.transform.bounds(),
+            ));
+        });
+        response.unwrap()
+    };
+    let sorted = |hidden_items: ahash::HashSet<String>| {
+        let mut hidden_items: Vec<String> = hidden_items.into_iter().collect();
+        hidden_items.sort();
+        hidden_items
+    };
+
+    // Seeding hides the series on the first frame:
+    let seeded = Legend::default().hidden_items(["b".to_owned()]);
+    let (hidden_items, bounds) = run("seeded", seeded, vec![], false);
+    assert_eq!(sorted(hidden_items), ["b"]);
+    assert!(bounds.max[1] < 2.0);
+
+    // Hiding from the plot closure:
+    let (hidden_items, _) = run("plot", Legend::default(), vec![], true);
+    assert_eq!(sorted(hidden_items), ["c"]);
+
+    // Clicking the first legend entry ("a") in the right top corner hides it:
+    let pos = pos2(380.0, 15.0);
+    let button = |pressed| Event::PointerButton {
+        pos,
+        button: PointerButton::Primary,
+        pressed,
+        modifiers: Modifiers::NONE,
+    };
+    run(
+        "plot",
+        Legend::default(),
+        vec![Event::PointerMoved(pos)],
+        false,
+    );
+    run("plot", Legend::default(), vec![button(true)], false);
+    let (hidden_items, _) = run("plot", Legend::default(), vec![button(false)], false);
+    assert_eq!(sorted(hidden_items), ["a", "c"]);
+
+    // The visibility persists in the plot memory:
+    let (hidden_items, _) = run("plot", Legend::default(), vec![], false);
+    assert_eq!(sorted(hidden_items), ["a", "c"]);
+}
+
+#[test]
+fn test_legend_max_height() {
+    let ctx = egui::Context::default();
+    let items: Vec<Box<dyn PlotItem>> = (0..100)
+        .map(|i| {
+            let line = Line::new(vec![[0.0, i as f64], [1.0, i as f64]]).name(format!("{i}"));
+            Box::new(line) as Box<dyn PlotItem>
+        })
+        .collect();
+    let legend_rect = |legend: Legend, scroll_offset: f32| {
+        let mut legend_rect = None;
+        run_frame(&ctx, vec2(400.0, 400.0), Default::default(), |ui| {
+            let plot_rect = Rect::from_min_size(Pos2::ZERO, vec2(400.0, 400.0));
+            let mut legend = LegendWidget::try_new(
+                plot_rect,
+                legend,
+                &items,
+                &Default::default(),
+                Id::new("legend"),
+                scroll_offset,
+            )
+            .unwrap();
+            legend_rect = Some(ui.add(&mut legend).rect);
+        });
+        legend_rect.unwrap()
+    };
+
+    let rect = legend_rect(Legend::default().max_height(200.0), 0.0);
+    assert!(rect.height() <= 200.0, "{rect:?}");
+    assert!(rect.height() > 150.0, "{rect:?}");
+
+    // By default the legend takes up at most three quarters of the plot:
+    let rect = legend_rect(Legend::default(), 0.0);
+    assert!(rect.height() <= 300.0, "{rect:?}");
+
+    // The corner placement is kept when scrolled:
+    let rect = legend_rect(
+        Legend::default()
+            .max_height(200.0)
+            .position(Corner::RightBottom),
+        100.0,
+    );
+    assert!(rect.height() <= 200.0, "{rect:?}");
+    assert_eq!(rect.right_bottom(), pos2(396.0, 396.0));
+}
+
+#[test]
+fn test_export_svg() {
+    let ctx = egui::Context::default();
+    let mut svg = String::new();
+    for _ in 0..2 {
+        run_frame(&ctx, vec2(400.0, 300.0), Default::default(), |ui| {
+            let response = Plot::new("plot")
+                .width(300.0)
+                .height(200.0)
+                .include_y(10.0)
+                .show(ui, |plot_ui| {
+                    plot_ui.line(Line::new(vec![[0.0, 0.0], [5.0, 8.0], [10.0, 4.0]]));
+                    plot_ui.polygon(Polygon::new(vec![[1.0, 1.0], [3.0, 1.0], [2.0, 3.0]]));
+                });
+            svg = response.export_svg();
+        });
+    }
+
+    assert!(svg.starts_with("<svg "));
+    assert!(svg.trim_end().ends_with("</svg>"));
+    assert!(svg.contains(r#"width="300" height="200""#));
+    // The line, and the fill and outline of the polygon:
+    assert_eq!(svg.matches("<polyline ").count(), 2, "{svg}");
+    assert_eq!(svg.matches("<polygon ").count(), 1, "{svg}");
+
+    // Axis labels are text, the grid lines are lines:
+    let num_texts = svg.matches("<text ").count();
+    assert!(num_texts >= 4, "{svg}");
+    assert_eq!(
+        svg.matches(r#"font-family="sans-serif""#).count(),
+        num_texts
+    );
+    assert!(svg.matches("<line ").count() >= 4, "{svg}");
+
+    // Every shape is clipped:
+    assert!(svg.contains("<clipPath "));
+}
+
+#[test]
+fn test_visible_data_csv() {
+    let rect = Rect::from_min_size(Pos2::ZERO, vec2(100.0, 100.0));
+    let transform = PlotTransform::new(
+        rect,
+        PlotBounds::from_min_max([0.0, 0.0], [10.0, 10.0]),
+        false,
+        false,
+    );
+    let items: Vec<Box<dyn PlotItem>> = vec![
+        Box::new(
+            Line::new(vec![[-1.0, 1.0], [0.5, 2.25], [3.0, 11.0], [10.0, 1e-3]]).name("a, \"b\""),
+        ),
+        Box::new(BarChart::new(vec![Bar::new(2.0, 3.5), Bar::new(20.0, 1.0)]).name("bars")),
+        Box::new(BarChart::new(vec![Bar::new(4.0, 1.5).horizontal()]).name("horizontal")),
+        Box::new(
+            BoxPlot::new(vec![BoxElem::new(
+                5.0,
+                BoxSpread::new(1.0, 2.0, 3.0, 4.0, 12.0),
+            )])
+            .name("boxes"),
+        ),
+    ];
+
+    assert_eq!(
+        visible_data_csv(&items, &transform, None),
+        "series,x,y\n\
+         \"a, \"\"b\"\"\",0.5,2.25\n\
+         \"a, \"\"b\"\"\",10,0.001\n\
+         bars,2,3.5\n\
+         horizontal,1.5,4\n\
+         boxes,5,1\n\
+         boxes,5,2\n\
+         boxes,5,3\n\
+         boxes,5,4\n"
+    );
+}
+
+#[test]
+fn test_hover_formatter() {
+    let ctx = egui::Context::default();
+    let run = |events: Vec<Event>| {
+        let mut result = None;
+        let input = RawInput {
+            events,
+            ..Default::default()
+        };
+        run_frame(&ctx, vec2(400.0, 400.0), input, |ui| {
+            let unit = String::from("ms");
+            let response = Plot::new("plot")
+                .show_axes(false)
+                .label_formatter(|_, value| format!("plot {}", value.y))
+                .show(ui, |plot_ui| {
+                    plot_ui.line(
+                        Line::new(vec![[0.0, 2.0], [5.0, 2.0], [10.0, 2.0]])
+                            .hover_formatter(move |value| format!("{:.1} {unit}", value.y)),
+                    );
+                    plot_ui.points(
+                        Points::new(vec![[0.0, 8.0], [5.0, 8.0], [10.0, 8.0]])
+                            .hover_formatter(|value| format!("{:.0} %", 10.0 * value.y)),
+                    );
+                    plot_ui.line(Line::new(vec![[0.0, 5.0], [5.0, 5.0], [10.0, 5.0]]));
+                });
+            result = Some((response.transform, response.export_svg()));
+        });
+        result.unwrap()
+    };
+    let (transform, _) = run(vec![]);
+    let hover = |x: f64, y: f64| {
+        let pos = transform.position_from_point(&PlotPoint::new(x, y));
+        run(vec![Event::PointerMoved(pos)]).1
+    };
+
+    let svg = hover(5.0, 2.0);
+    assert!(svg.contains(">2.0 ms<"), "{svg}");
+    assert!(!svg.contains('%'), "{svg}");
+
+    let svg = hover(5.0, 8.0);
+    assert!(svg.contains(">80 %<"), "{svg}");
+    assert!(!svg.contains("ms<"), "{svg}");
+
+    // Items without a formatter use the one of the plot:
+    let svg = hover(5.0, 5.0);
+    assert!(svg.contains(">plot 5<"), "{svg}");
+}
+
+#[test]
+fn test_polygon_with_hole() {
+    let polygon =
+        Polygon::new(vec![[0.0, 0.0], [10.0, 0.0], [10.0, 10.0], [0.0, 10.0]]).holes(vec![vec![
+            [4.0, 4.0],
+            [6.0, 4.0],
+            [6.0, 6.0],
+            [4.0, 6.0],
+        ]
+        .into()]);
+    let transform = PlotTransform::new(
+        Rect::from_min_size(Pos2::ZERO, vec2(100.0, 100.0)),
+        PlotBounds::from_min_max([0.0, 0.0], [10.0, 10.0]),
+        false,
+        false,
+    );
+    let hole = Rect::from_min_max(pos2(40.0, 40.0), pos2(60.0, 60.0));
+
+    let ctx = egui::Context::default();
+    let mut shapes = Vec::new();
+    let _ = ctx.run(Default::default(), |ctx| {
+        CentralPanel::default().show(ctx, |ui| {
+            polygon.shapes(ui, &transform, &mut shapes);
+        });
+    });
+    let Some(Shape::Mesh(mesh)) = shapes.first() else {
+        panic!("Expected a fill mesh, got {shapes:?}");
+    };
+
+    // The fill is in screen space, and avoids the hole:
+    for triangle in mesh.indices.chunks_exact(3) {
+        let [a, b, c] = [0, 1, 2].map(|i| mesh.vertices[triangle[i] as usize].pos);
+        let centroid = pos2((a.x + b.x + c.x) / 3.0, (a.y + b.y + c.y) / 3.0);
+        assert!(
+            !hole.contains(centroid),
+            "Triangle {a:?} {b:?} {c:?} is in the hole"
+        );
+    }
+
+    // Both rings are outlined:
+    assert_eq!(shapes.len(), 3);
+
+    // Hovering misses inside the hole:
+    assert!(polygon.find_closest(pos2(50.0, 50.0), &transform).is_none());
+    assert!(polygon.find_closest(pos2(20.0, 50.0), &transform).is_some());
+
+    // The holes don't affect the bounds:
+    assert_eq!(
+        polygon.bounds(),
+        PlotBounds::from_min_max([0.0, 0.0], [10.0, 10.0])
+    );
+}
+
+#[test]
+fn test_auto_bounds_margin() {
+    let ctx = egui::Context::default();
+    let run = |plot: Plot<'_>, points: Vec<[f64; 2]>, set_x: bool| {
+        let mut transform = None;
+        run_frame(&ctx, vec2(400.0, 400.0), Default::default(), |ui| {
+            let response = plot.show(ui, |plot_ui| {
+                if set_x {
+                    plot_ui.set_plot_bounds_x(100.0..=200.0);
+                }
+                plot_ui.line(Line::new(points));
+            });
+            transform = Some(response.transform);
+        });
+        transform.unwrap()
+    };
+    let assert_close = |a: f64, b: f64| assert!((a - b).abs() < 1e-6, "{a} != {b}");
+
+    // A 5% margin on both sides:
+    let transform = run(
+        Plot::new("margin").set_margin_fraction(Vec2::splat(0.05)),
+        vec![[0.0, 0.0], [10.0, 20.0]],
+        false,
+    );
+    let bounds = transform.bounds();
+    assert_close(bounds.min[0], -0.5);
+    assert_close(bounds.max[0], 10.5);
+    assert_close(bounds.min[1], -1.0);
+    assert_close(bounds.max[1], 21.0);
+
+    // A minimum margin in ui points:
+    let transform = run(
+        Plot::new("min_margin")
+            .set_margin_fraction(Vec2::ZERO)
+            .set_min_margin(vec2(10.0, 20.0)),
+        vec![[0.0, 0.0], [10.0, 20.0]],
+        false,
+    );
+    let frame = transform.frame();
+    let min = transform.position_from_point(&PlotPoint::new(0.0, 0.0));
+    let max = transform.position_from_point(&PlotPoint::new(10.0, 20.0));
+    assert!(
+        (min.x - frame.left() - 10.0).abs() < 1e-3,
+        "{min:?} in {frame:?}"
+    );
+    assert!(
+        (frame.right() - max.x - 10.0).abs() < 1e-3,
+        "{max:?} in {frame:?}"
+    );
+    assert!(
+        (frame.bottom() - min.y - 20.0).abs() < 1e-3,
+        "{min:?} in {frame:?}"
+    );
+    assert!(
+        (max.y - frame.top() - 20.0).abs() < 1e-3,
+        "{max:?} in {frame:?}"
+    );
+
+    // Only Y is auto-fitted, X keeps the manually set range:
+    let plot = || Plot::new("streaming").auto_bounds(Vec2b::new(false, true));
+    run(plot(), vec![[0.0, 0.0], [10.0, 20.0]], true);
+    let transform = run(plot(), vec![[0.0, 0.0], [10.0, 40.0]], false);
+    let bounds = transform.bounds();
+    assert_close(bounds.min[0], 100.0);
+    assert_close(bounds.max[0], 200.0);
+    assert_close(bounds.min[1], -2.0);
+    assert_close(bounds.max[1], 42.0);
+}
+
+#[test]
+fn test_crosshair() {
+    let ctx = egui::Context::default();
+    let run = |events: Vec<Event>| {
+        let mut responses = Vec::new();
+        let input = RawInput {
+            events,
+            ..Default::default()
+        };
+        run_frame(&ctx, vec2(400.0, 400.0), input, |ui| {
+            for (name, crosshair) in [("a", Crosshair::LinesWithLabels), ("b", Crosshair::Lines)] {
+                let response = Plot::new(name)
+                    .height(150.0)
+                    .include_x(10.0)
+                    .include_y(10.0)
+                    .crosshair(crosshair)
+                    .link_cursor("group", true, false)
+                    .x_axis_formatter(|mark, _| format!("X={:.1}", mark.value))
+                    .show(ui, |_| {});
+                responses.push((response.transform, response.export_svg()));
+            }
+        });
+        responses
+    };
+    // The short dashes of the crosshair, as vertical and horizontal positions:
+    let dashes = |svg: &str| {
+        let mut dashes = (Vec::new(), Vec::new());
+        for line in svg.lines().filter(|line| line.starts_with("<line ")) {
+            let attribute = |name: &str| -> f32 {
+                let start = line.find(&format!(r#" {name}=""#)).unwrap() + name.len() + 3;
+                let len = line[start..].find('"').unwrap();
+                line[start..start + len].parse().unwrap()
+            };
+            let [x1, y1, x2, y2] = ["x1", "y1", "x2", "y2"].map(attribute);
+            if x1 == x2 && (y2 - y1).abs() <= 4.0 {
+                dashes.0.push(x1);
+            } else if y1 == y2 && (x2 - x1).abs() <= 4.0 {
+                dashes.1.push(y1);
+            }
+        }
+        dashes
+    };
+
+    // Nothing is hovered:
+    run(vec![]);
+    let responses = run(vec![]);
+    for (_, svg) in &responses {
+        assert_eq!(dashes(svg), (vec![], vec![]));
+    }
+    // The tick labels use the same formatter as the crosshair label:
+    let num_labels = |svg: &str| svg.matches(">X=").count();
+    let num_tick_labels = num_labels(&responses[0].1);
+
+    // Hovering the first plot:
+    let pointer = responses[0].0.frame().center() + vec2(13.0, 7.0);
+    let coordinate = responses[0].0.value_from_position(pointer);
+    let responses = run(vec![Event::PointerMoved(pointer)]);
+    let (vertical, horizontal) = dashes(&responses[0].1);
+    assert!(vertical.len() > 5 && vertical.iter().all(|x| *x == pointer.x));
+    assert!(horizontal.len() > 5 && horizontal.iter().all(|y| *y == pointer.y));
+    assert_eq!(num_labels(&responses[0].1), num_tick_labels + 1);
+    let label = format!(">X={:.1}<", coordinate.x);
+    assert!(responses[0].1.contains(&label), "{}", responses[0].1);
+
+    // The linked plot shows it at the same X coordinate, but not Y, which isn't linked:
+    let linked_x = responses[1].0.position_from_point(&coordinate).x;
+    let (vertical, horizontal) = dashes(&responses[1].1);
+    assert!(vertical.len() > 5 && vertical.iter().all(|x| *x == linked_x));
+    assert!(horizontal.is_empty());
+    assert_eq!(num_labels(&responses[1].1), num_tick_labels);
+}
+
+#[test]
+fn test_line_labels() {
+    let frame = Rect::from_min_size(pos2(10.0, 10.0), vec2(200.0, 100.0));
+    let transform = PlotTransform::new(
+        frame,
+        PlotBounds::from_min_max([0.0, 0.0], [10.0, 10.0]),
+        false,
+        false,
+    );
+
+    let ctx = egui::Context::default();
+    let chip = |item: &dyn PlotItem| {
+        let mut shapes = Vec::new();
+        let _ = ctx.run(Default::default(), |ctx| {
+            CentralPanel::default().show(ctx, |ui| {
+                item.shapes(ui, &transform, &mut shapes);
+            });
+        });
+        shapes
+            .iter()
+            .find_map(|shape| match shape {
+                Shape::Rect(rect) => Some(rect.rect),
+                _ => None,
+            })
+            .expect("no label chip")
+    };
+
+    // Lines at the very top and bottom of the bounds:
+    for position in [
+        LabelPosition::LeftAbove,
+        LabelPosition::LeftBelow,
+        LabelPosition::RightAbove,
+        LabelPosition::RightBelow,
+    ] {
+        for y in [0.0, 10.0] {
+            let line = HLine::new(y).label("SLA 99 %").label_position(position);
+            let rect = chip(&line);
+            assert!(frame.contains_rect(rect), "{position:?} at {y}: {rect:?}");
+        }
+        for x in [0.0, 10.0] {
+            let line = VLine::new(x).label("Deploy").label_position(position);
+            let rect = chip(&line);
+            assert!(frame.contains_rect(rect), "{position:?} at {x}: {rect:?}");
+        }
+    }
+
+    // Away from the edges, the label stays on its side of the line:
+    let line = HLine::new(5.0)
+        .label("above")
+        .label_position(LabelPosition::LeftAbove);
+    assert!(chip(&line).bottom() <= frame.center().y);
+    let line = HLine::new(5.0)
+        .label("below")
+        .label_position(LabelPosition::RightBelow);
+    assert!(chip(&line).top() >= frame.center().y);
+    let line = VLine::new(5.0)
+        .label("left")
+        .label_position(LabelPosition::LeftAbove);
+    assert!(chip(&line).right() <= frame.center().x);
+    let line = VLine::new(5.0)
+        .label("right")
+        .label_position(LabelPosition::RightBelow);
+    assert!(chip(&line).left() >= frame.center().x);
+}
+
+#[test]
+fn test_selection() {
+    // Drag the pointer over the plot and return the transform and the selection afterwards.
+    fn drag(
+        path: &[Pos2],
+        mode: SelectionMode,
+        modifiers: Modifiers,
+    ) -> (PlotTransform, PlotTransform, Option<PlotBounds>) {
+        let ctx = egui::Context::default();
+        let run = |events: Vec<Event>| {
+            let mut result = None;
+            let input = RawInput {
+                modifiers,
+                events,
+                ..Default::default()
+            };
+            run_frame(&ctx, vec2(400.0, 400.0), input, |ui| {
+                let response = Plot::new("plot")
+                    .show_axes(false)
+                    .include_x(0.0)
+                    .include_x(10.0)
+                    .include_y(0.0)
+                    .include_y(10.0)
+                    .boxed_zoom_pointer_button(PointerButton::Primary)
+                    .boxed_zoom_modifier(Modifiers::SHIFT)
+                    .selection(mode)
+                    .show(ui, |_| {});
+                result = Some((response.transform, response.selection));
+            });
+            result.unwrap()
+        };
+        let button = |pos, pressed| Event::PointerButton {
+            pos,
+            button: PointerButton::Primary,
+            pressed,
+            modifiers,
+        };
+
+        let (before, _) = run(vec![]);
+        run(vec![Event::PointerMoved(path[0]), button(path[0], true)]);
+        for &pos in &path[1..] {
+            run(vec![Event::PointerMoved(pos)]);
+        }
+        run(vec![button(path[path.len() - 1], false)]);
+        // The selection persists:
+        let (after, selection) = run(vec![]);
+        (before, after, selection)
+    }
+
+    let path = [pos2(100.0, 100.0), pos2(150.0, 150.0), pos2(200.0, 250.0)];
+
+    // Dragging with alt selects, without panning or zooming:
+    let (before, after, selection) = drag(&path, SelectionMode::Rectangle, Modifiers::ALT);
+    assert_eq!(before.bounds(), after.bounds());
+    let corner_a = before.value_from_position(path[0]);
+    let corner_b = before.value_from_position(path[2]);
+    let selection = selection.unwrap();
+    assert!((selection.min[0] - corner_a.x).abs() < 1e-6);
+    assert!((selection.max[0] - corner_b.x).abs() < 1e-6);
+    assert!((selection.min[1] - corner_b.y).abs() < 1e-6);
+    assert!((selection.max[1] - corner_a.y).abs() < 1e-6);
+
+    // A horizontal selection spans all Y values:
+    let (_, _, selection) = drag(&path, SelectionMode::Horizontal, Modifiers::ALT);
+    let selection = selection.unwrap();
+    assert!((selection.min[0] - corner_a.x).abs() < 1e-6);
+    assert!((selection.max[0] - corner_b.x).abs() < 1e-6);
+    assert_eq!(selection.range_y(), f64::NEG_INFINITY..=f64::INFINITY);
+
+    // Other modifiers pan or zoom instead:
+    let (before, after, selection) = drag(&path, SelectionMode::Rectangle, Modifiers::NONE);
+    assert!(selection.is_none());
+    assert_eq!(before.bounds().width(), after.bounds().width());
+    assert_ne!(before.bounds().min[0], after.bounds().min[0]);
+    let (before, after, selection) = drag(&path, SelectionMode::Rectangle, Modifiers::SHIFT);
+    assert!(selection.is_none());
+    assert!(after.bounds().width() < before.bounds().width());
+
+    // Filtering the points by the selection:
+    let points = PlotPoints::from(vec![
+        [1.0, 1.0],
+        [2.0, 5.0],
+        [3.0, 9.0],
+        [4.0, 5.0],
+        [5.0, 5.0],
+    ]);
+    let bounds = PlotBounds::from_min_max([2.0, 4.0], [4.0, 6.0]);
+    assert_eq!(PlotUi::points_in_bounds(&points, bounds), vec![1, 3]);
+    let bounds = PlotBounds::from_min_max([2.5, f64::NEG_INFINITY], [10.0, f64::INFINITY]);
+    assert_eq!(PlotUi::points_in_bounds(&points, bounds), vec![2, 3, 4]);
+}
+
+#[test]
+fn test_streaming_line() {
+    let mut line = StreamingLine::new(100);
+    for i in 0..50 {
+        line.push(i as f64, 0.0);
+    }
+    assert_eq!(line.len(), 50);
+
+    let buffer = line.points().as_ptr();
+    for i in 50..1000 {
+        line.push(i as f64, (i as f64).sin());
+        assert!(line.len() <= line.max_len());
+        // Steady state: the buffer is reused, never reallocated.
+        assert_eq!(line.points[..].as_ptr(), buffer);
+    }
+    assert_eq!(line.len(), 100);
+    assert!(line.points().windows(2).all(|w| w[0].x <= w[1].x));
+    assert_eq!(line.points()[0].x, 900.0);
+    assert_eq!(line.points()[99].x, 999.0);
+
+    let bounds = PlotItem::bounds(&line);
+    assert_eq!(bounds.min()[0], 900.0);
+    assert_eq!(bounds.max()[0], 999.0);
+
+    // Sharing the line with a plot doesn't copy the points.
+    let shared = line.clone();
+    assert_eq!(shared.points().as_ptr(), line.points().as_ptr());
+    drop(shared);
+    line.push(1000.0, 0.0);
+    assert_eq!(line.points[..].as_ptr(), buffer);
+}
+
+#[test]
+fn test_response_transform() {
+    let ctx = egui::Context::default();
+    run_frame(&ctx, vec2(400.0, 300.0), Default::default(), |ui| {
+        let point = PlotPoint::new(1.25, -3.5);
+        let response = Plot::new("plot")
+            .include_x(-2.0)
+            .include_y(7.0)
+            .show(ui, |plot_ui| {
+                plot_ui.points(Points::new(vec![[point.x, point.y]]).radius(3.0));
+            });
+
+        // Where the plot drew the point:
+        let svg = response.export_svg();
+        let circle = svg
+            .lines()
+            .find(|line| line.starts_with("<circle ") && line.contains(r#" r="3""#))
+            .unwrap();
+        let attribute = |name: &str| -> f32 {
+            let start = circle.find(&format!(r#" {name}=""#)).unwrap() + name.len() + 3;
+            let len = circle[start..].find('"').unwrap();
+            circle[start..start + len].parse().unwrap()
+        };
+        let drawn = pos2(attribute("cx"), attribute("cy"));
+
+        let pos = response.transform.position_from_point(&point);
+        assert!(pos.distance(drawn) < 0.5, "{pos:?} != {drawn:?}");
+        let back = response.transform.point_from_position(pos);
+        assert!((back.x - point.x).abs() < 1e-3 && (back.y - point.y).abs() < 1e-3);
+
+        let painter = response.painter();
+        assert_eq!(painter.clip_rect(), *response.transform.frame());
+        assert!(painter.clip_rect().contains(pos));
+    });
+}
+
+#[test]
+fn test_pinch_zoom_axes() {
+    // Two fingers on a diagonal around the center of the plot, `spread` apart.
+    let touches = |phase: TouchPhase, spread: f32, offset: Vec2| {
+        [(-1.0, 0), (1.0, 1)].map(|(sign, id)| Event::Touch {
+            device_id: TouchDeviceId(0),
+            id: TouchId(id),
+            phase,
+            pos: pos2(200.0, 150.0) + offset + Vec2::splat(sign * spread),
+            force: None,
+        })
+    };
+    let gesture = |plot: &dyn Fn() -> Plot<'static>, frames: Vec<Vec<Event>>| {
+        let ctx = egui::Context::default();
+        let mut bounds = Vec::new();
+        let first = vec![Event::PointerMoved(pos2(200.0, 150.0))];
+        for events in std::iter::once(first).chain(frames) {
+            let input = RawInput {
+                events,
+                ..Default::default()
+            };
+            run_frame(&ctx, vec2(400.0, 300.0), input, |ui| {
+                let response = plot().show(ui, |plot_ui| {
+                    plot_ui.points(Points::new(vec![[-1.0, -1.0], [1.0, 1.0]]));
+                });
+                bounds.push(*response.transform.bounds());
+            });
+        }
+        (bounds[1], *bounds.last().unwrap())
+    };
+    let pinch = vec![
+        touches(TouchPhase::Start, 20.0, Vec2::ZERO).to_vec(),
+        touches(TouchPhase::Move, 40.0, Vec2::ZERO).to_vec(),
+    ];
+    let pan = vec![
+        touches(TouchPhase::Start, 20.0, Vec2::ZERO).to_vec(),
+        touches(TouchPhase::Move, 20.0, vec2(30.0, 30.0)).to_vec(),
+    ];
+
+    // By default pinching zooms both axes.
+    let (before, after) = gesture(&|| Plot::new("plot"), pinch.clone());
+    assert!(after.width() < 0.9 * before.width());
+    assert!(after.height() < 0.9 * before.height());
+
+    // Only X:
+    let (before, after) = gesture(&|| Plot::new("plot").pinch_zoom_axes([true, false]), pinch);
+    assert!(after.width() < 0.9 * before.width());
+    assert_eq!(after.range_y(), before.range_y());
+
+    // Two-finger panning follows `allow_drag`, not the pinch axes:
+    let (before, after) = gesture(
+        &|| {
+            Plot::new("plot")
+                .pinch_zoom_axes([true, false])
+                .allow_drag([true, false])
+        },
+        pan,
+    );
+    assert!(after.min()[0] < before.min()[0]);
+    assert_eq!(after.width(), before.width());
+    assert_eq!(after.range_y(), before.range_y());
+}
+
+#[test]
+fn test_x_label_rotation() {
+    // The height of the X axis band, and the tick labels as (position, size, angle):
+    let run = |rotation: f32| {
+        let ctx = egui::Context::default();
+        let mut result = (0.0, Vec::new());
+        for _ in 0..3 {
+            run_frame(&ctx, vec2(600.0, 400.0), Default::default(), |ui| {
+                let x_axis = AxisHints::new_x()
+                    .label_rotation(rotation)
+                    .label_spacing(10.0..=20.0)
+                    .formatter(|mark, _| format!("{} o'clock sharp", mark.value));
+                let response = Plot::new("plot")
+                    .custom_x_axes(vec![x_axis])
+                    .include_x(0.0)
+                    .include_x(24.0)
+                    .show(ui, |_| {});
+                let band = response.complete_rect.bottom() - response.transform.frame().bottom();
+                let (layer_id, range) = response.painted_shapes.clone();
+                let labels = ctx.graphics(|graphics| {
+                    graphics.get(layer_id).map_or_else(Vec::new, |paint_list| {
+                        paint_list
+                            .all_entries()
+                            .skip(range.start)
+                            .take(range.len())
+                            .filter_map(|clipped| match &clipped.shape {
+                                Shape::Text(text) if text.galley.text().contains("o'clock") => {
+                                    Some((text.pos, text.galley.size(), text.angle))
+                                }
+                                _ => None,
+                            })
+                            .collect()
+                    })
+                });
+                result = (band, labels);
+            });
+        }
+        result
+    };
+
+    let (flat_band, flat_labels) = run(0.0);
+    let (band, labels) = run(45.0);
+    assert!(band > flat_band + 20.0, "{band} <= {flat_band}");
+
+    // More labels fit when slanted, and none of them overlap:
+    assert!(labels.len() > flat_labels.len());
+    let angle = -45_f32.to_radians();
+    let (along, across) = (
+        emath::Rot2::from_angle(angle) * Vec2::X,
+        emath::Rot2::from_angle(angle) * Vec2::Y,
+    );
+    for &(pos, size, label_angle) in &labels {
+        assert_eq!(label_angle, angle);
+        // The end of the label is at the tick, within the axis band:
+        let end = pos + along * size.x + across * (size.y / 2.0);
+        assert!((end.y - (400.0 - band) - size.y / 2.0 * angle.cos()).abs() < 0.5);
+    }
+    for pair in labels.windows(2) {
+        let [(a, a_size, _), (b, b_size, _)] = [pair[0], pair[1]];
+        let intervals_overlap = |axis: Vec2, a_len: f32, b_len: f32| {
+            let (a, b) = (a.to_vec2().dot(axis), b.to_vec2().dot(axis));
+            a < b + b_len && b < a + a_len
+        };
+        assert!(
+            !(intervals_overlap(along, a_size.x, b_size.x)
+                && intervals_overlap(across, a_size.y, b_size.y)),
+            "labels overlap"
+        );
+    }
+}
+
+#[test]
+fn test_legend_outside() {
+    // The plot area, the rect of the plot including its axes, the rects of the legend entries,
+    // and the hidden items:
+    let run = |legend: Legend, events: Vec<Event>| {
+        let ctx = egui::Context::default();
+        let mut result = (Rect::NOTHING, Rect::NOTHING, Vec::new(), Default::default());
+        for i in 0..3 {
+            let input = RawInput {
+                events: if i == 2 { events.clone() } else { Vec::new() },
+                ..Default::default()
+            };
+            run_frame(&ctx, vec2(600.0, 400.0), input, |ui| {
+                let response = Plot::new("plot")
+                    .legend(legend.clone())
+                    .show(ui, |plot_ui| {
+                        for name in ["alpha", "beta", "gamma"] {
+                            plot_ui.line(Line::new(vec![[0.0, 0.0], [1.0, 1.0]]).name(name));
+                        }
+                    });
+                let (layer_id, range) = response.painted_shapes.clone();
+                let entries = ctx.graphics(|graphics| {
+                    graphics.get(layer_id).map_or_else(Vec::new, |paint_list| {
+                        paint_list
+                            .all_entries()
+                            .skip(range.start)
+                            .take(range.len())
+                            .filter_map(|clipped| match &clipped.shape {
+                                Shape::Text(text) => ["alpha", "beta", "gamma"]
+                                    .contains(&text.galley.text())
+                                    .then(|| Rect::from_min_size(text.pos, text.galley.size())),
+                                _ => None,
+                            })
+                            .collect()
+                    })
+                });
+                result = (
+                    response.response.rect,
+                    response.complete_rect,
+                    entries,
+                    response.hidden_items,
+                );
+            });
+        }
+        result
+    };
+
+    let (full_plot, ..) = run(Legend::default(), Vec::new());
+
+    // To the right, the plot and legend split the space side by side:
+    let (plot, complete, entries, _) = run(
+        Legend::default().position_outside(OuterPosition::Right),
+        Vec::new(),
+    );
+    assert_eq!(entries.len(), 3);
+    assert!(plot.width() < full_plot.width());
+    assert_eq!(plot.height(), full_plot.height());
+    let legend = Rect::from_min_max(pos2(plot.right(), complete.top()), complete.max);
+    assert!(legend.width() > 0.0);
+    for entry in &entries {
+        assert!(legend.contains_rect(*entry));
+        assert!(!entry.intersects(plot));
+    }
+    // The entries are below each other:
+    assert!(entries
+        .iter()
+        .all(|entry| entry.left() == entries[0].left()));
+
+    // Below, the entries wrap into columns next to each other:
+    let (plot, complete, entries, _) = run(
+        Legend::default().position_outside(OuterPosition::Bottom),
+        Vec::new(),
+    );
+    assert_eq!(entries.len(), 3);
+    assert_eq!(plot.width(), full_plot.width());
+    assert!(plot.height() < full_plot.height());
+    let legend_top = entries
+        .iter()
+        .map(|entry| entry.top())
+        .fold(f32::MAX, f32::min);
+    assert!(legend_top > plot.bottom());
+    for entry in &entries {
+        assert!(complete.contains_rect(*entry));
+    }
+    assert!(entries.iter().all(|entry| entry.top() == entries[0].top()));
+
+    // Clicking an entry still hides its line:
+    let right = Legend::default().position_outside(OuterPosition::Right);
+    let (_, _, entries, hidden) = run(right.clone(), Vec::new());
+    assert!(hidden.is_empty());
+    let click = entries[0].left_center() - vec2(8.0, 0.0);
+    let events = vec![
+        Event::PointerMoved(click),
+        Event::PointerButton {
+            pos: click,
+            button: PointerButton::Primary,
+            pressed: true,
+            modifiers: Modifiers::NONE,
+        },
+        Event::PointerButton {
+            pos: click,
+            button: PointerButton::Primary,
+            pressed: false,
+            modifiers: Modifiers::NONE,
+        },
+    ];
+    let (_, _, _, hidden) = run(right, events);
+    assert!(hidden.contains("alpha"));
+}
+
+#[test]
+fn test_bounds_constraints() {
+    // One frame per event, returning the bounds after each:
+    let run = |plot: &dyn Fn() -> Plot<'static>, events: Vec<Event>| {
+        let ctx = egui::Context::default();
+        let mut bounds = Vec::new();
+        let first = vec![Event::PointerMoved(pos2(150.0, 100.0))];
+        for event in std::iter::once(first).chain(events.into_iter().map(|e| vec![e])) {
+            let input = RawInput {
+                events: event,
+                ..Default::default()
+            };
+            run_frame(&ctx, vec2(400.0, 300.0), input, |ui| {
+                let response = plot().show(ui, |plot_ui| {
+                    plot_ui.line(Line::new(vec![[0.0, 0.0], [10.0, 10.0]]));
+                });
+                bounds.push(response.transform.data_bounds());
+            });
+        }
+        bounds
+    };
+
+    // Zooming out repeatedly converges to the largest window, without leaving the constraint:
+    let constrained = || {
+        Plot::new("plot")
+            .bounds_constraint(PlotBounds::from_min_max([-100.0, -50.0], [100.0, 50.0]))
+            .zoom_range_x(0.5..=40.0)
+            .zoom_range_y(0.5..=1000.0)
+    };
+    let bounds = run(&constrained, vec![Event::Zoom(0.5); 20]);
+    let last = *bounds.last().unwrap();
+    assert!((last.width() - 40.0).abs() < 1e-9);
+    assert_eq!(last.range_y(), -50.0..=50.0);
+    assert_eq!(bounds[bounds.len() - 2], last);
+
+    // Zooming in stops at the smallest window:
+    let bounds = run(&constrained, vec![Event::Zoom(2.0); 20]);
+    let last = *bounds.last().unwrap();
+    assert!((last.width() - 0.5).abs() < 1e-9);
+    assert!((last.height() - 0.5).abs() < 1e-9);
+
+    // Panning stops at the edge, and then stays there exactly:
+    let mut events = vec![Event::PointerButton {
+        pos: pos2(150.0, 100.0),
+        button: PointerButton::Primary,
+        pressed: true,
+        modifiers: Modifiers::NONE,
+    }];
+    for i in 1..40 {
+        events.push(Event::PointerMoved(pos2(150.0 + 50.0 * i as f32, 100.0)));
+    }
+    let bounds = run(
+        &|| {
+            Plot::new("plot")
+                .bounds_constraint(PlotBounds::from_min_max([-20.0, -1e9], [30.0, 1e9]))
+        },
+        events,
+    );
+    let last = *bounds.last().unwrap();
+    assert_eq!(last.min()[0], -20.0);
+    assert!(bounds
+        .iter()
+        .all(|b| b.min()[0] >= -20.0 && b.max()[0] <= 30.0));
+    assert!(bounds[bounds.len() - 5..].iter().all(|b| *b == last));
+
+    // The window is shrunk around its center first, and then moved into the limits:
+    assert_eq!(
+        constrain_bounds(
+            PlotBounds::from_min_max([90.0, 0.0], [130.0, 1.0]),
+            [Some([-100.0, 100.0]), None],
+            &[Some(0.5..=20.0), None],
+        ),
+        PlotBounds::from_min_max([80.0, 0.0], [100.0, 1.0])
+    );
+}
+
+#[test]
+fn test_editable_points() {
+    // One frame per event, applying the moved points like an app would:
+    let run = |editable: &dyn Fn(Vec<[f64; 2]>) -> EditablePoints,
+               points: Vec<[f64; 2]>,
+               drag: &[[f64; 2]]| {
+        let ctx = egui::Context::default();
+        let mut points = points;
+        let mut moved = Vec::new();
+        let mut bounds = Vec::new();
+        let mut transform = None;
+        let mut frames = vec![vec![]];
+        for (i, _) in drag.iter().enumerate() {
+            frames.push(vec![Event::PointerMoved(Pos2::ZERO)]);
+            if i == 0 {
+                frames.push(vec![Event::PointerButton {
+                    pos: Pos2::ZERO,
+                    button: PointerButton::Primary,
+                    pressed: true,
+                    modifiers: Modifiers::NONE,
+                }]);
+            }
+        }
+        frames.push(vec![Event::PointerButton {
+            pos: Pos2::ZERO,
+            button: PointerButton::Primary,
+            pressed: false,
+            modifiers: Modifiers::NONE,
+        }]);
+        let mut drag = drag.iter();
+        let mut pointer = Pos2::ZERO;
+        for mut events in frames {
+            // The events are placed at the drag positions with the transform of the last frame.
+            if let (Some(Event::PointerMoved(_)), Some(transform)) = (events.first(), transform) {
+                let to = drag.next().unwrap();
+                pointer = PlotTransform::position_from_point(&transform, &PlotPoint::from(*to));
+            }
+            for event in &mut events {
+                match event {
+                    Event::PointerMoved(pos) | Event::PointerButton { pos, .. } => *pos = pointer,
+                    _ => {}
+                }
+            }
+            let input = RawInput {
+                events,
+                ..Default::default()
+            };
+            run_frame(&ctx, vec2(400.0, 300.0), input, |ui| {
+                let response = Plot::new("plot")
+                    .include_x(0.0)
+                    .include_x(10.0)
+                    .include_y(0.0)
+                    .include_y(10.0)
+                    .auto_bounds(false.into())
+                    .show(ui, |plot_ui| {
+                        plot_ui.editable_points(editable(points.clone()).name("points"));
+                    });
+                for &(id, index, point) in &response.moved_points {
+                    assert_eq!(id, Id::new("points"));
+                    points[index] = [point.x, point.y];
+                }
+                moved.extend(response.moved_points.iter().map(|m| m.1));
+                bounds.push(response.transform.data_bounds());
+                transform = Some(response.transform);
+            });
+        }
+        (points, moved, bounds)
+    };
+    let close = |a: [f64; 2], b: [f64; 2]| (a[0] - b[0]).abs() < 0.1 && (a[1] - b[1]).abs() < 0.1;
+    let initial = vec![[1.0, 1.0], [5.0, 5.0], [9.0, 2.0]];
+
+    // The grabbed point follows the pointer, and the plot doesn't pan:
+    let (points, moved, bounds) = run(
+        &EditablePoints::new,
+        initial.clone(),
+        &[[5.0, 5.0], [5.5, 6.0], [6.0, 7.0], [6.5, 8.0]],
+    );
+    assert!(close(points[1], [6.5, 8.0]), "{points:?}");
+    assert_eq!((points[0], points[2]), (initial[0], initial[2]));
+    assert!(moved.iter().all(|&index| index == 1));
+    assert!(bounds.windows(2).all(|b| b[0] == b[1]));
+
+    // Locked x, clamped y:
+    let (points, _, _) = run(
+        &|points| {
+            EditablePoints::new(points)
+                .lock_x(true)
+                .clamp_to(PlotBounds::from_min_max([0.0, 0.0], [10.0, 6.0]))
+        },
+        initial.clone(),
+        &[[5.0, 5.0], [6.0, 6.0], [7.0, 7.0], [8.0, 8.0]],
+    );
+    assert_eq!(points[1][0], 5.0);
+    assert_eq!(points[1][1], 6.0);
+
+    // Dragging past a neighbor swaps places with it:
+    let (points, moved, _) = run(
+        &|points| EditablePoints::new(points).keep_sorted(true),
+        initial.clone(),
+        &[[5.0, 5.0], [7.0, 5.0], [9.5, 5.0], [9.8, 4.0]],
+    );
+    assert_eq!(points[1], initial[2]);
+    assert!(close(points[2], [9.8, 4.0]), "{points:?}");
+    assert!(moved.contains(&1) && moved.contains(&2));
+
+    // Dragging away from the points pans as before:
+    let (points, moved, bounds) = run(
+        &EditablePoints::new,
+        initial.clone(),
+        &[[3.0, 8.0], [4.0, 8.0], [5.0, 8.0], [6.0, 8.0]],
+    );
+    assert_eq!(points, initial);
+    assert!(moved.is_empty());
+    assert_ne!(bounds.first(), bounds.last());
+}
+
+#[test]
+fn test_violin() {
+    // The area of each painted violin, in the order of the `violins`:
+    let areas = |scale: ViolinScale| {
+        let ctx = egui::Context::default();
+        let mut areas = Vec::new();
+        run_frame(&ctx, vec2(400.0, 300.0), Default::default(), |ui| {
+            let few = ViolinElem::new(1.0, &[0.0, 1.0, 2.0]).fill(Color32::RED);
+            let many: Vec<f64> = (0..100).map(|i| (i % 10) as f64 * 0.5).collect();
+            let many = ViolinElem::new(2.0, &many).fill(Color32::BLUE);
+            let response = Plot::new("plot").show(ui, |plot_ui| {
+                plot_ui.violin(Violin::new(vec![few, many]).scale(scale).inner_box(false));
+            });
+            let (layer_id, range) = response.painted_shapes.clone();
+            ctx.graphics(|g| {
+                for clipped in g
+                    .get(layer_id)
+                    .unwrap()
+                    .all_entries()
+                    .skip(range.start)
+                    .take(range.len())
+                {
+                    let Shape::Mesh(mesh) = &clipped.shape else {
+                        continue;
+                    };
+                    let area: f32 = mesh
+                        .indices
+                        .chunks(3)
+                        .map(|t| {
+                            let [a, b, c] = [0, 1, 2].map(|i| mesh.vertices[t[i] as usize].pos);
+                            let (u, v) = (b - a, c - a);
+                            (u.x * v.y - u.y * v.x).abs() / 2.0
+                        })
+                        .sum();
+                    let width = mesh
+                        .vertices
+                        .iter()
+                        .map(|v| v.pos.x)
+                        .fold(f32::MIN, f32::max)
+                        - mesh
+                            .vertices
+                            .iter()
+                            .map(|v| v.pos.x)
+                            .fold(f32::MAX, f32::min);
+                    areas.push((mesh.vertices[0].color, area, width));
+                }
+            });
+        });
+        areas
+    };
+
+    // Equal area, although the samples differ in count and spread:
+    let painted = areas(ViolinScale::Area);
+    assert_eq!(painted.len(), 2);
+    assert_eq!((painted[0].0, painted[1].0), (Color32::RED, Color32::BLUE));
+    assert!(
+        (painted[0].1 / painted[1].1 - 1.0).abs() < 0.02,
+        "{painted:?}"
+    );
+    assert!((painted[0].2 - painted[1].2).abs() > 1.0);
+
+    // Equal width:
+    let painted = areas(ViolinScale::Width);
+    assert!((painted[0].2 - painted[1].2).abs() < 0.1, "{painted:?}");
+    assert!((painted[0].1 / painted[1].1 - 1.0).abs() > 0.02);
+
+    // Horizontal violins swap the axes:
+    let violin = Violin::new(vec![ViolinElem::new(3.0, &[1.0, 2.0])]).horizontal();
+    let bounds = PlotItem::bounds(&violin);
+    assert_eq!(bounds.range_y(), 2.75..=3.25);
+    assert!(bounds.min()[0] < 1.0 && bounds.max()[0] > 2.0);
+}
+
+#[test]
+fn test_image_rotation() {
+    let frame = Rect::from_min_size(Pos2::ZERO, vec2(100.0, 100.0));
+    let transform = PlotTransform::new(
+        frame,
+        PlotBounds::from_min_max([-5.0, -5.0], [5.0, 5.0]),
+        false,
+        false,
+    );
+    let mesh = |image: &PlotImage| {
+        let ctx = egui::Context::default();
+        let mut shapes = Vec::new();
+        let _ = ctx.run(RawInput::default(), |ctx| {
+            CentralPanel::default().show(ctx, |ui| image.shapes(ui, &transform, &mut shapes));
+        });
+        shapes
+            .into_iter()
+            .find_map(|shape| match shape {
+                Shape::Mesh(mesh) => Some(mesh),
+                _ => None,
+            })
+            .unwrap()
+    };
+    let corner = |mesh: &Mesh, uv: Pos2| {
+        let vertex = mesh.vertices.iter().find(|v| v.uv == uv).unwrap();
+        transform.value_from_position(vertex.pos)
+    };
+    let close = |a: PlotPoint, b: [f64; 2]| (a.x - b[0]).abs() < 1e-4 && (a.y - b[1]).abs() < 1e-4;
+
+    // A 2×1 image at (1, 1), rotated by 90° counter-clockwise, so the top of the texture is on the left:
+    let texture = TextureId::Managed(0);
+    let image = PlotImage::new(texture, PlotPoint::new(1.0, 1.0), vec2(2.0, 1.0))
+        .rotate(std::f64::consts::FRAC_PI_2);
+    let rotated = mesh(&image);
+    assert_eq!(rotated.texture_id, texture);
+    assert!(close(corner(&rotated, pos2(0.0, 0.0)), [0.5, 0.0]));
+    assert!(close(corner(&rotated, pos2(1.0, 0.0)), [0.5, 2.0]));
+    assert!(close(corner(&rotated, pos2(1.0, 1.0)), [1.5, 2.0]));
+    assert!(close(corner(&rotated, pos2(0.0, 1.0)), [1.5, 0.0]));
+    let bounds = PlotItem::bounds(&image);
+    assert!(close(PlotPoint::from(bounds.min()), [0.5, 0.0]));
+    assert!(close(PlotPoint::from(bounds.max()), [1.5, 2.0]));
+
+    // Flipped UVs swap the texture corners:
+    let flipped = image.uv(Rect::from_min_max(pos2(1.0, 0.0), pos2(0.0, 1.0)));
+    let flipped = mesh(&flipped);
+    assert!(close(corner(&flipped, pos2(1.0, 0.0)), [0.5, 0.0]));
+
+    // The bounds of a unit image rotated by 45° fit the diamond tightly:
+    let image = PlotImage::new(texture, PlotPoint::new(0.0, 0.0), vec2(1.0, 1.0))
+        .rotate(std::f64::consts::FRAC_PI_4);
+    let bounds = PlotItem::bounds(&image);
+    let half_diagonal = 0.5 * std::f64::consts::SQRT_2;
+    assert!(close(
+        PlotPoint::from(bounds.min()),
+        [-half_diagonal, -half_diagonal]
+    ));
+    assert!(close(
+        PlotPoint::from(bounds.max()),
+        [half_diagonal, half_diagonal]
+    ));
+
+    // Hovering uses the diamond, not its bounding box:
+    let at = |x: f64, y: f64| transform.position_from_point(&PlotPoint::new(x, y));
+    let dist_sq = |pos| {
+        PlotItem::find_closest(&image, pos, &transform)
+            .unwrap()
+            .dist_sq
+    };
+    assert_eq!(dist_sq(at(0.6, 0.0)), 0.0);
+    assert!(dist_sq(at(0.6, 0.6)) > 0.0);
+}
+
+#[test]
+fn test_arrows() {
+    let frame = Rect::from_min_size(Pos2::ZERO, vec2(100.0, 100.0));
+    let transform = |half_extent: f64| {
+        PlotTransform::new(
+            frame,
+            PlotBounds::new_symmetrical(half_extent),
+            false,
+            false,
+        )
+    };
+    // The shaft and the head of each arrow, as (origin, tip, head, color):
+    let paint = |arrows: &Arrows, transform: &PlotTransform| {
+        let ctx = egui::Context::default();
+        let mut shapes = Vec::new();
+        let _ = ctx.run(RawInput::default(), |ctx| {
+            CentralPanel::default().show(ctx, |ui| arrows.shapes(ui, transform, &mut shapes));
+        });
+        shapes
+            .chunks(2)
+            .map(|pair| match pair {
+                [Shape::LineSegment { points, stroke }, Shape::Path(head)] => {
+                    let epaint::ColorMode::Solid(color) = stroke.color else {
+                        panic!("expected a solid color");
+                    };
+                    (points[0], points[1], head.points.clone(), color)
+                }
+                _ => panic!("unexpected shapes {pair:?}"),
+            })
+            .collect::<Vec<_>>()
+    };
+
+    // An arrow to the right has its head behind the tip, mirrored around the shaft,
+    // and the same size in ui points at any zoom:
+    let arrows = Arrows::new(vec![[0.0, 0.0]], vec![[1.0, 0.0]])
+        .color(Color32::RED)
+        .head_size_points(6.0);
+    for half_extent in [2.0, 20.0] {
+        let painted = paint(&arrows, &transform(half_extent));
+        let (_, tip, head, color) = &painted[0];
+        assert_eq!(*color, Color32::RED);
+        assert_eq!(head[1], *tip);
+        for end in [head[0], head[2]] {
+            assert!(end.x < tip.x);
+            assert!(((end - *tip).length() - 6.0).abs() < 1e-3);
+        }
+        assert!((head[0].y - tip.y + head[2].y - tip.y).abs() < 1e-3);
+        assert!(head[0].y != head[2].y);
+    }
+
+    // Scaled and normalized arrows keep their direction:
+    let arrows = Arrows::new(vec![[0.0, 0.0], [0.0, 0.0]], vec![[1.0, 0.0], [0.0, -3.0]]);
+    let painted = paint(&arrows.scale(2.0), &transform(10.0));
+    assert_eq!(painted[0].1, pos2(60.0, 50.0));
+    assert_eq!(painted[1].1, pos2(50.0, 80.0));
+    let arrows = Arrows::new(vec![[0.0, 0.0], [0.0, 0.0]], vec![[1.0, 0.0], [0.0, -3.0]]);
+    let painted = paint(&arrows.normalize(true).scale(2.0), &transform(10.0));
+    assert_eq!(painted[0].1, pos2(60.0, 50.0));
+    assert_eq!(painted[1].1, pos2(50.0, 60.0));
+
+    // The smallest, middle and largest magnitude get the ends and middle of the colormap:
+    let colormap = Colormap::Custom(vec![Color32::BLACK, Color32::WHITE]);
+    let field = || {
+        Arrows::new(
+            vec![[0.0, 0.0], [0.0, 0.0], [0.0, 0.0]],
+            vec![[1.0, 0.0], [0.0, 2.0], [-3.0, 0.0]],
+        )
+    };
+    let colors = |arrows: Arrows| {
+        paint(&arrows, &transform(10.0))
+            .into_iter()
+            .map(|arrow| arrow.3)
+            .collect::<Vec<_>>()
+    };
+    assert_eq!(
+        colors(field().color_by_magnitude(colormap.clone(), None)),
+        [0.0, 0.5, 1.0].map(|t| colormap.color(t))
+    );
+    assert_eq!(
+        colors(field().color_by_magnitude(colormap.clone(), Some(0.0..=4.0))),
+        [0.25, 0.5, 0.75].map(|t| colormap.color(t))
+    );
+    assert_eq!(colormap.color(0.0), Color32::BLACK);
+    assert_eq!(colormap.color(1.0), Color32::WHITE);
+}
+
+#[test]
+fn test_linked_selection() {
+    let ctx = egui::Context::default();
+    let modifiers = Modifiers::ALT;
+    // One frame with three stacked plots, where "c" is in another group.
+    // Returns their selections, after clearing the selection of the plot named in `clear`.
+    let run = |events: Vec<Event>, clear: &str| {
+        let mut selections = Vec::new();
+        let input = RawInput {
+            modifiers,
+            events,
+            ..Default::default()
+        };
+        run_frame(&ctx, vec2(400.0, 600.0), input, |ui| {
+            for (name, group, link_y) in [("a", "ab", true), ("b", "ab", false), ("c", "c", true)] {
+                let response = Plot::new(name)
+                    .height(190.0)
+                    .show_axes(false)
+                    .include_x(0.0)
+                    .include_x(10.0)
+                    .include_y(0.0)
+                    .include_y(10.0)
+                    .selection(SelectionMode::Rectangle)
+                    .link_selection(group, link_y)
+                    .show(ui, |plot_ui| {
+                        if clear == name {
+                            plot_ui.clear_selection();
+                        }
+                    });
+                selections.push(response.selection);
+            }
+        });
+        selections
+    };
+    let button = |pos, pressed| Event::PointerButton {
+        pos,
+        button: PointerButton::Primary,
+        pressed,
+        modifiers,
+    };
+    let select = |from: Pos2, to: Pos2| {
+        run(vec![Event::PointerMoved(from), button(from, true)], "");
+        run(vec![Event::PointerMoved(from + (to - from) / 2.0)], "");
+        run(vec![Event::PointerMoved(to)], "");
+        run(vec![button(to, false)], "")
+    };
+
+    run(vec![], "");
+    // Selecting in "a" shows the selection in "b" in the same frame, with all Y values:
+    let selections = select(pos2(100.0, 50.0), pos2(200.0, 150.0));
+    let a = selections[0].unwrap();
+    let b = selections[1].unwrap();
+    assert!(a.range_y().start().is_finite());
+    assert_eq!(a.range_x(), b.range_x());
+    assert_eq!(b.range_y(), f64::NEG_INFINITY..=f64::INFINITY);
+    assert_eq!(selections[2], None);
+    // It stays shared:
+    assert_eq!(run(vec![], ""), selections);
+
+    // Clearing in "a" clears "b" too:
+    let selections = run(vec![], "a");
+    assert_eq!(selections, vec![None; 3]);
+
+    // A selection in "b" reaches "a" in the next frame, with its full extent:
+    let selections = select(pos2(100.0, 250.0), pos2(300.0, 350.0));
+    assert!(selections[1].is_some());
+    let selections = run(vec![], "");
+    assert_eq!(selections[0], selections[1]);
+    assert!(selections[0].unwrap().range_y().start().is_finite());
+    assert_eq!(selections[2], None);
+}
+
+#[test]
+fn test_category_hover_link_expires() {
+    let group = Id::new("group");
+    let (a, b) = (Id::new("a"), Id::new("b"));
+    let mut groups = CategoryHoverLinkGroups::default();
+    groups.0.insert(
+        group,
+        LinkedCategoryHover {
+            plot_id: a,
+            index: 2,
+            frame_nr: 10,
+        },
+    );
+
+    // Only other plots see the hover, in the same and the next frame:
+    assert_eq!(groups.hovered_by_other(group, a, 10), None);
+    assert_eq!(groups.hovered_by_other(group, b, 10), Some(2));
+    assert_eq!(groups.hovered_by_other(group, b, 11), Some(2));
+
+    // After that, the hovering plot is no longer shown:
+    assert_eq!(groups.hovered_by_other(group, b, 12), None);
+    assert!(groups.0.is_empty());
+}
+
+#[test]
+fn test_line_gradient() {
+    let points = vec![[0.0, 0.0], [1.0, 1.0], [2.0, 0.0], [3.0, 1.0], [4.0, 0.0]];
+    let colormap = Colormap::Turbo;
+
+    // Normalized to the range of the values:
+    let line =
+        Line::new(points.clone()).gradient_by(vec![2.0, 4.0, 6.0, 8.0, 10.0], colormap.clone());
+    let colors = line.gradient_colors().unwrap();
+    let expected = [0.0, 0.25, 0.5, 0.75, 1.0].map(|t| colormap.color(t));
+    assert_eq!(colors, expected);
+
+    // Normalized to a fixed range, clamping values outside of it, and NaN is transparent:
+    let line = Line::new(points)
+        .gradient_by(vec![0.0, 5.0, 10.0, f64::NAN, 20.0], colormap.clone())
+        .gradient_range(0.0..=10.0);
+    let colors = line.gradient_colors().unwrap();
+    assert_eq!(colors[1], colormap.color(0.5));
+    assert_eq!(colors[2], colormap.color(1.0));
+    assert_eq!(colors[3], Color32::TRANSPARENT);
+    assert_eq!(colors[4], colormap.color(1.0));
+
+    // Both vertices of a point have its color:
+    let positions = [pos2(0.0, 0.0), pos2(10.0, 0.0), pos2(20.0, 10.0)];
+    let mesh = items::gradient_line_mesh(&positions, &colors[..3], 2.0);
+    assert_eq!(mesh.vertices.len(), 6);
+    assert_eq!(mesh.indices.len(), 12);
+    for (i, color) in colors[..3].iter().enumerate() {
+        assert_eq!(mesh.vertices[2 * i].color, *color);
+        assert_eq!(mesh.vertices[2 * i + 1].color, *color);
+    }
+    assert_eq!(mesh.vertices[0].pos, pos2(0.0, 1.0));
+    assert_eq!(mesh.vertices[1].pos, pos2(0.0, -1.0));
+
+    // Custom stops are interpolated:
+    let custom = Colormap::Custom(vec![Color32::BLACK, Color32::WHITE]);
+    assert_eq!(custom.color(0.0), Color32::BLACK);
+    assert_eq!(custom.color(0.5), Color32::from_gray(128));
+    assert_eq!(custom.color(1.0), Color32::WHITE);
+}
+
+#[test]
+fn test_points_styles() {
+    // Three points, so the colors cycle and the third point gets the first color:
+    let points = Points::new(vec![[2.0, 5.0], [5.0, 5.0], [8.0, 5.0]])
+        .radius(10.0)
+        .color(Color32::GREEN)
+        .colors(vec![Color32::RED, Color32::BLUE])
+        .shapes(vec![
+            MarkerShape::Circle,
+            MarkerShape::Square,
+            MarkerShape::Diamond,
+        ]);
+    let transform = PlotTransform::new(
+        Rect::from_min_size(Pos2::ZERO, vec2(100.0, 100.0)),
+        PlotBounds::from_min_max([0.0, 0.0], [10.0, 10.0]),
+        false,
+        false,
+    );
+
+    let ctx = egui::Context::default();
+    let mut shapes = Vec::new();
+    let _ = ctx.run(Default::default(), |ctx| {
+        CentralPanel::default().show(ctx, |ui| {
+            PlotItem::shapes(&points, ui, &transform, &mut shapes);
+        });
+    });
+    let [Shape::Mesh(mesh)] = shapes.as_slice() else {
+        panic!("Expected a single mesh, got {shapes:?}");
+    };
+
+    // The extent of the opaque vertices around a marker of the given color, in both directions:
+    let extent = |center: Pos2, color: Color32| {
+        let vertices: Vec<_> = mesh
+            .vertices
+            .iter()
+            .filter(|v| v.color.a() == 255 && v.pos.distance(center) < 20.0)
+            .collect();
+        assert!(!vertices.is_empty());
+        assert!(vertices.iter().all(|v| v.color == color));
+        vertices
+            .iter()
+            .fold(Vec2::ZERO, |extent, v| extent.max((v.pos - center).abs()))
+    };
+    // Feathering blends the outermost pixel, even more so in sharp corners:
+    let approx = |a: f32, b: f32| (a - b).abs() <= 0.75;
+
+    let circle = extent(pos2(20.0, 50.0), Color32::RED);
+    assert!(
+        approx(circle.x, 10.0) && approx(circle.y, 10.0),
+        "{circle:?}"
+    );
+
+    let square = extent(pos2(50.0, 50.0), Color32::BLUE);
+    let half_side = 10.0 / 2_f32.sqrt();
+    assert!(
+        approx(square.x, half_side) && approx(square.y, half_side),
+        "{square:?}"
+    );
+
+    let diamond = extent(pos2(80.0, 50.0), Color32::RED);
+    assert!(
+        approx(diamond.x, 10.0) && approx(diamond.y, 10.0),
+        "{diamond:?}"
+    );
+
+    // The legend uses the base color:
+    assert_eq!(PlotItem::color(&points), Color32::GREEN);
 }
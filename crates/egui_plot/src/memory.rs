@@ -1,6 +1,6 @@
 use std::collections::BTreeMap;
 
-use egui::{Context, Id, Pos2, Vec2b};
+use egui::{emath::Tween, Context, Id, Pos2, Vec2, Vec2b};
 
 use crate::{PlotBounds, PlotTransform};
 
@@ -23,15 +23,57 @@ pub struct PlotMemory {
     /// The transform from last frame.
     pub(crate) transform: PlotTransform,
 
+    /// Maps primary Y values to secondary Y values as `(scale, offset)`,
+    /// if the plot has a secondary Y axis.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub(crate) secondary_y: Option<(f64, f64)>,
+
+    /// How far the legend entries are scrolled, if they don't fit.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub(crate) legend_scroll_offset: f32,
+
+    /// The bounds animation in progress, see [`crate::PlotUi::animate_bounds_to`].
+    #[cfg_attr(feature = "serde", serde(skip))]
+    pub(crate) bounds_animation: Option<Tween<PlotBounds>>,
+
     /// Allows to remember the first click position when performing a boxed zoom
     pub(crate) last_click_pos_for_zoom: Option<Pos2>,
 
+    /// Where the selection being dragged out started.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub(crate) selection_start: Option<Pos2>,
+
+    /// The last selection, see [`crate::Plot::selection`].
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub(crate) selection: Option<PlotBounds>,
+
     /// The thickness of each of the axes the previous frame.
     ///
     /// This is used in the next frame to make the axes thicker
     /// in order to fit the labels, if necessary.
     pub(crate) x_axis_thickness: BTreeMap<usize, f32>,
     pub(crate) y_axis_thickness: BTreeMap<usize, f32>,
+
+    /// The width (or height) of a legend outside the plot the previous frame,
+    /// see [`crate::Legend::position_outside`].
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub(crate) legend_outside_size: f32,
+
+    /// The point being dragged, see [`crate::EditablePoints`].
+    #[cfg_attr(feature = "serde", serde(skip))]
+    pub(crate) dragged_point: Option<DraggedPoint>,
+}
+
+/// A point the user is dragging.
+#[derive(Clone, Copy, Debug)]
+pub(crate) struct DraggedPoint {
+    /// The id, or else the name, of the item.
+    pub item: Id,
+
+    pub index: usize,
+
+    /// From the pointer to the point, so the point doesn't jump to the pointer.
+    pub offset: Vec2,
 }
 
 impl PlotMemory {
@@ -8,6 +8,8 @@ pub struct PlotUi {
     pub(crate) next_auto_color_idx: usize,
     pub(crate) last_plot_transform: PlotTransform,
     pub(crate) last_auto_bounds: Vec2b,
+    pub(crate) hidden_items: ahash::HashSet<String>,
+    pub(crate) selection: Option<PlotBounds>,
     pub(crate) response: Response,
     pub(crate) bounds_modifications: Vec<BoundsModification>,
 }
@@ -28,17 +30,64 @@ impl PlotUi {
     /// The plot bounds as they were in the last frame. If called on the first frame and the bounds were not
     /// further specified in the plot builder, this will return bounds centered on the origin. The bounds do
     /// not change until the plot is drawn.
+    ///
+    /// These are in data values, also on a logarithmic axis (see [`crate::AxisScale`]).
     pub fn plot_bounds(&self) -> PlotBounds {
-        *self.last_plot_transform.bounds()
+        self.last_plot_transform.data_bounds()
     }
 
     /// Set the plot bounds. Can be useful for implementing alternative plot navigation methods.
+    ///
+    /// These are in data values, also on a logarithmic axis (see [`crate::AxisScale`]).
     pub fn set_plot_bounds(&mut self, plot_bounds: PlotBounds) {
         self.bounds_modifications
             .push(BoundsModification::Set(plot_bounds));
     }
 
+    /// Set the X range of the plot bounds, and stop auto-fitting X.
+    ///
+    /// The Y axis keeps its auto-bounds mode, e.g. to follow streaming data in a fixed
+    /// time window while Y fits the data.
+    ///
+    /// These are in data values, also on a logarithmic axis (see [`crate::AxisScale`]).
+    pub fn set_plot_bounds_x(&mut self, range: impl Into<RangeInclusive<f64>>) {
+        self.bounds_modifications
+            .push(BoundsModification::SetX(range.into()));
+    }
+
+    /// Set the Y range of the plot bounds, and stop auto-fitting Y.
+    ///
+    /// The X axis keeps its auto-bounds mode.
+    ///
+    /// These are in data values, also on a logarithmic axis (see [`crate::AxisScale`]).
+    pub fn set_plot_bounds_y(&mut self, range: impl Into<RangeInclusive<f64>>) {
+        self.bounds_modifications
+            .push(BoundsModification::SetY(range.into()));
+    }
+
+    /// Move the plot bounds smoothly to `plot_bounds` over `duration` seconds,
+    /// e.g. for a "zoom to selection" button.
+    ///
+    /// `easing` shapes the motion, e.g. [`emath::easing::cubic_out`] or any other [`emath::easing::Curve`].
+    /// Panning or zooming the plot, or changing its bounds otherwise, cancels the animation.
+    ///
+    /// These are in data values, also on a logarithmic axis (see [`crate::AxisScale`]).
+    pub fn animate_bounds_to(
+        &mut self,
+        plot_bounds: PlotBounds,
+        duration: f32,
+        easing: impl emath::easing::Curve + Send + Sync + 'static,
+    ) {
+        self.bounds_modifications.push(BoundsModification::Animate(
+            plot_bounds,
+            duration,
+            BoxedCurve::new(easing),
+        ));
+    }
+
     /// Move the plot bounds. Can be useful for implementing alternative plot navigation methods.
+    ///
+    /// On a logarithmic axis (see [`crate::AxisScale`]) the delta is in decades.
     pub fn translate_bounds(&mut self, delta_pos: Vec2) {
         self.bounds_modifications
             .push(BoundsModification::Translate(delta_pos));
@@ -56,6 +105,42 @@ impl PlotUi {
             .push(BoundsModification::AutoBounds(auto_bounds));
     }
 
+    /// Show or hide the items with the given name, like clicking their legend entry.
+    ///
+    /// The hidden items are returned in [`crate::PlotResponse::hidden_items`].
+    pub fn set_item_visibility(&mut self, name: impl Into<String>, visible: bool) {
+        let name = name.into();
+        if visible {
+            self.hidden_items.remove(&name);
+        } else {
+            self.hidden_items.insert(name);
+        }
+    }
+
+    /// The region selected last, see [`crate::Plot::selection`].
+    pub fn selection(&self) -> Option<PlotBounds> {
+        self.selection
+    }
+
+    /// Remove the selection, see [`crate::Plot::selection`].
+    pub fn clear_selection(&mut self) {
+        self.selection = None;
+    }
+
+    /// The indices of the points within the bounds, including their edges,
+    /// e.g. the points within [`Self::selection`].
+    pub fn points_in_bounds(points: &PlotPoints, bounds: PlotBounds) -> Vec<usize> {
+        points
+            .points()
+            .iter()
+            .enumerate()
+            .filter(|(_, point)| {
+                bounds.range_x().contains(&point.x) && bounds.range_y().contains(&point.y)
+            })
+            .map(|(index, _)| index)
+            .collect()
+    }
+
     /// Can be used to check if the plot was hovered or clicked.
     pub fn response(&self) -> &Response {
         &self.response
@@ -134,6 +219,22 @@ impl PlotUi {
         self.items.push(Box::new(line));
     }
 
+    /// Add a [`StreamingLine`] kept in your own state.
+    ///
+    /// This shares the points with `line` instead of copying them.
+    pub fn line_streaming(&mut self, line: &StreamingLine) {
+        if line.is_empty() {
+            return;
+        };
+
+        let mut line = line.clone();
+        // Give the stroke an automatic color if no color has been assigned.
+        if line.stroke.color == Color32::TRANSPARENT {
+            line.stroke.color = self.auto_color();
+        }
+        self.items.push(Box::new(line));
+    }
+
     /// Add a polygon. The polygon has to be convex.
     pub fn polygon(&mut self, mut polygon: Polygon) {
         if polygon.series.is_empty() {
@@ -147,6 +248,19 @@ impl PlotUi {
         self.items.push(Box::new(polygon));
     }
 
+    /// Add a filled band between two curves, e.g. a confidence interval.
+    pub fn band(&mut self, mut band: Band) {
+        if band.upper.is_empty() || band.lower.is_empty() {
+            return;
+        };
+
+        // Give the band an automatic color if no color has been assigned.
+        if band.color == Color32::TRANSPARENT {
+            band.color = self.auto_color();
+        }
+        self.items.push(Box::new(band));
+    }
+
     /// Add a text.
     pub fn text(&mut self, text: Text) {
         if text.text.is_empty() {
@@ -156,6 +270,15 @@ impl PlotUi {
         self.items.push(Box::new(text));
     }
 
+    /// Add a text box pinned to a point, see [`Annotation`].
+    pub fn annotation(&mut self, annotation: Annotation) {
+        if annotation.text.is_empty() {
+            return;
+        };
+
+        self.items.push(Box::new(annotation));
+    }
+
     /// Add data points.
     pub fn points(&mut self, mut points: Points) {
         if points.series.is_empty() {
@@ -169,6 +292,54 @@ impl PlotUi {
         self.items.push(Box::new(points));
     }
 
+    /// Add a stem plot.
+    pub fn stems(&mut self, mut stems: Stems) {
+        if stems.series.is_empty() {
+            return;
+        };
+
+        // Give the stems an automatic color if no color has been assigned.
+        if stems.stroke.color == Color32::TRANSPARENT {
+            stems.stroke.color = self.auto_color();
+        }
+        self.items.push(Box::new(stems));
+    }
+
+    /// Add points which the user can drag, see [`EditablePoints`].
+    pub fn editable_points(&mut self, mut points: EditablePoints) {
+        if points.points.is_empty() {
+            return;
+        };
+
+        // Give the points an automatic color if no color has been assigned.
+        if points.color == Color32::TRANSPARENT {
+            points.color = self.auto_color();
+        }
+        self.items.push(Box::new(points));
+    }
+
+    /// Add error bars.
+    pub fn error_bars(&mut self, mut error_bars: ErrorBars) {
+        if error_bars.series.is_empty() {
+            return;
+        };
+
+        // Give the bars the color of the series they are attached to, or an automatic color.
+        if error_bars.stroke.color == Color32::TRANSPARENT {
+            error_bars.stroke.color = error_bars
+                .attached
+                .then(|| {
+                    self.items
+                        .iter()
+                        .find(|item| item.name() == error_bars.name)
+                        .map(|item| item.color())
+                })
+                .flatten()
+                .unwrap_or_else(|| self.auto_color());
+        }
+        self.items.push(Box::new(error_bars));
+    }
+
     /// Add arrows.
     pub fn arrows(&mut self, mut arrows: Arrows) {
         if arrows.origins.is_empty() || arrows.tips.is_empty() {
@@ -187,6 +358,11 @@ impl PlotUi {
         self.items.push(Box::new(image));
     }
 
+    /// Add a heat map.
+    pub fn heat_map(&mut self, heat_map: HeatMap) {
+        self.items.push(Box::new(heat_map));
+    }
+
     /// Add a horizontal line.
     /// Can be useful e.g. to show min/max bounds or similar.
     /// Always fills the full width of the plot.
@@ -220,6 +396,19 @@ impl PlotUi {
         self.items.push(Box::new(box_plot));
     }
 
+    /// Add a violin diagram.
+    pub fn violin(&mut self, mut violin: Violin) {
+        if violin.violins.is_empty() {
+            return;
+        }
+
+        // Give the elements an automatic color if no color has been assigned.
+        if violin.default_color == Color32::TRANSPARENT {
+            violin = violin.color(self.auto_color());
+        }
+        self.items.push(Box::new(violin));
+    }
+
     /// Add a bar chart.
     pub fn bar_chart(&mut self, mut chart: BarChart) {
         if chart.bars.is_empty() {
@@ -232,4 +421,34 @@ impl PlotUi {
         }
         self.items.push(Box::new(chart));
     }
+
+    /// Add bar charts stacked on top of each other.
+    pub fn stacked_bars(&mut self, mut stacked: StackedBars) {
+        stacked.stack(&self.hidden_items);
+        for chart in stacked.charts {
+            self.bar_chart(chart);
+        }
+    }
+
+    /// Add a rose chart.
+    pub fn rose_chart(&mut self, mut chart: RoseChart) {
+        if chart.values.is_empty() {
+            return;
+        }
+
+        // Give the chart an automatic color if no color has been assigned.
+        if chart.default_color == Color32::TRANSPARENT {
+            chart = chart.color(self.auto_color());
+        }
+        self.items.push(Box::new(chart));
+    }
+
+    /// Add a gauge.
+    pub fn gauge(&mut self, mut gauge: Gauge) {
+        // Give the gauge an automatic color if no color has been assigned.
+        if gauge.default_color == Color32::TRANSPARENT {
+            gauge = gauge.color(self.auto_color());
+        }
+        self.items.push(Box::new(gauge));
+    }
 }
@@ -0,0 +1,359 @@
+//! Export of a plot as SVG, see [`crate::PlotResponse::export_svg`].
+
+use std::fmt::Write as _;
+
+use egui::{
+    epaint::{
+        ClippedShape, ColorMode, CubicBezierShape, Mesh, PathShape, PathStroke,
+        QuadraticBezierShape, RectShape, TextShape,
+    },
+    Color32, FontFamily, Pos2, Rect, Shape, Stroke,
+};
+
+/// Serialize the shapes of a plot as an SVG document showing `view_box`.
+///
+/// Clip rectangles become `clipPath`s, text becomes `<text>` elements and meshes become
+/// triangles, since SVG has no equivalent of textured meshes.
+pub(crate) fn shapes_to_svg(view_box: Rect, shapes: &[ClippedShape]) -> String {
+    let mut svg = String::new();
+    let _ = writeln!(
+        svg,
+        r#"<svg xmlns="http://www.w3.org/2000/svg" width="{}" height="{}" viewBox="{} {} {} {}">"#,
+        view_box.width(),
+        view_box.height(),
+        view_box.min.x,
+        view_box.min.y,
+        view_box.width(),
+        view_box.height(),
+    );
+
+    let mut current_clip = None;
+    let mut num_clips = 0;
+    for ClippedShape { clip_rect, shape } in shapes {
+        let clip_rect = clip_rect.intersect(view_box);
+        if current_clip != Some(clip_rect) {
+            if current_clip.is_some() {
+                svg.push_str("</g>\n");
+            }
+            let _ = writeln!(
+                svg,
+                r#"<clipPath id="clip{num_clips}"><rect x="{}" y="{}" width="{}" height="{}"/></clipPath>"#,
+                clip_rect.min.x,
+                clip_rect.min.y,
+                clip_rect.width().max(0.0),
+                clip_rect.height().max(0.0),
+            );
+            let _ = writeln!(svg, r#"<g clip-path="url(#clip{num_clips})">"#);
+            num_clips += 1;
+            current_clip = Some(clip_rect);
+        }
+        write_shape(&mut svg, shape);
+    }
+    if current_clip.is_some() {
+        svg.push_str("</g>\n");
+    }
+
+    svg.push_str("</svg>\n");
+    svg
+}
+
+fn write_shape(svg: &mut String, shape: &Shape) {
+    match shape {
+        Shape::Noop | Shape::Callback(_) => {}
+        Shape::Vec(shapes) => {
+            for shape in shapes {
+                write_shape(svg, shape);
+            }
+        }
+        Shape::Circle(circle) => {
+            let _ = writeln!(
+                svg,
+                r#"<circle cx="{}" cy="{}" r="{}"{}{}/>"#,
+                circle.center.x,
+                circle.center.y,
+                circle.radius,
+                fill(circle.fill),
+                stroke(circle.stroke),
+            );
+        }
+        Shape::Ellipse(ellipse) => {
+            let _ = writeln!(
+                svg,
+                r#"<ellipse cx="{}" cy="{}" rx="{}" ry="{}"{}{}/>"#,
+                ellipse.center.x,
+                ellipse.center.y,
+                ellipse.radius.x,
+                ellipse.radius.y,
+                fill(ellipse.fill),
+                stroke(ellipse.stroke),
+            );
+        }
+        Shape::LineSegment { points, stroke } => {
+            let _ = writeln!(
+                svg,
+                r#"<line x1="{}" y1="{}" x2="{}" y2="{}"{}/>"#,
+                points[0].x,
+                points[0].y,
+                points[1].x,
+                points[1].y,
+                path_stroke(stroke, points),
+            );
+        }
+        Shape::Path(PathShape {
+            points,
+            closed,
+            fill: fill_color,
+            stroke,
+        }) => {
+            let element = if *closed { "polygon" } else { "polyline" };
+            let points: Vec<String> = points.iter().map(|p| format!("{},{}", p.x, p.y)).collect();
+            let _ = writeln!(
+                svg,
+                r#"<{element} points="{}"{}{}/>"#,
+                points.join(" "),
+                fill(*fill_color),
+                path_stroke(stroke, &[]),
+            );
+        }
+        Shape::Rect(RectShape {
+            rect,
+            rounding,
+            fill: fill_color,
+            stroke: rect_stroke,
+            ..
+        }) => {
+            // SVG only supports the same rounding for all corners.
+            let _ = writeln!(
+                svg,
+                r#"<rect x="{}" y="{}" width="{}" height="{}" rx="{}"{}{}/>"#,
+                rect.min.x,
+                rect.min.y,
+                rect.width(),
+                rect.height(),
+                rounding.nw,
+                fill(*fill_color),
+                stroke(*rect_stroke),
+            );
+        }
+        Shape::Text(text) => write_text(svg, text),
+        Shape::Mesh(mesh) => write_mesh(svg, mesh),
+        Shape::QuadraticBezier(QuadraticBezierShape {
+            points: [from, control, to],
+            closed,
+            fill: fill_color,
+            stroke,
+        }) => {
+            let _ = writeln!(
+                svg,
+                r#"<path d="M {} {} Q {} {} {} {}{}"{}{}/>"#,
+                from.x,
+                from.y,
+                control.x,
+                control.y,
+                to.x,
+                to.y,
+                if *closed { " Z" } else { "" },
+                fill(*fill_color),
+                path_stroke(stroke, &[]),
+            );
+        }
+        Shape::CubicBezier(CubicBezierShape {
+            points: [from, control_a, control_b, to],
+            closed,
+            fill: fill_color,
+            stroke,
+        }) => {
+            let _ = writeln!(
+                svg,
+                r#"<path d="M {} {} C {} {} {} {} {} {}{}"{}{}/>"#,
+                from.x,
+                from.y,
+                control_a.x,
+                control_a.y,
+                control_b.x,
+                control_b.y,
+                to.x,
+                to.y,
+                if *closed { " Z" } else { "" },
+                fill(*fill_color),
+                path_stroke(stroke, &[]),
+            );
+        }
+    }
+}
+
+/// Each row of the text becomes a `<text>` element, placed at its baseline.
+fn write_text(svg: &mut String, text: &TextShape) {
+    let galley = &text.galley;
+    let rotation = if text.angle == 0.0 {
+        String::new()
+    } else {
+        format!(
+            r#" transform="rotate({} {} {})""#,
+            text.angle.to_degrees(),
+            text.pos.x,
+            text.pos.y
+        )
+    };
+
+    for row in &galley.rows {
+        let Some(first_glyph) = row.glyphs.first() else {
+            continue;
+        };
+        let string: String = row.glyphs.iter().map(|glyph| glyph.chr).collect();
+        if string.trim().is_empty() {
+            continue;
+        }
+        let Some(section) = galley.job.sections.get(first_glyph.section_index as usize) else {
+            continue;
+        };
+        let font_id = &section.format.font_id;
+        let family = match &font_id.family {
+            FontFamily::Proportional => "sans-serif",
+            FontFamily::Monospace => "monospace",
+            FontFamily::Name(name) => name,
+        };
+        let mut color = text.override_text_color.unwrap_or(section.format.color);
+        if color == Color32::PLACEHOLDER {
+            color = text.fallback_color;
+        }
+        let color = color.gamma_multiply(text.opacity_factor);
+
+        let pos = text.pos + first_glyph.pos.to_vec2();
+        let _ = writeln!(
+            svg,
+            r#"<text x="{}" y="{}" font-family="{}" font-size="{}"{}{rotation}>{}</text>"#,
+            pos.x,
+            pos.y,
+            escape(family),
+            font_id.size,
+            fill(color),
+            escape(&string),
+        );
+    }
+}
+
+/// Triangles of the same color are merged into one path.
+fn write_mesh(svg: &mut String, mesh: &Mesh) {
+    let mut color = None;
+    let mut path = String::new();
+    for triangle in mesh.indices.chunks_exact(3) {
+        let [a, b, c] = [0, 1, 2].map(|i| mesh.vertices[triangle[i] as usize]);
+        if color != Some(a.color) {
+            if let Some(color) = color {
+                let _ = writeln!(svg, r#"<path d="{path}"{}/>"#, fill(color));
+            }
+            path.clear();
+            color = Some(a.color);
+        }
+        let _ = write!(
+            path,
+            "M {} {} L {} {} L {} {} Z ",
+            a.pos.x, a.pos.y, b.pos.x, b.pos.y, c.pos.x, c.pos.y
+        );
+    }
+    if let Some(color) = color {
+        let _ = writeln!(svg, r#"<path d="{path}"{}/>"#, fill(color));
+    }
+}
+
+fn fill(color: Color32) -> String {
+    if color.a() == 0 {
+        r#" fill="none""#.to_owned()
+    } else {
+        let (rgb, opacity) = svg_color(color);
+        format!(r#" fill="{rgb}" fill-opacity="{opacity}""#)
+    }
+}
+
+fn stroke(stroke: Stroke) -> String {
+    if stroke.is_empty() {
+        String::new()
+    } else {
+        let (rgb, opacity) = svg_color(stroke.color);
+        format!(
+            r#" stroke="{rgb}" stroke-opacity="{opacity}" stroke-width="{}""#,
+            stroke.width
+        )
+    }
+}
+
+/// Strokes with a color callback get the color at the first point.
+fn path_stroke(path_stroke: &PathStroke, points: &[Pos2]) -> String {
+    let color = match &path_stroke.color {
+        ColorMode::Solid(color) => *color,
+        ColorMode::UV(callback) => {
+            let pos = points.first().copied().unwrap_or_default();
+            callback(Rect::from_min_max(pos, pos), pos)
+        }
+    };
+    stroke(Stroke::new(path_stroke.width, color))
+}
+
+/// The unmultiplied color and its opacity.
+fn svg_color(color: Color32) -> (String, f32) {
+    let [r, g, b, a] = color.to_srgba_unmultiplied();
+    (format!("rgb({r},{g},{b})"), a as f32 / 255.0)
+}
+
+fn escape(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_shapes_to_svg() {
+        let view_box = Rect::from_min_size(Pos2::ZERO, egui::vec2(100.0, 50.0));
+        let left = Rect::from_min_max(Pos2::ZERO, Pos2::new(50.0, 50.0));
+        let circle = |fill| Shape::circle_filled(Pos2::new(10.0, 10.0), 5.0, fill);
+        let shapes = [
+            ClippedShape {
+                clip_rect: left,
+                shape: circle(Color32::RED),
+            },
+            ClippedShape {
+                clip_rect: left,
+                shape: circle(Color32::TRANSPARENT),
+            },
+            ClippedShape {
+                clip_rect: Rect::EVERYTHING,
+                shape: Shape::line_segment(
+                    [Pos2::ZERO, Pos2::new(100.0, 50.0)],
+                    Stroke::new(2.0, Color32::from_rgba_unmultiplied(0, 0, 255, 51)),
+                ),
+            },
+        ];
+        let svg = shapes_to_svg(view_box, &shapes);
+
+        // Consecutive shapes with the same clip rectangle share a group,
+        // and clip rectangles are limited to the view box:
+        assert_eq!(svg.matches("<clipPath ").count(), 2, "{svg}");
+        assert_eq!(svg.matches("<g clip-path").count(), 2, "{svg}");
+        assert_eq!(svg.matches("</g>").count(), 2, "{svg}");
+        assert!(
+            svg.contains(r#"<rect x="0" y="0" width="100" height="50"/>"#),
+            "{svg}"
+        );
+
+        assert!(
+            svg.contains(r#"fill="rgb(255,0,0)" fill-opacity="1""#),
+            "{svg}"
+        );
+        assert!(svg.contains(r#"fill="none""#), "{svg}");
+        assert!(
+            svg.contains(r#"stroke="rgb(0,0,255)" stroke-opacity="0.2" stroke-width="2""#),
+            "{svg}"
+        );
+    }
+
+    #[test]
+    fn test_escape() {
+        assert_eq!(escape(r#"<a & "b">"#), "&lt;a &amp; &quot;b&quot;&gt;");
+    }
+}
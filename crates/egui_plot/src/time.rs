@@ -0,0 +1,429 @@
+//! Date and time support for [`crate::Plot::x_axis_time`].
+//!
+//! X values are timestamps in seconds since the Unix epoch (1970-01-01 00:00:00 UTC).
+
+use std::ops::RangeInclusive;
+
+use crate::{GridInput, GridMark, GridSpacer};
+
+const MINUTE: f64 = 60.0;
+const HOUR: f64 = 60.0 * MINUTE;
+const DAY: f64 = 24.0 * HOUR;
+
+/// Larger timestamps are not shown as dates (this is roughly ±3 million years).
+const MAX_TIMESTAMP: f64 = 1e14;
+
+const MONTH_NAMES: [&str; 12] = [
+    "Jan", "Feb", "Mar", "Apr", "May", "Jun", "Jul", "Aug", "Sep", "Oct", "Nov", "Dec",
+];
+
+/// In which time zone the timestamps of a time axis are shown.
+///
+/// See [`crate::Plot::x_axis_time`].
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum TimeAxis {
+    /// Show times in UTC.
+    #[default]
+    Utc,
+
+    /// Show local times, which are `utc_offset_secs` ahead of UTC.
+    ///
+    /// The standard library can't look up the time zone, so the offset must be provided,
+    /// e.g. from `chrono::Local::now().offset().local_minus_utc()`.
+    Local { utc_offset_secs: i32 },
+}
+
+impl TimeAxis {
+    fn utc_offset(self) -> f64 {
+        match self {
+            Self::Utc => 0.0,
+            Self::Local { utc_offset_secs } => utc_offset_secs as f64,
+        }
+    }
+
+    fn date_time(self, timestamp: f64) -> DateTime {
+        DateTime::from_secs(timestamp + self.utc_offset())
+    }
+
+    fn timestamp(self, date_time: &DateTime) -> f64 {
+        date_time.to_secs() - self.utc_offset()
+    }
+
+    /// Format a timestamp as e.g. `2024-03-03 14:05:30`, used when hovering the plot.
+    pub fn format_timestamp(self, timestamp: f64) -> String {
+        if !is_date(timestamp) {
+            return timestamp.to_string();
+        }
+        let DateTime {
+            year,
+            month,
+            day,
+            secs_of_day,
+        } = self.date_time(timestamp);
+        let (hour, minute, second) = split_secs_of_day(secs_of_day);
+        format!("{year}-{month:02}-{day:02} {hour:02}:{minute:02}:{second:02}")
+    }
+
+    /// Grid marks at natural time boundaries, e.g. every 15 minutes, every day or every month.
+    pub(crate) fn grid_spacer(self) -> GridSpacer<'static> {
+        Box::new(move |input: GridInput| -> Vec<GridMark> {
+            let (min, max) = input.bounds;
+            if !is_date(min) || !is_date(max) {
+                return Vec::new();
+            }
+
+            // Like the other spacers, we return three levels of grid lines.
+            let fine = TimeStep::for_min_step(input.base_step_size);
+            let medium = fine.coarser_unit();
+            let coarse = medium.coarser_unit();
+
+            let mut marks: Vec<GridMark> = [fine, medium, coarse]
+                .into_iter()
+                .flat_map(|step| {
+                    self.step_marks(step, min, max)
+                        .into_iter()
+                        .map(move |value| GridMark {
+                            value,
+                            step_size: step.nominal_secs(),
+                        })
+                })
+                .collect();
+
+            // Keep the coarsest step for each value:
+            marks.sort_by(|a, b| {
+                a.value
+                    .total_cmp(&b.value)
+                    .then(b.step_size.total_cmp(&a.step_size))
+            });
+            marks.dedup_by(|a, b| a.value == b.value);
+            marks
+        })
+    }
+
+    /// All timestamps in `[min, max]` which are a multiple of `step`.
+    fn step_marks(self, step: TimeStep, min: f64, max: f64) -> Vec<f64> {
+        let mut marks = Vec::new();
+        match step {
+            TimeStep::Seconds(_)
+            | TimeStep::Minutes(_)
+            | TimeStep::Hours(_)
+            | TimeStep::Days(_) => {
+                let step_secs = step.nominal_secs();
+                // Multiples of days start on a Monday, 1970-01-05.
+                let origin = if let TimeStep::Days(_) = step {
+                    4.0 * DAY - self.utc_offset()
+                } else {
+                    -self.utc_offset()
+                };
+                let mut i = ((min - origin) / step_secs).ceil();
+                while origin + i * step_secs <= max {
+                    marks.push(origin + i * step_secs);
+                    i += 1.0;
+                }
+            }
+            TimeStep::Months(months) => {
+                let start = self.date_time(min);
+                let mut month_index = start.year * 12 + (start.month as i64 - 1);
+                month_index -= month_index.rem_euclid(months as i64);
+                loop {
+                    let date_time = DateTime {
+                        year: month_index.div_euclid(12),
+                        month: month_index.rem_euclid(12) as u32 + 1,
+                        day: 1,
+                        secs_of_day: 0.0,
+                    };
+                    let value = self.timestamp(&date_time);
+                    if max < value {
+                        break;
+                    }
+                    if min <= value {
+                        marks.push(value);
+                    }
+                    month_index += months as i64;
+                }
+            }
+            TimeStep::Years(years) => {
+                let mut year = self.date_time(min).year;
+                year -= year.rem_euclid(years as i64);
+                loop {
+                    let value = self.timestamp(&DateTime {
+                        year,
+                        month: 1,
+                        day: 1,
+                        secs_of_day: 0.0,
+                    });
+                    if max < value {
+                        break;
+                    }
+                    if min <= value {
+                        marks.push(value);
+                    }
+                    year += years as i64;
+                }
+            }
+        }
+        marks
+    }
+
+    /// The tick label of a grid mark, e.g. `14:05`, `Mar 3` or `2024`.
+    ///
+    /// The first label of its kind in the visible range gets a second line with the coarser unit,
+    /// e.g. the date for a time of day.
+    pub(crate) fn format_mark(self, mark: GridMark, range: &RangeInclusive<f64>) -> String {
+        if !is_date(mark.value) {
+            return String::new();
+        }
+        let DateTime {
+            year,
+            month,
+            day,
+            secs_of_day,
+        } = self.date_time(mark.value);
+        let (hour, minute, second) = split_secs_of_day(secs_of_day);
+        let month_name = MONTH_NAMES[month as usize - 1];
+
+        let (label, context) = if second != 0 {
+            (
+                format!("{hour:02}:{minute:02}:{second:02}"),
+                Some(format!("{month_name} {day}, {year}")),
+            )
+        } else if hour != 0 || minute != 0 {
+            (
+                format!("{hour:02}:{minute:02}"),
+                Some(format!("{month_name} {day}, {year}")),
+            )
+        } else if day != 1 {
+            (format!("{month_name} {day}"), Some(year.to_string()))
+        } else if month != 1 {
+            (month_name.to_owned(), Some(year.to_string()))
+        } else {
+            (year.to_string(), None)
+        };
+
+        match context {
+            Some(context) if mark.value - mark.step_size < *range.start() => {
+                format!("{label}\n{context}")
+            }
+            _ => label,
+        }
+    }
+}
+
+/// Can the timestamp be shown as a date? Also `false` for NaN.
+fn is_date(timestamp: f64) -> bool {
+    timestamp.abs() < MAX_TIMESTAMP
+}
+
+fn split_secs_of_day(secs_of_day: f64) -> (u32, u32, u32) {
+    let secs = secs_of_day.floor() as u32;
+    (secs / 3600, secs / 60 % 60, secs % 60)
+}
+
+/// The distance between two grid marks on a time axis.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub(crate) enum TimeStep {
+    Seconds(u32),
+    Minutes(u32),
+    Hours(u32),
+    Days(u32),
+    Months(u32),
+    Years(u32),
+}
+
+impl TimeStep {
+    /// All steps up to one year, from fine to coarse.
+    const STEPS: [Self; 25] = [
+        Self::Seconds(1),
+        Self::Seconds(2),
+        Self::Seconds(5),
+        Self::Seconds(10),
+        Self::Seconds(15),
+        Self::Seconds(30),
+        Self::Minutes(1),
+        Self::Minutes(2),
+        Self::Minutes(5),
+        Self::Minutes(10),
+        Self::Minutes(15),
+        Self::Minutes(30),
+        Self::Hours(1),
+        Self::Hours(2),
+        Self::Hours(3),
+        Self::Hours(6),
+        Self::Hours(12),
+        Self::Days(1),
+        Self::Days(2),
+        Self::Days(7),
+        Self::Days(14),
+        Self::Months(1),
+        Self::Months(3),
+        Self::Months(6),
+        Self::Years(1),
+    ];
+
+    /// The smallest natural step which is at least `min_step` seconds.
+    pub(crate) fn for_min_step(min_step: f64) -> Self {
+        if let Some(step) = Self::STEPS
+            .iter()
+            .find(|step| min_step <= step.nominal_secs())
+        {
+            return *step;
+        }
+
+        // 1, 2, 5, 10, 20, 50, … years
+        let min_years = min_step / Self::Years(1).nominal_secs();
+        let mut magnitude: u32 = 1;
+        loop {
+            for factor in [1, 2, 5] {
+                let years = factor * magnitude;
+                if min_years <= years as f64 || u32::MAX / 50 < magnitude {
+                    return Self::Years(years);
+                }
+            }
+            magnitude *= 10;
+        }
+    }
+
+    /// The next coarser unit, e.g. hours for minutes, used for the thicker grid lines.
+    fn coarser_unit(self) -> Self {
+        match self {
+            Self::Seconds(_) => Self::Minutes(1),
+            Self::Minutes(_) => Self::Hours(1),
+            Self::Hours(_) => Self::Days(1),
+            Self::Days(_) => Self::Months(1),
+            Self::Months(_) => Self::Years(1),
+            Self::Years(years) => Self::Years(years.saturating_mul(10)),
+        }
+    }
+
+    /// The approximate length of the step, with months of 30 and years of 365 days.
+    pub(crate) fn nominal_secs(self) -> f64 {
+        match self {
+            Self::Seconds(n) => n as f64,
+            Self::Minutes(n) => n as f64 * MINUTE,
+            Self::Hours(n) => n as f64 * HOUR,
+            Self::Days(n) => n as f64 * DAY,
+            Self::Months(n) => n as f64 * 30.0 * DAY,
+            Self::Years(n) => n as f64 * 365.0 * DAY,
+        }
+    }
+}
+
+/// A date in the proleptic Gregorian calendar, and the time of that day.
+struct DateTime {
+    year: i64,
+
+    /// 1-12
+    month: u32,
+
+    /// 1-31
+    day: u32,
+    secs_of_day: f64,
+}
+
+impl DateTime {
+    fn from_secs(secs: f64) -> Self {
+        let days = (secs / DAY).floor();
+        let (year, month, day) = civil_from_days(days as i64);
+        Self {
+            year,
+            month,
+            day,
+            secs_of_day: secs - days * DAY,
+        }
+    }
+
+    fn to_secs(&self) -> f64 {
+        days_from_civil(self.year, self.month, self.day) as f64 * DAY + self.secs_of_day
+    }
+}
+
+/// Days since 1970-01-01, see <https://howardhinnant.github.io/date_algorithms.html>.
+fn days_from_civil(year: i64, month: u32, day: u32) -> i64 {
+    let year = if month <= 2 { year - 1 } else { year };
+    let era = year.div_euclid(400);
+    let year_of_era = year.rem_euclid(400);
+    let month = month as i64;
+    let day_of_year =
+        (153 * (if month > 2 { month - 3 } else { month + 9 }) + 2) / 5 + day as i64 - 1;
+    let day_of_era = year_of_era * 365 + year_of_era / 4 - year_of_era / 100 + day_of_year;
+    era * 146_097 + day_of_era - 719_468
+}
+
+/// Year, month and day of the given number of days since 1970-01-01.
+fn civil_from_days(days: i64) -> (i64, u32, u32) {
+    let days = days + 719_468;
+    let era = days.div_euclid(146_097);
+    let day_of_era = days.rem_euclid(146_097);
+    let year_of_era =
+        (day_of_era - day_of_era / 1460 + day_of_era / 36524 - day_of_era / 146_096) / 365;
+    let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+    let mp = (5 * day_of_year + 2) / 153;
+    let day = (day_of_year - (153 * mp + 2) / 5 + 1) as u32;
+    let month = if mp < 10 { mp + 3 } else { mp - 9 } as u32;
+    let year = year_of_era + era * 400 + i64::from(month <= 2);
+    (year, month, day)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_time_axis() {
+        // About eight labels across the visible span:
+        let step_for_span = |span: f64| TimeStep::for_min_step(span / 8.0);
+        assert_eq!(step_for_span(90.0), TimeStep::Seconds(15));
+        assert_eq!(step_for_span(3.0 * 3600.0), TimeStep::Minutes(30));
+        assert_eq!(step_for_span(40.0 * 86400.0), TimeStep::Days(7));
+        assert_eq!(step_for_span(5.0 * 365.0 * 86400.0), TimeStep::Years(1));
+        assert_eq!(step_for_span(300.0 * 365.0 * 86400.0), TimeStep::Years(50));
+
+        let time_axis = TimeAxis::Utc;
+        assert_eq!(
+            time_axis.format_timestamp(1_709_474_730.0),
+            "2024-03-03 14:05:30"
+        );
+        assert_eq!(
+            TimeAxis::Local {
+                utc_offset_secs: -3600
+            }
+            .format_timestamp(1_709_474_730.0),
+            "2024-03-03 13:05:30"
+        );
+
+        // 40 days from 2024-02-20: weeks start on Mondays, and months are coarser marks.
+        let feb_20 = 1_708_387_200.0;
+        let bounds = (feb_20, feb_20 + 40.0 * 86400.0);
+        let marks = (time_axis.grid_spacer())(GridInput {
+            bounds,
+            base_step_size: 5.0 * 86400.0,
+        });
+        let week = 7.0 * 86400.0;
+        let month = 30.0 * 86400.0;
+        let feb_26 = 1_708_905_600.0;
+        let mar_1 = 1_709_251_200.0;
+        assert_eq!(marks[0].value, feb_26);
+        assert_eq!(marks[0].step_size, week);
+        assert_eq!(marks[1].value, mar_1);
+        assert_eq!(marks[1].step_size, month);
+        assert_eq!(marks[2].value, feb_26 + week);
+
+        let range = bounds.0..=bounds.1;
+        let label = |mark: &GridMark| time_axis.format_mark(*mark, &range);
+        assert_eq!(label(&marks[0]), "Feb 26\n2024");
+        assert_eq!(label(&marks[1]), "Mar\n2024");
+        assert_eq!(label(&marks[2]), "Mar 4");
+    }
+
+    #[test]
+    fn test_civil_days() {
+        assert_eq!(days_from_civil(1970, 1, 1), 0);
+        assert_eq!(days_from_civil(2024, 3, 3), 19_785);
+        assert_eq!(days_from_civil(1969, 12, 31), -1);
+        for days in [-719_468, -1, 0, 59, 19_785, 2_932_896] {
+            let (year, month, day) = civil_from_days(days);
+            assert_eq!(days_from_civil(year, month, day), days);
+        }
+        assert_eq!(civil_from_days(19_782), (2024, 2, 29));
+    }
+}
@@ -261,7 +261,63 @@ impl PlotBounds {
     }
 }
 
+impl emath::Lerp for PlotBounds {
+    fn lerp(start: Self, end: Self, t: f32) -> Self {
+        let t = t as f64;
+        Self {
+            min: [
+                emath::lerp(start.min[0]..=end.min[0], t),
+                emath::lerp(start.min[1]..=end.min[1], t),
+            ],
+            max: [
+                emath::lerp(start.max[0]..=end.max[0], t),
+                emath::lerp(start.max[1]..=end.max[1], t),
+            ],
+        }
+    }
+}
+
+/// How the data values along an axis are mapped to the screen.
+///
+/// See [`crate::Plot::x_axis_scale`] and [`crate::Plot::y_axis_scale`].
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub enum AxisScale {
+    /// Equal distances on screen are equal differences in value.
+    #[default]
+    Linear,
+
+    /// Equal distances on screen are equal ratios of value, e.g. one step per decade.
+    ///
+    /// Values below `min`, including zero and negative values, are clamped to `min`.
+    /// `min` must be positive.
+    Log10 { min: f64 },
+}
+
+impl AxisScale {
+    /// Map a data value to the space the plot bounds are in.
+    ///
+    /// This is the identity for [`Self::Linear`] and `log10` for [`Self::Log10`].
+    pub fn scaled(self, value: f64) -> f64 {
+        match self {
+            Self::Linear => value,
+            Self::Log10 { min } => value.max(min.max(f64::MIN_POSITIVE)).log10(),
+        }
+    }
+
+    /// The inverse of [`Self::scaled`].
+    pub fn unscaled(self, value: f64) -> f64 {
+        match self {
+            Self::Linear => value,
+            Self::Log10 { .. } => 10.0_f64.powf(value),
+        }
+    }
+}
+
 /// Contains the screen rectangle and the plot bounds and provides methods to transform between them.
+///
+/// A transform is only valid for the frame it was produced in: the next frame the plot may
+/// have moved, been resized, zoomed or panned.
 #[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
 #[derive(Clone, Copy, Debug)]
 pub struct PlotTransform {
@@ -276,6 +332,10 @@ pub struct PlotTransform {
 
     /// Whether to always center the y-range of the bounds.
     y_centered: bool,
+
+    /// How data values are mapped to the bounds, per axis.
+    #[cfg_attr(feature = "serde", serde(default))]
+    axis_scales: [AxisScale; 2],
 }
 
 impl PlotTransform {
@@ -338,6 +398,7 @@ impl PlotTransform {
             bounds: new_bounds,
             x_centered,
             y_centered,
+            axis_scales: [AxisScale::Linear; 2],
         }
     }
 
@@ -348,11 +409,57 @@ impl PlotTransform {
     }
 
     /// Plot-space bounds.
+    ///
+    /// On an axis with [`AxisScale::Log10`] these are the `log10` of the data values,
+    /// see [`Self::data_bounds`].
     #[inline]
     pub fn bounds(&self) -> &PlotBounds {
         &self.bounds
     }
 
+    /// The bounds in data values, i.e. [`Self::bounds`] with [`AxisScale::unscaled`] applied.
+    pub fn data_bounds(&self) -> PlotBounds {
+        self.map_bounds(&self.bounds, AxisScale::unscaled)
+    }
+
+    /// Convert bounds in data values to plot-space bounds, see [`Self::bounds`].
+    pub fn bounds_from_data(&self, data_bounds: &PlotBounds) -> PlotBounds {
+        self.map_bounds(data_bounds, AxisScale::scaled)
+    }
+
+    fn map_bounds(&self, bounds: &PlotBounds, f: fn(AxisScale, f64) -> f64) -> PlotBounds {
+        let mut result = *bounds;
+        for axis in 0..2 {
+            // Leave empty/infinite axes alone, e.g. of `PlotBounds::NOTHING`:
+            if bounds.min[axis].is_finite() && bounds.max[axis].is_finite() {
+                result.min[axis] = f(self.axis_scales[axis], bounds.min[axis]);
+                result.max[axis] = f(self.axis_scales[axis], bounds.max[axis]);
+            }
+        }
+        result
+    }
+
+    /// How data values are mapped to the X and Y axes.
+    #[inline]
+    pub fn axis_scales(&self) -> [AxisScale; 2] {
+        self.axis_scales
+    }
+
+    /// Set the axis scales, keeping [`Self::bounds`] as they are.
+    pub(crate) fn with_axis_scales(mut self, axis_scales: [AxisScale; 2]) -> Self {
+        self.axis_scales = axis_scales;
+        self
+    }
+
+    /// Change the axis scales, keeping the same data bounds (as far as the new scales allow).
+    pub(crate) fn set_axis_scales(&mut self, axis_scales: [AxisScale; 2]) {
+        if self.axis_scales != axis_scales {
+            let data_bounds = self.data_bounds();
+            self.axis_scales = axis_scales;
+            self.bounds = self.bounds_from_data(&data_bounds);
+        }
+    }
+
     #[inline]
     pub fn set_bounds(&mut self, bounds: PlotBounds) {
         self.bounds = bounds;
@@ -372,7 +479,7 @@ impl PlotTransform {
 
     /// Zoom by a relative factor with the given screen position as center.
     pub fn zoom(&mut self, zoom_factor: Vec2, center: Pos2) {
-        let center = self.value_from_position(center);
+        let center = self.scaled_value_from_position(center);
 
         let mut new_bounds = self.bounds;
         new_bounds.zoom(zoom_factor, center);
@@ -382,17 +489,19 @@ impl PlotTransform {
         }
     }
 
+    /// Screen/ui x position from an x value on the plot.
     pub fn position_from_point_x(&self, value: f64) -> f32 {
         remap(
-            value,
+            self.axis_scales[0].scaled(value),
             self.bounds.min[0]..=self.bounds.max[0],
             (self.frame.left() as f64)..=(self.frame.right() as f64),
         ) as f32
     }
 
+    /// Screen/ui y position from a y value on the plot.
     pub fn position_from_point_y(&self, value: f64) -> f32 {
         remap(
-            value,
+            self.axis_scales[1].scaled(value),
             self.bounds.min[1]..=self.bounds.max[1],
             (self.frame.bottom() as f64)..=(self.frame.top() as f64), // negated y axis!
         ) as f32
@@ -408,6 +517,23 @@ impl PlotTransform {
 
     /// Plot point from screen/ui position.
     pub fn value_from_position(&self, pos: Pos2) -> PlotPoint {
+        let scaled = self.scaled_value_from_position(pos);
+        PlotPoint::new(
+            self.axis_scales[0].unscaled(scaled.x),
+            self.axis_scales[1].unscaled(scaled.y),
+        )
+    }
+
+    /// Plot point from screen/ui position, the inverse of [`Self::position_from_point`].
+    ///
+    /// Same as [`Self::value_from_position`].
+    #[inline]
+    pub fn point_from_position(&self, pos: Pos2) -> PlotPoint {
+        self.value_from_position(pos)
+    }
+
+    /// Like [`Self::value_from_position`], but in the space of [`Self::bounds`].
+    fn scaled_value_from_position(&self, pos: Pos2) -> PlotPoint {
         let x = remap(
             pos.x as f64,
             (self.frame.left() as f64)..=(self.frame.right() as f64),
@@ -2,14 +2,90 @@
 //!
 //! Contains most easing functions from <https://easings.net/>.
 //!
-//! All functions take a value in `[0, 1]` and return a value in `[0, 1]`.
+//! All functions take a value in `[0, 1]` and return a value in `[0, 1]`,
+//! except for [`back_in`], [`back_out`] and [`back_in_out`] which overshoot (see [`output_range`]).
 //!
 //! Derived from <https://github.com/warrenm/AHEasing/blob/master/AHEasing/easing.c>.
-use std::f32::consts::PI;
 
-#[inline]
-fn powf(base: f32, exp: f32) -> f32 {
-    base.powf(exp)
+#![allow(clippy::needless_pass_by_value)] // Curves are taken by value, like closures usually are.
+
+use core::f32::consts::{FRAC_PI_2, PI};
+use core::ops::RangeInclusive;
+
+#[cfg(not(feature = "std"))]
+use alloc::{sync::Arc, vec, vec::Vec};
+#[cfg(feature = "std")]
+use std::sync::Arc;
+
+use self::float::{abs, asin, cos, powf, rem_euclid, signum, sin, sqrt};
+
+/// The float functions we need, from `std` or from `libm` in `no_std` builds.
+#[cfg(feature = "std")]
+mod float {
+    #[inline]
+    pub fn powf(base: f32, exp: f32) -> f32 {
+        base.powf(exp)
+    }
+
+    #[inline]
+    pub fn sin(x: f32) -> f32 {
+        x.sin()
+    }
+
+    #[inline]
+    pub fn cos(x: f32) -> f32 {
+        x.cos()
+    }
+
+    #[inline]
+    pub fn asin(x: f32) -> f32 {
+        x.asin()
+    }
+
+    #[inline]
+    pub fn sqrt(x: f32) -> f32 {
+        x.sqrt()
+    }
+
+    #[inline]
+    pub fn abs(x: f32) -> f32 {
+        x.abs()
+    }
+
+    #[inline]
+    pub fn signum(x: f32) -> f32 {
+        x.signum()
+    }
+
+    #[inline]
+    pub fn rem_euclid(x: f32, y: f32) -> f32 {
+        x.rem_euclid(y)
+    }
+}
+
+/// The float functions we need, from `std` or from `libm` in `no_std` builds.
+#[cfg(not(feature = "std"))]
+mod float {
+    pub use libm::{asinf as asin, cosf as cos, fabsf as abs, powf, sinf as sin, sqrtf as sqrt};
+
+    #[inline]
+    pub fn signum(x: f32) -> f32 {
+        if x.is_nan() {
+            f32::NAN
+        } else {
+            libm::copysignf(1.0, x)
+        }
+    }
+
+    #[inline]
+    pub fn rem_euclid(x: f32, y: f32) -> f32 {
+        let r = x % y;
+        if r < 0.0 {
+            r + abs(y)
+        } else {
+            r
+        }
+    }
 }
 
 /// No easing, just `y = x`
@@ -18,6 +94,37 @@ pub fn linear(t: f32) -> f32 {
     t
 }
 
+/// Classic smoothstep: `y = 3x^2 - 2x^3`
+///
+/// Has zero slope at both ends. Same as [`crate::ease_in_ease_out`], but without clamping.
+#[inline]
+pub fn smoothstep(t: f32) -> f32 {
+    t * t * (3. - 2. * t)
+}
+
+/// Inverse of [`smoothstep`], for `y` in `[0, 1]`.
+#[inline]
+pub fn smoothstep_inverse(y: f32) -> f32 {
+    0.5 - sin(asin(1. - 2. * y) / 3.)
+}
+
+/// Ken Perlin's smootherstep: `y = 6x^5 - 15x^4 + 10x^3`
+///
+/// Has zero slope and zero curvature at both ends.
+///
+/// There is no closed-form inverse; use [`inverse_numeric`].
+#[inline]
+pub fn smootherstep(t: f32) -> f32 {
+    // The polynomial loses precision near 1 (it even overshoots it),
+    // so evaluate the upper half by symmetry.
+    let half = |t: f32| t * t * t * (t * (6. * t - 15.) + 10.);
+    if t <= 0.5 {
+        half(t)
+    } else {
+        1. - half(1. - t)
+    }
+}
+
 /// <https://easings.net/#easeInQuad>
 ///
 /// Modeled after the parabola `y = x^2`
@@ -70,12 +177,66 @@ pub fn cubic_in_out(t: f32) -> f32 {
     }
 }
 
+/// `y = x^n`, a generalization of [`quadratic_in`] (`n = 2`) and [`cubic_in`] (`n = 3`).
+///
+/// `n` must be positive. Large `n` gives a sharp ramp at the end,
+/// `n < 1` starts fast instead.
+///
+/// A closure with a constant `n` can be passed anywhere an easing function is expected:
+/// ```
+/// # use emath::{easing, Tween};
+/// let tween = Tween::new(0.0_f32, 1.0, 1.0).with_easing(|t| easing::poly_in(t, 8.0));
+/// ```
+#[inline]
+pub fn poly_in(t: f32, n: f32) -> f32 {
+    debug_assert!(0.0 < n, "n must be positive, got {n}");
+    powf(t, n)
+}
+
+/// Same as `1.0 - poly_in(1.0 - t, n)`. See [`poly_in`].
+#[inline]
+pub fn poly_out(t: f32, n: f32) -> f32 {
+    1. - poly_in(1. - t, n)
+}
+
+/// [`poly_in`] for the first half, [`poly_out`] for the second half.
+#[inline]
+pub fn poly_in_out(t: f32, n: f32) -> f32 {
+    if t < 0.5 {
+        0.5 * poly_in(2. * t, n)
+    } else {
+        0.5 * poly_out(2. * t - 1., n) + 0.5
+    }
+}
+
+/// Inverse of [`poly_in`], for `y` in `[0, 1]`.
+#[inline]
+pub fn poly_in_inverse(y: f32, n: f32) -> f32 {
+    poly_in(y, 1. / n)
+}
+
+/// Inverse of [`poly_out`], for `y` in `[0, 1]`.
+#[inline]
+pub fn poly_out_inverse(y: f32, n: f32) -> f32 {
+    poly_out(y, 1. / n)
+}
+
+/// Inverse of [`poly_in_out`], for `y` in `[0, 1]`.
+#[inline]
+pub fn poly_in_out_inverse(y: f32, n: f32) -> f32 {
+    if y < 0.5 {
+        0.5 * poly_in_inverse(2. * y, n)
+    } else {
+        0.5 * poly_out_inverse(2. * y - 1., n) + 0.5
+    }
+}
+
 /// <https://easings.net/#easeInSine>
 ///
 /// Modeled after quarter-cycle of sine wave
 #[inline]
 pub fn sin_in(t: f32) -> f32 {
-    ((t - 1.) * 2. * PI).sin() + 1.
+    sin((t - 1.) * FRAC_PI_2) + 1.
 }
 
 /// <https://easings.net/#easeOuSine>
@@ -83,7 +244,7 @@ pub fn sin_in(t: f32) -> f32 {
 /// Modeled after quarter-cycle of sine wave (different phase)
 #[inline]
 pub fn sin_out(t: f32) -> f32 {
-    (t * 2. * PI).sin()
+    sin(t * FRAC_PI_2)
 }
 
 /// <https://easings.net/#easeInOutSine>
@@ -91,7 +252,7 @@ pub fn sin_out(t: f32) -> f32 {
 /// Modeled after half sine wave
 #[inline]
 pub fn sin_in_out(t: f32) -> f32 {
-    0.5 * (1. - (t * PI).cos())
+    0.5 * (1. - cos(t * PI))
 }
 
 /// <https://easings.net/#easeInCirc>
@@ -99,7 +260,7 @@ pub fn sin_in_out(t: f32) -> f32 {
 /// Modeled after shifted quadrant IV of unit circle
 #[inline]
 pub fn circular_in(t: f32) -> f32 {
-    1. - (1. - t * t).sqrt()
+    1. - sqrt(1. - t * t)
 }
 
 /// <https://easings.net/#easeOutCirc>
@@ -107,16 +268,16 @@ pub fn circular_in(t: f32) -> f32 {
 /// Modeled after shifted quadrant II of unit circle
 #[inline]
 pub fn circular_out(t: f32) -> f32 {
-    (2. - t).sqrt() * t
+    sqrt(2. - t) * t
 }
 
 /// <https://easings.net/#easeInOutCirc>
 #[inline]
 pub fn circular_in_out(t: f32) -> f32 {
     if t < 0.5 {
-        0.5 * (1. - (1. - 4. * t * t).sqrt())
+        0.5 * (1. - sqrt(1. - 4. * t * t))
     } else {
-        0.5 * ((-(2. * t - 3.) * (2. * t - 1.)).sqrt() + 1.)
+        0.5 * (sqrt(-(2. * t - 3.) * (2. * t - 1.)) + 1.)
     }
 }
 
@@ -154,21 +315,21 @@ pub fn exponential_in_out(t: f32) -> f32 {
     } else if t < 0.5 {
         0.5 * powf(2.0, 20. * t - 10.)
     } else {
-        0.5 * powf(2.0, -20. * t + 10.) + 1.
+        -0.5 * powf(2.0, -20. * t + 10.) + 1.
     }
 }
 
 /// <https://easings.net/#easeInBack>
 #[inline]
 pub fn back_in(t: f32) -> f32 {
-    t * t * t - t * (t * PI).sin()
+    t * t * t - t * sin(t * PI)
 }
 
 /// <https://easings.net/#easeOutBack>
 #[inline]
 pub fn back_out(t: f32) -> f32 {
     let f = 1. - t;
-    1. - (f * f * f - f * (f * PI).sin())
+    1. - (f * f * f - f * sin(f * PI))
 }
 
 /// <https://easings.net/#easeInOutBack>
@@ -176,10 +337,10 @@ pub fn back_out(t: f32) -> f32 {
 pub fn back_in_out(t: f32) -> f32 {
     if t < 0.5 {
         let f = 2. * t;
-        0.5 * (f * f * f - f * (f * PI).sin())
+        0.5 * (f * f * f - f * sin(f * PI))
     } else {
         let f = 1. - (2. * t - 1.);
-        0.5 * (1. - (f * f * f - f * (f * PI).sin())) + 0.5
+        0.5 * (1. - (f * f * f - f * sin(f * PI))) + 0.5
     }
 }
 
@@ -196,24 +357,19 @@ pub fn bounce_in(t: f32) -> f32 {
 /// Each bounce is modelled as a parabola.
 #[inline]
 pub fn bounce_out(t: f32) -> f32 {
-    if t < 4. / 11. {
-        const T2: f32 = 121. / 16.;
-        T2 * t * t
-    } else if t < 8. / 11. {
-        const T2: f32 = 363. / 40.;
-        const T1: f32 = -99. / 10.;
-        const T0: f32 = 17. / 5.;
-        T2 * t * t + T1 * t + T0
-    } else if t < 9. / 10. {
-        const T2: f32 = 4356. / 361.;
-        const T1: f32 = -35442. / 1805.;
-        const T0: f32 = 16061. / 1805.;
-        T2 * t * t + T1 * t + T0
+    const N1: f32 = 7.5625;
+    const D1: f32 = 2.75;
+    if t < 1. / D1 {
+        N1 * t * t
+    } else if t < 2. / D1 {
+        let t = t - 1.5 / D1;
+        N1 * t * t + 0.75
+    } else if t < 2.5 / D1 {
+        let t = t - 2.25 / D1;
+        N1 * t * t + 0.9375
     } else {
-        const T2: f32 = 54. / 5.;
-        const T1: f32 = -513. / 25.;
-        const T0: f32 = 268. / 25.;
-        T2 * t * t + T1 * t + T0
+        let t = t - 2.625 / D1;
+        N1 * t * t + 0.984_375
     }
 }
 
@@ -228,3 +384,833 @@ pub fn bounce_in_out(t: f32) -> f32 {
         0.5 * bounce_out(t * 2. - 1.) + 0.5
     }
 }
+
+/// The first half follows `out` scaled to `[0, 0.5]`, the second half follows `in_`.
+///
+/// This gives a fast-slow-fast curve, the opposite of the `_in_out` variants.
+#[inline]
+fn out_in(out: fn(f32) -> f32, in_: fn(f32) -> f32, t: f32) -> f32 {
+    if t < 0.5 {
+        0.5 * out(2. * t)
+    } else {
+        0.5 * in_(2. * t - 1.) + 0.5
+    }
+}
+
+/// [`quadratic_out`] then [`quadratic_in`]: fast-slow-fast.
+#[inline]
+pub fn quadratic_out_in(t: f32) -> f32 {
+    out_in(quadratic_out, quadratic_in, t)
+}
+
+/// [`cubic_out`] then [`cubic_in`]: fast-slow-fast.
+#[inline]
+pub fn cubic_out_in(t: f32) -> f32 {
+    out_in(cubic_out, cubic_in, t)
+}
+
+/// [`sin_out`] then [`sin_in`]: fast-slow-fast.
+#[inline]
+pub fn sin_out_in(t: f32) -> f32 {
+    out_in(sin_out, sin_in, t)
+}
+
+/// [`circular_out`] then [`circular_in`]: fast-slow-fast.
+#[inline]
+pub fn circular_out_in(t: f32) -> f32 {
+    out_in(circular_out, circular_in, t)
+}
+
+/// [`exponential_out`] then [`exponential_in`]: fast-slow-fast.
+#[inline]
+pub fn exponential_out_in(t: f32) -> f32 {
+    out_in(exponential_out, exponential_in, t)
+}
+
+/// [`back_out`] then [`back_in`]: fast-slow-fast.
+#[inline]
+pub fn back_out_in(t: f32) -> f32 {
+    out_in(back_out, back_in, t)
+}
+
+/// [`bounce_out`] then [`bounce_in`]: fast-slow-fast.
+#[inline]
+pub fn bounce_out_in(t: f32) -> f32 {
+    out_in(bounce_out, bounce_in, t)
+}
+
+/// Something that maps `[0, 1]` to (usually) `[0, 1]`, like the easing functions in this module.
+///
+/// This is implemented for all functions and closures taking and returning an `f32`,
+/// as well as for [`EasingTable`] and [`BoxedCurve`],
+/// so functions taking an `impl Curve` accept any of them.
+///
+/// ```
+/// # use emath::easing::{self, Curve};
+/// fn midpoint(curve: impl Curve) -> f32 {
+///     curve.apply(0.5)
+/// }
+/// assert_eq!(midpoint(easing::quadratic_in), 0.25);
+/// assert_eq!(midpoint(|t: f32| t * t), 0.25);
+/// ```
+pub trait Curve {
+    /// The eased value at `t`.
+    ///
+    /// `t` is usually in `[0, 1]`, where `0` maps to `0` and `1` maps to `1`.
+    /// Outside of that range the result is whatever the curve extrapolates to,
+    /// see [`apply_clamped`] and [`apply_with_policy`].
+    fn apply(&self, t: f32) -> f32;
+}
+
+impl<F: Fn(f32) -> f32> Curve for F {
+    #[inline]
+    fn apply(&self, t: f32) -> f32 {
+        self(t)
+    }
+}
+
+/// A shared, type-erased [`Curve`], for when you need to store a curve in e.g. a settings struct.
+///
+/// Cheap to clone.
+#[derive(Clone)]
+pub struct BoxedCurve(Arc<dyn Curve + Send + Sync>);
+
+impl BoxedCurve {
+    pub fn new(curve: impl Curve + Send + Sync + 'static) -> Self {
+        Self(Arc::new(curve))
+    }
+}
+
+impl Curve for BoxedCurve {
+    #[inline]
+    fn apply(&self, t: f32) -> f32 {
+        self.0.apply(t)
+    }
+}
+
+impl core::fmt::Debug for BoxedCurve {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.write_str("BoxedCurve")
+    }
+}
+
+/// How to treat inputs outside of `[0, 1]` in [`apply_with_policy`].
+///
+/// The easing functions themselves assume `0 <= t <= 1`,
+/// and can produce surprising values outside that range
+/// (e.g. `quadratic_in(-0.5)` is positive).
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub enum OutOfRange {
+    /// Clamp `t` to `[0, 1]` (default).
+    #[default]
+    Clamp,
+
+    /// Evaluate the easing function as-is outside of `[0, 1]`.
+    Extend,
+
+    /// Reflect `t` back into `[0, 1]`, so that `1.2` becomes `0.8` and `2.1` becomes `0.1`.
+    ///
+    /// Useful for ping-pong animations.
+    Mirror,
+}
+
+/// Apply the easing function with `t` clamped to `[0, 1]`.
+///
+/// Use this when `t` comes from a timer that may overshoot.
+#[inline]
+pub fn apply_clamped(easing: impl Curve, t: f32) -> f32 {
+    easing.apply(t.clamp(0.0, 1.0))
+}
+
+/// Apply the easing function, treating a `t` outside of `[0, 1]` according to `policy`.
+#[inline]
+pub fn apply_with_policy(easing: impl Curve, t: f32, policy: OutOfRange) -> f32 {
+    match policy {
+        OutOfRange::Clamp => apply_clamped(easing, t),
+        OutOfRange::Extend => easing.apply(t),
+        OutOfRange::Mirror => {
+            let t = rem_euclid(t, 2.0);
+            easing.apply(if 1.0 < t { 2.0 - t } else { t })
+        }
+    }
+}
+
+/// Apply the easing function to every value in `input`, writing the results to `output`.
+///
+/// Pass the easing function itself (e.g. `cubic_out`) rather than a `fn` pointer
+/// to get a tight, inlined loop.
+///
+/// # Panics
+/// If `input` and `output` have different lengths.
+#[inline]
+pub fn apply_slice(easing: impl Curve, input: &[f32], output: &mut [f32]) {
+    assert_eq!(input.len(), output.len());
+    for (out, &t) in output.iter_mut().zip(input) {
+        *out = easing.apply(t);
+    }
+}
+
+/// Evaluate the easing function at `n` evenly spaced points in `[0, 1]`, including both ends.
+///
+/// ```
+/// # use emath::easing::{sample, quadratic_in};
+/// assert_eq!(sample(quadratic_in, 3), vec![0.0, 0.25, 1.0]);
+/// ```
+pub fn sample(easing: impl Curve, n: usize) -> Vec<f32> {
+    match n {
+        0 => vec![],
+        1 => vec![easing.apply(0.0)],
+        _ => {
+            let last = (n - 1) as f32;
+            (0..n)
+                .map(|i| {
+                    // Make sure we hit 1.0 exactly:
+                    let t = if i == n - 1 { 1.0 } else { i as f32 / last };
+                    easing.apply(t)
+                })
+                .collect()
+        }
+    }
+}
+
+/// The slope of the easing function at `t`, i.e. `d easing(t) / dt`.
+///
+/// Useful for velocity-matched transitions, e.g. handing off from a drag to an animated settle.
+///
+/// This uses finite differences with a step of `1/128`, refined by Richardson extrapolation.
+/// They are central, except within a step of the ends of `[0, 1]` where they are one-sided,
+/// so it works for any easing function (including [`back_out`] and [`bounce_out`])
+/// and is accurate to about `1e-4` for the smooth ones.
+/// At a kink (like the bounces of [`bounce_out`]) it gives roughly the average of the two slopes.
+///
+/// ```
+/// # use emath::easing::{derivative, quadratic_in};
+/// assert!((derivative(quadratic_in, 0.5) - 1.0).abs() < 1e-4);
+/// ```
+pub fn derivative(easing: impl Curve, t: f32) -> f32 {
+    const H: f32 = 1.0 / 128.0;
+    let t = t.clamp(0.0, 1.0);
+    let f = |t: f32| easing.apply(t);
+
+    // Both steps use the same kind of difference, so that extrapolation cancels their errors.
+    let central = H <= t && t + H <= 1.0;
+    let forward = t + 2.0 * H <= 1.0;
+    let difference = |h: f32| {
+        if central {
+            (f(t + h) - f(t - h)) / (2.0 * h)
+        } else if forward {
+            (-3.0 * f(t) + 4.0 * f(t + h) - f(t + 2.0 * h)) / (2.0 * h)
+        } else {
+            (3.0 * f(t) - 4.0 * f(t - h) + f(t - 2.0 * h)) / (2.0 * h)
+        }
+    };
+    (4.0 * difference(H / 2.0) - difference(H)) / 3.0
+}
+
+/// A precomputed easing curve, for when the same easing is evaluated many times per frame.
+///
+/// The curve is sampled once into a table, and [`Self::apply`] linearly interpolates between samples.
+/// Use [`Self::max_error`] to pick a resolution that is good enough.
+///
+/// ```
+/// # use emath::easing::{EasingTable, sin_in_out};
+/// let table = EasingTable::new(sin_in_out, 64);
+/// assert!(table.max_error() < 1e-3);
+/// assert!((table.apply(0.3) - sin_in_out(0.3)).abs() <= table.max_error());
+/// ```
+#[derive(Clone, Debug)]
+pub struct EasingTable {
+    /// `values[i] = easing(i / (values.len() - 1))`
+    values: Vec<f32>,
+
+    max_error: f32,
+}
+
+impl EasingTable {
+    /// Sample `easing` at `resolution` evenly spaced points, including both ends.
+    ///
+    /// `resolution` is at least 2.
+    pub fn new(easing: impl Curve, resolution: usize) -> Self {
+        // How many points between each pair of samples we check when estimating the error.
+        const ERROR_PROBES: usize = 16;
+
+        let mut table = Self {
+            values: sample(|t| easing.apply(t), resolution.max(2)),
+            max_error: 0.0,
+        };
+
+        let num_probes = (table.values.len() - 1) * ERROR_PROBES;
+        table.max_error = (0..=num_probes)
+            .map(|i| {
+                let t = i as f32 / num_probes as f32;
+                abs(table.apply(t) - easing.apply(t))
+            })
+            .fold(0.0, f32::max);
+        table
+    }
+
+    /// Look up the eased value of `t`, which is clamped to `[0, 1]`.
+    #[inline]
+    pub fn apply(&self, t: f32) -> f32 {
+        let last = self.values.len() - 1;
+        let x = t.clamp(0.0, 1.0) * last as f32;
+        let i = (x as usize).min(last - 1);
+        let f = x - i as f32;
+        crate::lerp(self.values[i]..=self.values[i + 1], f)
+    }
+
+    /// The largest difference from the real easing function that was found when building the table.
+    ///
+    /// This is measured at a number of points between each sample,
+    /// so it is an estimate, but a close one for all easings in this module.
+    #[inline]
+    pub fn max_error(&self) -> f32 {
+        self.max_error
+    }
+
+    /// Number of samples in the table.
+    #[inline]
+    pub fn resolution(&self) -> usize {
+        self.values.len()
+    }
+
+    /// The smallest and largest output of [`Self::apply`].
+    ///
+    /// Unlike [`output_range`], this is exact, and only looks at the samples.
+    pub fn output_range(&self) -> RangeInclusive<f32> {
+        let min = self.values.iter().copied().fold(f32::INFINITY, f32::min);
+        let max = self
+            .values
+            .iter()
+            .copied()
+            .fold(f32::NEG_INFINITY, f32::max);
+        min..=max
+    }
+}
+
+impl Curve for EasingTable {
+    #[inline]
+    fn apply(&self, t: f32) -> f32 {
+        Self::apply(self, t)
+    }
+}
+
+/// The smallest and largest output of the easing function for `t` in `[0, 1]`.
+///
+/// For most easings this is `0.0..=1.0`, but e.g. [`back_in`] dips below zero
+/// and [`back_out`] shoots past one.
+///
+/// This is found by sampling the curve and refining around the extremes,
+/// so it is accurate to about `1e-6`.
+///
+/// That takes about 1100 evaluations of the curve, so compute it once and keep it,
+/// rather than calling this every frame.
+/// For an [`EasingTable`], [`EasingTable::output_range`] is exact and much cheaper.
+///
+/// ```
+/// # use emath::easing::{back_in, cubic_out, output_range};
+/// assert_eq!(output_range(cubic_out), 0.0..=1.0);
+/// assert!(*output_range(back_in).start() < -0.2);
+/// ```
+pub fn output_range(easing: impl Curve) -> RangeInclusive<f32> {
+    const NUM_SAMPLES: usize = 1024;
+
+    let mut min = (0.0, easing.apply(0.0));
+    let mut max = min;
+    for i in 1..=NUM_SAMPLES {
+        let t = i as f32 / NUM_SAMPLES as f32;
+        let y = easing.apply(t);
+        if y < min.1 {
+            min = (t, y);
+        }
+        if max.1 < y {
+            max = (t, y);
+        }
+    }
+
+    // Golden-section search in the neighborhood of a sampled extreme.
+    let refine = |(t, y): (f32, f32), sign: f32| -> f32 {
+        const INV_PHI: f32 = 0.618_034;
+        let h = 1.0 / NUM_SAMPLES as f32;
+        let (mut lo, mut hi) = ((t - h).max(0.0), (t + h).min(1.0));
+        let mut best = y;
+        for _ in 0..32 {
+            let a = hi - INV_PHI * (hi - lo);
+            let b = lo + INV_PHI * (hi - lo);
+            let (ya, yb) = (easing.apply(a), easing.apply(b));
+            best = if sign * ya < sign * best { ya } else { best };
+            best = if sign * yb < sign * best { yb } else { best };
+            if sign * ya < sign * yb {
+                hi = b;
+            } else {
+                lo = a;
+            }
+        }
+        best
+    };
+
+    refine(min, 1.0)..=refine(max, -1.0)
+}
+
+/// Does the easing function go outside of `[0, 1]` for any `t` in `[0, 1]`?
+///
+/// Of the easings in this module, only [`back_in`], [`back_out`] and [`back_in_out`] do.
+/// All others are safe to use directly for e.g. opacity or color channels.
+///
+/// Deviations smaller than `1e-5` (rounding errors at the end points) are ignored.
+///
+/// This uses [`output_range`], with the same cost, unless a coarse sampling already overshoots.
+pub fn is_overshooting(easing: impl Curve) -> bool {
+    const TOLERANCE: f32 = 1e-5;
+    const NUM_SAMPLES: usize = 64;
+
+    let overshoots = |y: f32| y < -TOLERANCE || 1.0 + TOLERANCE < y;
+    if (0..=NUM_SAMPLES).any(|i| overshoots(easing.apply(i as f32 / NUM_SAMPLES as f32))) {
+        return true;
+    }
+    let range = output_range(easing);
+    overshoots(*range.start()) || overshoots(*range.end())
+}
+
+/// Numerically find the smallest `t` in `[0, 1]` for which `easing(t) ≈ y`.
+///
+/// This is useful for scrubbing an animation backwards,
+/// even for easings that have no closed-form inverse, like [`back_out`] or [`bounce_out`].
+///
+/// The curve is first sampled to find the first interval where it reaches `y`,
+/// which is then refined by bisection until `easing(t)` is within `tolerance` of `y`.
+/// When there are several pre-images (e.g. for [`bounce_in`]), the smallest one is returned.
+/// If `y` is never reached, the end of the curve closest to `y` is returned.
+///
+/// This always terminates, also for curves with flat regions.
+///
+/// ```
+/// # use emath::easing::{cubic_out, inverse_numeric};
+/// let t = inverse_numeric(cubic_out, 0.5, 1e-6);
+/// assert!((cubic_out(t) - 0.5).abs() <= 1e-6);
+/// ```
+pub fn inverse_numeric(easing: impl Curve, y: f32, tolerance: f32) -> f32 {
+    const NUM_SAMPLES: usize = 64;
+    const MAX_BISECTIONS: usize = 64;
+
+    let mut lo = 0.0;
+    let mut d_lo = easing.apply(lo) - y;
+
+    for i in 1..=NUM_SAMPLES {
+        if abs(d_lo) <= tolerance {
+            return lo;
+        }
+
+        let hi = i as f32 / NUM_SAMPLES as f32;
+        let d_hi = easing.apply(hi) - y;
+
+        if signum(d_lo) != signum(d_hi) {
+            // The curve crosses `y` in `[lo, hi]`:
+            let (mut lo, mut hi, mut d_hi) = (lo, hi, d_hi);
+            for _ in 0..MAX_BISECTIONS {
+                if abs(d_hi) <= tolerance {
+                    break;
+                }
+                let mid = 0.5 * (lo + hi);
+                if mid <= lo || hi <= mid {
+                    break; // out of precision
+                }
+                let d_mid = easing.apply(mid) - y;
+                if signum(d_mid) == signum(d_lo) && tolerance < abs(d_mid) {
+                    lo = mid;
+                } else {
+                    hi = mid;
+                    d_hi = d_mid;
+                }
+            }
+            return hi;
+        }
+
+        lo = hi;
+        d_lo = d_hi;
+    }
+
+    if abs(d_lo) <= tolerance {
+        return 1.0;
+    }
+
+    if abs(easing.apply(0.0) - y) <= abs(easing.apply(1.0) - y) {
+        0.0
+    } else {
+        1.0
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    const ALL: [(&str, fn(f32) -> f32); 31] = [
+        ("linear", linear),
+        ("smoothstep", smoothstep),
+        ("smootherstep", smootherstep),
+        ("quadratic_in", quadratic_in),
+        ("quadratic_out", quadratic_out),
+        ("quadratic_in_out", quadratic_in_out),
+        ("cubic_in", cubic_in),
+        ("cubic_out", cubic_out),
+        ("cubic_in_out", cubic_in_out),
+        ("sin_in", sin_in),
+        ("sin_out", sin_out),
+        ("sin_in_out", sin_in_out),
+        ("circular_in", circular_in),
+        ("circular_out", circular_out),
+        ("circular_in_out", circular_in_out),
+        ("exponential_in", exponential_in),
+        ("exponential_out", exponential_out),
+        ("exponential_in_out", exponential_in_out),
+        ("back_in", back_in),
+        ("back_out", back_out),
+        ("back_in_out", back_in_out),
+        ("bounce_in", bounce_in),
+        ("bounce_out", bounce_out),
+        ("bounce_in_out", bounce_in_out),
+        ("quadratic_out_in", quadratic_out_in),
+        ("cubic_out_in", cubic_out_in),
+        ("sin_out_in", sin_out_in),
+        ("circular_out_in", circular_out_in),
+        ("exponential_out_in", exponential_out_in),
+        ("back_out_in", back_out_in),
+        ("bounce_out_in", bounce_out_in),
+    ];
+
+    #[test]
+    fn test_out_in() {
+        for (name, easing) in ALL {
+            if name.ends_with("_out_in") {
+                for t in [0.0, 0.5, 1.0] {
+                    assert!((easing(t) - t).abs() < 1e-6, "{name}({t}) = {}", easing(t));
+                }
+            }
+        }
+
+        // Fast at the ends, slow in the middle:
+        assert_eq!(quadratic_out_in(0.25), 0.375);
+        assert_eq!(quadratic_out_in(0.75), 0.625);
+        assert_eq!(cubic_out_in(0.25), 0.4375);
+        assert_eq!(cubic_out_in(0.75), 0.5625);
+        assert!(derivative(sin_out_in, 0.5) < derivative(sin_out_in, 0.1));
+    }
+
+    #[test]
+    fn test_poly() {
+        type Poly = fn(f32, f32) -> f32;
+        let close = |a: f32, b: f32| (a - b).abs() < 1e-6;
+        for i in 0..=100 {
+            let t = i as f32 / 100.0;
+            assert!(close(poly_in(t, 2.0), quadratic_in(t)), "{t}");
+            assert!(close(poly_out(t, 2.0), quadratic_out(t)), "{t}");
+            assert!(close(poly_in_out(t, 2.0), quadratic_in_out(t)), "{t}");
+            assert!(close(poly_in(t, 3.0), cubic_in(t)), "{t}");
+            assert!(close(poly_out(t, 3.0), cubic_out(t)), "{t}");
+            assert!(close(poly_in_out(t, 3.0), cubic_in_out(t)), "{t}");
+            assert!(close(poly_in(t, 4.0), t * t * t * t), "{t}");
+            assert!(close(poly_in(t, 5.0), t * t * t * t * t), "{t}");
+
+            for n in [0.5, 1.5, 2.0, 3.0, 4.0, 5.0, 8.0] {
+                let pairs: [(Poly, Poly); 3] = [
+                    (poly_in, poly_in_inverse),
+                    (poly_out, poly_out_inverse),
+                    (poly_in_out, poly_in_out_inverse),
+                ];
+                for (f, inverse) in pairs {
+                    let y = f(t, n);
+                    let t_back = inverse(y, n);
+                    assert!(
+                        (t_back - t).abs() < 1e-3 || f(t_back, n) == y,
+                        "n={n}: {t} -> {t_back}"
+                    );
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_easing_table() {
+        for (name, easing) in ALL {
+            for resolution in [2, 17, 256] {
+                let table = EasingTable::new(easing, resolution);
+                assert_eq!(table.resolution(), resolution);
+                assert_eq!(table.apply(0.0), easing(0.0), "{name}");
+                assert_eq!(table.apply(1.0), easing(1.0), "{name}");
+                assert_eq!(table.apply(2.0), easing(1.0), "{name}");
+
+                for i in 0..=1000 {
+                    let t = i as f32 / 1000.0;
+                    let error = (table.apply(t) - easing(t)).abs();
+                    assert!(
+                        error <= 1.1 * table.max_error() + 1e-6,
+                        "{name} @ {resolution}: error {error} at {t}, max_error {}",
+                        table.max_error()
+                    );
+                }
+            }
+        }
+
+        assert!(EasingTable::new(sin_in_out, 256).max_error() < 1e-5);
+    }
+
+    #[test]
+    fn test_output_range() {
+        for (name, easing) in ALL {
+            let overshooting = ["back_in", "back_out", "back_in_out"].contains(&name);
+            assert_eq!(is_overshooting(easing), overshooting, "{name}");
+
+            let range = output_range(easing);
+            let scanned = sample(easing, 100_000);
+            for y in scanned {
+                assert!(
+                    *range.start() - 1e-6 <= y && y <= *range.end() + 1e-6,
+                    "{name}: {y} outside {range:?}"
+                );
+            }
+        }
+
+        // `back_in` has its minimum at t ≈ 0.5296, where `3t² = sin(πt) + πt cos(πt)`:
+        let range = output_range(back_in);
+        assert!((*range.start() - (-0.378_772)).abs() < 1e-5, "{range:?}");
+        assert!((*range.end() - 1.0).abs() < 1e-5, "{range:?}");
+
+        let range = output_range(back_out);
+        assert!((*range.end() - 1.378_772).abs() < 1e-5, "{range:?}");
+
+        // A table only reaches its samples:
+        let table = EasingTable::new(back_out, 5);
+        let table_range = table.output_range();
+        assert!(table_range.start().abs() < 1e-6);
+        assert!(1.3 < *table_range.end() && *table_range.end() <= *range.end());
+        assert!(is_overshooting(table));
+    }
+
+    #[test]
+    fn test_continuity_at_seams() {
+        // Small, because `circular_in_out` has a vertical tangent at its seam:
+        const EPS: f32 = 1e-7;
+        let bounce_seams = [1. / 2.75, 2. / 2.75, 2.5 / 2.75];
+        for (name, easing) in ALL {
+            let mut seams = vec![];
+            if name.ends_with("_in_out") || name.ends_with("_out_in") {
+                seams.push(0.5);
+            }
+            if name == "bounce_out" {
+                seams.extend(bounce_seams);
+            }
+            if name == "bounce_in" {
+                seams.extend(bounce_seams.map(|s| 1.0 - s));
+            }
+            for seam in seams {
+                let jump = (easing(seam - EPS) - easing(seam + EPS)).abs();
+                assert!(jump < 1e-3, "{name} jumps by {jump} at {seam}");
+            }
+        }
+    }
+
+    #[test]
+    fn test_bounce_reference_values() {
+        // From the formulas at <https://easings.net/#easeOutBounce>:
+        let expected = [
+            0.0, 0.075_625, 0.3025, 0.680_625, 0.91, 0.765_625, 0.7725, 0.930_625, 0.94, 0.988_125,
+            1.0,
+        ];
+        for (i, y) in expected.into_iter().enumerate() {
+            let t = i as f32 / 10.0;
+            assert!((bounce_out(t) - y).abs() < 1e-5, "bounce_out({t})");
+            assert!(
+                (bounce_in(1.0 - t) - (1.0 - y)).abs() < 1e-5,
+                "bounce_in({t})"
+            );
+        }
+    }
+
+    #[test]
+    fn test_curve() {
+        fn area(curve: &impl Curve) -> f32 {
+            (0..100).map(|i| curve.apply(i as f32 / 100.0)).sum::<f32>() / 100.0
+        }
+
+        let exact = area(&cubic_in);
+        assert_eq!(area(&|t: f32| t * t * t), exact);
+        assert!((area(&EasingTable::new(cubic_in, 64)) - exact).abs() < 1e-3);
+        assert_eq!(area(&BoxedCurve::new(cubic_in)), exact);
+
+        #[derive(Clone, Debug)]
+        struct Settings {
+            curve: BoxedCurve,
+        }
+        let n = 3;
+        let settings = Settings {
+            curve: BoxedCurve::new(move |t: f32| t.powi(n)),
+        };
+        let copy = settings.clone();
+        assert_eq!(area(&copy.curve), exact);
+        assert_eq!(derivative(settings.curve, 1.0), derivative(cubic_in, 1.0));
+    }
+
+    #[test]
+    fn test_smoothstep() {
+        for (t, y) in [
+            (0.0, 0.0),
+            (0.25, 0.15625),
+            (0.5, 0.5),
+            (0.75, 0.84375),
+            (1.0, 1.0),
+        ] {
+            assert!((smoothstep(t) - y).abs() < 1e-6, "smoothstep({t})");
+            assert!(
+                (smoothstep_inverse(y) - t).abs() < 1e-5,
+                "smoothstep_inverse({y})"
+            );
+        }
+        for (t, y) in [
+            (0.0, 0.0),
+            (0.25, 0.103_515_63),
+            (0.5, 0.5),
+            (0.75, 0.896_484_4),
+            (1.0, 1.0),
+        ] {
+            assert!((smootherstep(t) - y).abs() < 1e-6, "smootherstep({t})");
+        }
+    }
+
+    #[test]
+    fn test_sample() {
+        for (name, easing) in ALL {
+            let samples = sample(easing, 101);
+            assert_eq!(samples.len(), 101);
+            assert_eq!(samples[0], easing(0.0), "{name}");
+            assert_eq!(samples[100], easing(1.0), "{name}");
+
+            let input: Vec<f32> = (0..101).map(|i| i as f32 / 100.0).collect();
+            let mut output = vec![0.0; input.len()];
+            apply_slice(easing, &input, &mut output);
+            assert_eq!(output[50], easing(0.5), "{name}");
+        }
+        assert!(sample(linear, 0).is_empty());
+        assert_eq!(sample(linear, 1), vec![0.0]);
+    }
+
+    #[test]
+    fn test_derivative() {
+        type Curve = fn(f32) -> f32;
+        let analytic: [(&str, Curve, Curve); 5] = [
+            ("linear", linear, |_| 1.0),
+            ("quadratic_in", quadratic_in, |t| 2.0 * t),
+            ("cubic_out", cubic_out, |t| 3.0 * (1.0 - t) * (1.0 - t)),
+            ("smoothstep", smoothstep, |t| 6.0 * t * (1.0 - t)),
+            ("circular_in", circular_in, |t| t / (1.0 - t * t).sqrt()),
+        ];
+        for (name, easing, slope) in analytic {
+            for i in 0..20 {
+                let t = i as f32 / 20.0;
+                let d = derivative(easing, t);
+                assert!((d - slope(t)).abs() < 1e-4, "{name}'({t}) = {d}");
+            }
+        }
+
+        for (name, easing) in ALL {
+            assert!(derivative(easing, 0.0).is_finite(), "{name}");
+            assert!(derivative(easing, 0.5).is_finite(), "{name}");
+        }
+    }
+
+    #[test]
+    fn test_inverse_numeric() {
+        let tolerance = 1e-4;
+        for (name, easing) in ALL {
+            for i in 0..100 {
+                let y = i as f32 / 99.0;
+                let t = inverse_numeric(easing, y, tolerance);
+                assert!((0.0..=1.0).contains(&t), "{name}: t = {t}");
+                assert!(
+                    (easing(t) - y).abs() <= tolerance,
+                    "{name}: f({t}) = {}, expected {y}",
+                    easing(t)
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_inverse_round_trip() {
+        // For the strictly increasing curves, the inverse must recover `t`,
+        // not just some `t` with the right `y`:
+        for (name, easing) in ALL {
+            if name.starts_with("back") || name.starts_with("bounce") {
+                continue; // not monotonic
+            }
+            for i in 0..1000 {
+                let t = i as f32 / 999.0;
+                let y = easing(t);
+                let t_back = inverse_numeric(easing, y, 0.0);
+                // Near flat ends, neighboring `t` can round to the same `f32` output:
+                assert!(
+                    (t_back - t).abs() < 1e-3 || easing(t_back) == y,
+                    "{name}: {t} -> {t_back}"
+                );
+            }
+        }
+
+        for i in 0..1000 {
+            let t = i as f32 / 999.0;
+            let t_back = smoothstep_inverse(smoothstep(t));
+            assert!((t_back - t).abs() < 1e-3, "smoothstep: {t} -> {t_back}");
+        }
+    }
+
+    #[test]
+    fn test_inverse_numeric_smallest_pre_image() {
+        // `back_in` dips below zero before coming back up through it:
+        assert_eq!(inverse_numeric(back_in, 0.0, 1e-6), 0.0);
+
+        // The first bounce of `bounce_in` reaches 0.01 well before the last one does:
+        let t = inverse_numeric(bounce_in, 0.01, 1e-6);
+        assert!(t < 0.1, "t = {t}");
+    }
+
+    #[test]
+    fn test_inverse_numeric_terminates() {
+        // Zero tolerance can never be reached exactly, but we still terminate:
+        for (_, easing) in ALL {
+            let t = inverse_numeric(easing, 0.01, 0.0);
+            assert!((0.0..=1.0).contains(&t));
+        }
+        assert_eq!(inverse_numeric(bounce_in, 0.0, 0.0), 0.0);
+
+        // Out of range:
+        assert_eq!(inverse_numeric(cubic_in, -1.0, 1e-6), 0.0);
+        assert_eq!(inverse_numeric(cubic_in, 2.0, 1e-6), 1.0);
+    }
+
+    #[test]
+    fn test_out_of_range() {
+        let eps = 1e-3;
+        for (name, easing) in ALL {
+            assert_eq!(apply_clamped(easing, -eps), easing(0.0), "{name}");
+            assert_eq!(apply_clamped(easing, 1.0 + eps), easing(1.0), "{name}");
+
+            let policy = OutOfRange::Mirror;
+            let below = apply_with_policy(easing, -eps, policy);
+            let above = apply_with_policy(easing, 1.0 + eps, policy);
+            assert!((below - easing(eps)).abs() < 1e-4, "{name}");
+            assert!((above - easing(1.0 - eps)).abs() < 1e-4, "{name}");
+        }
+
+        // Extend may give values outside of [0, 1] (or even NaN, for `circular_in`):
+        assert_eq!(
+            apply_with_policy(quadratic_in, -0.5, OutOfRange::Extend),
+            0.25
+        );
+        assert!(1.0 < apply_with_policy(exponential_in, 1.2, OutOfRange::Extend));
+
+        // Ping-pong:
+        assert_eq!(apply_with_policy(linear, 1.25, OutOfRange::Mirror), 0.75);
+        assert_eq!(apply_with_policy(linear, 2.25, OutOfRange::Mirror), 0.25);
+        assert_eq!(apply_with_policy(linear, -0.25, OutOfRange::Mirror), 0.25);
+    }
+}
@@ -0,0 +1,108 @@
+//! The float functions of `std`, implemented with `libm` for `no_std` builds.
+//!
+//! Import [`FloatExt`] where the inherent methods of `f32` and `f64` are used,
+//! so the same method calls compile with and without `std`.
+
+pub(crate) trait FloatExt: Sized {
+    fn abs(self) -> Self;
+    fn signum(self) -> Self;
+    fn floor(self) -> Self;
+    fn ceil(self) -> Self;
+    fn round(self) -> Self;
+    fn sqrt(self) -> Self;
+    fn hypot(self, other: Self) -> Self;
+    fn powi(self, n: i32) -> Self;
+    fn powf(self, n: Self) -> Self;
+    fn log10(self) -> Self;
+    fn sin(self) -> Self;
+    fn cos(self) -> Self;
+    fn atan2(self, other: Self) -> Self;
+
+    fn sin_cos(self) -> (Self, Self);
+}
+
+macro_rules! impl_float_ext {
+    ($t: ty, $abs: ident, $copysign: ident, $floor: ident, $ceil: ident, $round: ident,
+     $sqrt: ident, $hypot: ident, $pow: ident, $log10: ident, $sin: ident, $cos: ident, $atan2: ident) => {
+        impl FloatExt for $t {
+            #[inline]
+            fn abs(self) -> Self {
+                libm::$abs(self)
+            }
+
+            #[inline]
+            fn signum(self) -> Self {
+                if self.is_nan() {
+                    Self::NAN
+                } else {
+                    libm::$copysign(1.0, self)
+                }
+            }
+
+            #[inline]
+            fn floor(self) -> Self {
+                libm::$floor(self)
+            }
+
+            #[inline]
+            fn ceil(self) -> Self {
+                libm::$ceil(self)
+            }
+
+            #[inline]
+            fn round(self) -> Self {
+                libm::$round(self)
+            }
+
+            #[inline]
+            fn sqrt(self) -> Self {
+                libm::$sqrt(self)
+            }
+
+            #[inline]
+            fn hypot(self, other: Self) -> Self {
+                libm::$hypot(self, other)
+            }
+
+            #[inline]
+            fn powi(self, n: i32) -> Self {
+                libm::$pow(self, n as Self)
+            }
+
+            #[inline]
+            fn powf(self, n: Self) -> Self {
+                libm::$pow(self, n)
+            }
+
+            #[inline]
+            fn log10(self) -> Self {
+                libm::$log10(self)
+            }
+
+            #[inline]
+            fn sin(self) -> Self {
+                libm::$sin(self)
+            }
+
+            #[inline]
+            fn cos(self) -> Self {
+                libm::$cos(self)
+            }
+
+            #[inline]
+            fn atan2(self, other: Self) -> Self {
+                libm::$atan2(self, other)
+            }
+
+            #[inline]
+            fn sin_cos(self) -> (Self, Self) {
+                (libm::$sin(self), libm::$cos(self))
+            }
+        }
+    };
+}
+
+impl_float_ext!(
+    f32, fabsf, copysignf, floorf, ceilf, roundf, sqrtf, hypotf, powf, log10f, sinf, cosf, atan2f
+);
+impl_float_ext!(f64, fabs, copysign, floor, ceil, round, sqrt, hypot, pow, log10, sin, cos, atan2);
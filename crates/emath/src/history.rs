@@ -1,4 +1,4 @@
-use std::collections::VecDeque;
+use alloc::collections::VecDeque;
 
 /// This struct tracks recent values of some time series.
 ///
@@ -52,7 +52,7 @@ where
     /// history.add(now(), 44.0_f32);
     /// assert_eq!(history.average(), Some(42.0));
     /// ```
-    pub fn new(length_range: std::ops::Range<usize>, max_age: f32) -> Self {
+    pub fn new(length_range: core::ops::Range<usize>, max_age: f32) -> Self {
         Self {
             min_len: length_range.start,
             max_len: length_range.end,
@@ -174,8 +174,8 @@ where
 impl<T> History<T>
 where
     T: Copy,
-    T: std::iter::Sum,
-    T: std::ops::Div<f32, Output = T>,
+    T: core::iter::Sum,
+    T: core::ops::Div<f32, Output = T>,
 {
     #[inline]
     pub fn sum(&self) -> T {
@@ -195,9 +195,9 @@ where
 impl<T> History<T>
 where
     T: Copy,
-    T: std::iter::Sum,
-    T: std::ops::Div<f32, Output = T>,
-    T: std::ops::Mul<f32, Output = T>,
+    T: core::iter::Sum,
+    T: core::ops::Div<f32, Output = T>,
+    T: core::ops::Mul<f32, Output = T>,
 {
     /// Average times rate.
     /// If you are keeping track of individual sizes of things (e.g. bytes),
@@ -210,8 +210,8 @@ where
 impl<T, Vel> History<T>
 where
     T: Copy,
-    T: std::ops::Sub<Output = Vel>,
-    Vel: std::ops::Div<f32, Output = Vel>,
+    T: core::ops::Sub<Output = Vel>,
+    Vel: core::ops::Div<f32, Output = Vel>,
 {
     /// Calculate a smooth velocity (per second) over the entire time span.
     /// Calculated as the last value minus the first value over the elapsed time between them.
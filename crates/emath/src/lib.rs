@@ -15,18 +15,33 @@
 //! For that, use something else ([`glam`](https://docs.rs/glam), [`nalgebra`](https://docs.rs/nalgebra), …)
 //! and enable the `mint` feature flag in `emath` to enable implicit conversion to/from `emath`.
 //!
+//! ## `no_std`
+//! With the default `std` feature disabled, `emath` is `#![no_std]` (but needs `alloc`).
+//! Then enable the `libm` feature, so the float functions come from [`libm`](https://docs.rs/libm) instead.
+//!
 //! ## Feature flags
 #![cfg_attr(feature = "document-features", doc = document_features::document_features!())]
 //!
 
+#![cfg_attr(not(any(feature = "std", test)), no_std)]
 #![allow(clippy::float_cmp)]
 
-use std::ops::{Add, Div, Mul, RangeInclusive, Sub};
+extern crate alloc;
+
+#[cfg(not(any(feature = "std", feature = "libm", test)))]
+compile_error!("emath needs either the `std` or the `libm` feature");
+
+#[cfg(not(any(feature = "std", test)))]
+use crate::float::FloatExt as _;
+use alloc::{format, string::String};
+use core::ops::{Add, Div, Mul, RangeInclusive, Sub};
 
 // ----------------------------------------------------------------------------
 
 pub mod align;
 pub mod easing;
+#[cfg(not(any(feature = "std", test)))]
+mod float;
 mod history;
 mod numeric;
 mod ordered_float;
@@ -36,7 +51,10 @@ mod rect;
 mod rect_transform;
 mod rot2;
 pub mod smart_aim;
+mod smooth_damp;
+mod timeline;
 mod ts_transform;
+mod tween;
 mod vec2;
 mod vec2b;
 
@@ -50,7 +68,10 @@ pub use self::{
     rect::*,
     rect_transform::*,
     rot2::*,
+    smooth_damp::{smooth_damp, smooth_damp_vec2},
+    timeline::{Keyframe, KeyframeTimeError, Timeline, TimelineBuilder},
     ts_transform::*,
+    tween::{ease_lerp, Lerp, Tween},
     vec2::*,
     vec2b::*,
 };
@@ -180,6 +201,59 @@ where
     }
 }
 
+/// Like [`remap`], but with `easing` applied in between.
+///
+/// `x` is first normalized so that `from.start()` is `0` and `from.end()` is `1`,
+/// then eased, then mapped to the `to` range.
+/// Either range may be reversed.
+///
+/// ```
+/// # use emath::{easing, remap_with_easing};
+/// assert_eq!(remap_with_easing(15.0, 10.0..=20.0, 0.0..=100.0, easing::quadratic_in), 25.0);
+/// assert_eq!(remap_with_easing(15.0, 20.0..=10.0, 0.0..=100.0, easing::quadratic_in), 25.0);
+/// ```
+pub fn remap_with_easing<T>(
+    x: T,
+    from: impl Into<RangeInclusive<T>>,
+    to: impl Into<RangeInclusive<T>>,
+    easing: impl easing::Curve,
+) -> T
+where
+    T: Real + Lerp + Into<f64>,
+{
+    let from = from.into();
+    debug_assert!(from.start() != from.end());
+    let t = (x - *from.start()) / (*from.end() - *from.start());
+    ease_lerp(to, t.into() as f32, easing)
+}
+
+/// Like [`remap_with_easing`], but `x` is clamped to the `from` range before easing.
+///
+/// The result is within the `to` range unless the easing itself overshoots (e.g. [`easing::back_out`]).
+pub fn remap_clamp_with_easing<T>(
+    x: T,
+    from: impl Into<RangeInclusive<T>>,
+    to: impl Into<RangeInclusive<T>>,
+    easing: impl easing::Curve,
+) -> T
+where
+    T: Real + Lerp + Into<f64>,
+{
+    let from = from.into();
+    let to = to.into();
+    if from.start() == from.end() {
+        return *to.end();
+    }
+    let t = ((x - *from.start()) / (*from.end() - *from.start())).into() as f32;
+    if t <= 0.0 {
+        *to.start()
+    } else if 1.0 <= t {
+        *to.end()
+    } else {
+        ease_lerp(to, t, easing)
+    }
+}
+
 /// Round a value to the given number of decimal places.
 pub fn round_to_decimals(value: f64, decimal_places: usize) -> f64 {
     // This is a stupid way of doing this, but stupid works.
@@ -240,7 +314,7 @@ fn test_format() {
     assert_eq!(format_with_minimum_decimals(3.14, 2), "3.14");
     assert_eq!(format_with_minimum_decimals(3.14, 3), "3.140");
     assert_eq!(
-        format_with_minimum_decimals(std::f64::consts::PI, 2),
+        format_with_minimum_decimals(core::f64::consts::PI, 2),
         "3.14159"
     );
 }
@@ -286,6 +360,71 @@ fn test_remap() {
     assert_eq!(remap_clamp(0.5, 1.0..=0.0, 16.0..=0.0), 8.0);
 }
 
+#[test]
+fn test_remap_with_easing() {
+    use easing::{exponential_in, linear};
+
+    assert_eq!(remap_with_easing(0.5, 0.0..=1.0, 0.0..=16.0, linear), 8.0);
+    assert_eq!(
+        remap_clamp_with_easing(1.0, 0.0..=1.0, 0.0..=16.0, linear),
+        16.0
+    );
+    assert_eq!(
+        remap_clamp_with_easing(1.0, 1.0..=0.0, 16.0..=0.0, linear),
+        16.0
+    );
+    assert_eq!(
+        remap_clamp_with_easing(0.5, 1.0..=0.0, 16.0..=0.0, linear),
+        8.0
+    );
+
+    // Extrapolates unless clamped:
+    assert_eq!(remap_with_easing(2.0, 0.0..=1.0, 0.0..=16.0, linear), 32.0);
+    assert_eq!(
+        remap_clamp_with_easing(2.0, 0.0..=1.0, 0.0..=16.0, linear),
+        16.0
+    );
+    assert_eq!(
+        remap_clamp_with_easing(-1.0, 0.0..=1.0, 0.0..=16.0, linear),
+        0.0
+    );
+
+    let expected = exponential_in(0.75) as f64 * 16.0;
+    assert_eq!(
+        remap_with_easing(3.0_f64, 0.0..=4.0, 0.0..=16.0, exponential_in),
+        expected
+    );
+    assert_eq!(
+        remap_clamp_with_easing(3.0_f64, 0.0..=4.0, 0.0..=16.0, exponential_in),
+        expected
+    );
+
+    // Reversed input range: easing still starts at `from.start()`.
+    assert_eq!(
+        remap_with_easing(1.0, 4.0..=0.0, 0.0..=16.0, exponential_in),
+        expected
+    );
+    assert_eq!(
+        remap_clamp_with_easing(1.0, 4.0..=0.0, 0.0..=16.0, exponential_in),
+        expected
+    );
+
+    // Reversed output range:
+    let expected = 16.0 - exponential_in(0.75) * 16.0;
+    assert_eq!(
+        remap_with_easing(3.0, 0.0..=4.0, 16.0..=0.0, exponential_in),
+        expected
+    );
+    assert_eq!(
+        remap_clamp_with_easing(3.0, 0.0..=4.0, 16.0..=0.0, exponential_in),
+        expected
+    );
+    assert_eq!(
+        remap_clamp_with_easing(9.0, 0.0..=4.0, 16.0..=0.0, exponential_in),
+        0.0
+    );
+}
+
 // ----------------------------------------------------------------------------
 
 /// Extends `f32`, [`Vec2`] etc with `at_least` and `at_most` as aliases for `max` and `min`.
@@ -336,7 +475,7 @@ impl_num_ext!(Pos2);
 
 /// Wrap angle to `[-PI, PI]` range.
 pub fn normalized_angle(mut angle: f32) -> f32 {
-    use std::f32::consts::{PI, TAU};
+    use core::f32::consts::{PI, TAU};
     angle %= TAU;
     if angle > PI {
         angle -= TAU;
@@ -346,6 +485,55 @@ pub fn normalized_angle(mut angle: f32) -> f32 {
     angle
 }
 
+/// Interpolate between two angles along the shortest arc, so that `0.0 => a, 1.0 => b`.
+///
+/// Angles are in radians and may be outside `[-PI, PI]`, including several turns.
+/// When `a` and `b` are exactly half a turn apart, this goes in the positive direction.
+///
+/// The result is continuous with `a` rather than wrapped, so `t = 0` returns `a` exactly,
+/// and `t = 1` returns an angle equivalent to `b`.
+///
+/// ```
+/// # use emath::angle_lerp;
+/// use std::f32::consts::PI;
+/// let angle = angle_lerp(0.9 * PI, -0.9 * PI, 0.5); // crosses PI instead of 0
+/// assert!((angle - PI).abs() < 1e-5);
+/// ```
+pub fn angle_lerp(a: f32, b: f32, t: f32) -> f32 {
+    use core::f32::consts::{PI, TAU};
+    let mut delta = normalized_angle(b - a);
+    if delta <= -PI {
+        delta += TAU;
+    }
+    a + t * delta
+}
+
+#[test]
+fn test_angle_lerp() {
+    use core::f32::consts::{PI, TAU};
+    let same = |x: f32, y: f32| normalized_angle(x - y).abs() < 1e-5;
+
+    // Wrap-around at ±PI:
+    assert!(same(angle_lerp(0.9 * PI, -0.9 * PI, 0.5), PI));
+    assert!(same(angle_lerp(-0.9 * PI, 0.9 * PI, 0.25), -0.95 * PI));
+    assert!(same(angle_lerp(0.1, -0.1, 0.5), 0.0));
+
+    // Exact ends:
+    assert_eq!(angle_lerp(0.9 * PI, -0.9 * PI, 0.0), 0.9 * PI);
+    assert!(same(angle_lerp(0.9 * PI, -0.9 * PI, 1.0), -0.9 * PI));
+
+    // Half a turn apart always goes the positive way:
+    assert!(same(angle_lerp(0.0, PI, 0.5), 0.5 * PI));
+    assert!(same(angle_lerp(0.0, -PI, 0.5), 0.5 * PI));
+    assert!(same(angle_lerp(PI, 0.0, 0.5), 1.5 * PI));
+
+    // Several turns:
+    assert!(same(
+        angle_lerp(3.0 * TAU + 0.1, -5.0 * TAU - 0.1, 0.5),
+        0.0
+    ));
+}
+
 #[test]
 fn test_normalized_angle() {
     macro_rules! almost_eq {
@@ -356,7 +544,7 @@ fn test_normalized_angle() {
         };
     }
 
-    use std::f32::consts::TAU;
+    use core::f32::consts::TAU;
     almost_eq!(normalized_angle(-3.0 * TAU), 0.0);
     almost_eq!(normalized_angle(-2.3 * TAU), -0.3 * TAU);
     almost_eq!(normalized_angle(-TAU), 0.0);
@@ -1,5 +1,7 @@
-use std::fmt;
-use std::ops::{Add, AddAssign, Sub, SubAssign};
+#[cfg(not(any(feature = "std", test)))]
+use crate::float::FloatExt as _;
+use core::fmt;
+use core::ops::{Add, AddAssign, Sub, SubAssign};
 
 use crate::*;
 
@@ -197,9 +199,18 @@ impl Pos2 {
             y: lerp(self.y..=other.y, t),
         }
     }
+
+    /// Interpolate towards `other` with `easing` applied to `t`, so that `0.0 => self, 1.0 => other`.
+    ///
+    /// Overshooting easings (e.g. [`crate::easing::back_out`]) extrapolate past `other`.
+    #[allow(clippy::needless_pass_by_value)]
+    #[inline]
+    pub fn ease_lerp(&self, other: Self, t: f32, easing: impl crate::easing::Curve) -> Self {
+        self.lerp(other, easing.apply(t))
+    }
 }
 
-impl std::ops::Index<usize> for Pos2 {
+impl core::ops::Index<usize> for Pos2 {
     type Output = f32;
 
     #[inline(always)]
@@ -212,7 +223,7 @@ impl std::ops::Index<usize> for Pos2 {
     }
 }
 
-impl std::ops::IndexMut<usize> for Pos2 {
+impl core::ops::IndexMut<usize> for Pos2 {
     #[inline(always)]
     fn index_mut(&mut self, index: usize) -> &mut f32 {
         match index {
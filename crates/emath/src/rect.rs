@@ -1,5 +1,7 @@
-use std::f32::INFINITY;
-use std::fmt;
+#[cfg(not(any(feature = "std", test)))]
+use crate::float::FloatExt as _;
+use core::f32::INFINITY;
+use core::fmt;
 
 use crate::*;
 
@@ -450,6 +452,23 @@ impl Rect {
         }
     }
 
+    /// Interpolate `min` and `max` towards `other` with `easing` applied to `t`.
+    ///
+    /// Overshooting easings (e.g. [`crate::easing::back_out`]) are allowed to extrapolate,
+    /// but the result never becomes inverted: if `min` would pass `max` on an axis,
+    /// that axis collapses to zero size at their midpoint.
+    /// This assumes `self` and `other` are not themselves negative.
+    #[allow(clippy::needless_pass_by_value)]
+    #[inline]
+    pub fn ease_lerp(&self, other: &Self, t: f32, easing: impl crate::easing::Curve) -> Self {
+        let Self { min, max } = self.lerp_towards(other, easing.apply(t));
+        let center = min.lerp(max, 0.5);
+        Self {
+            min: pos2(min.x.min(center.x), min.y.min(center.y)),
+            max: pos2(max.x.max(center.x), max.y.max(center.y)),
+        }
+    }
+
     #[inline(always)]
     pub fn x_range(&self) -> Rangef {
         Rangef::new(self.min.x, self.max.x)
@@ -740,6 +759,26 @@ mod tests {
         assert_eq!(r.distance_sq_to_pos(pos2(25.0, 5.0)), 50.0); // right and above
     }
 
+    #[test]
+    fn test_ease_lerp() {
+        use crate::easing;
+
+        let a = Rect::from_min_max(pos2(0.0, 0.0), pos2(40.0, 40.0));
+        let b = Rect::from_min_max(pos2(10.0, 20.0), pos2(60.0, 20.0));
+        assert_eq!(a.ease_lerp(&b, 0.0, easing::quadratic_in), a);
+        assert_eq!(
+            a.ease_lerp(&b, 0.5, easing::quadratic_in),
+            Rect::from_min_max(pos2(2.5, 5.0), pos2(45.0, 35.0))
+        );
+        assert_eq!(a.ease_lerp(&b, 1.0, easing::quadratic_in), b);
+
+        // back_out overshoots past `b`, which would invert the y axis:
+        let r = a.ease_lerp(&b, 0.8, easing::back_out);
+        assert!(!r.is_negative(), "{r:?}");
+        assert_eq!(r.height(), 0.0);
+        assert!(b.max.x < r.max.x && r.min.x < r.max.x, "{r:?}");
+    }
+
     #[test]
     fn test_ray_intersection() {
         let rect = Rect::from_min_max(pos2(1.0, 1.0), pos2(3.0, 3.0));
@@ -1,4 +1,6 @@
 use super::Vec2;
+#[cfg(not(any(feature = "std", test)))]
+use crate::float::FloatExt as _;
 
 // {s,c} represents the rotation matrix:
 //
@@ -87,10 +89,43 @@ impl Rot2 {
         debug_assert!(ret.is_finite());
         ret
     }
+
+    /// Rotate towards `target` along the shortest arc, with `easing` applied to `t`.
+    ///
+    /// The result is always a pure (normalized) rotation, except that `t = 0` returns `self`
+    /// and `t = 1` returns `target` unchanged. See [`crate::angle_lerp`].
+    ///
+    /// Overshooting easings (e.g. [`crate::easing::back_out`]) rotate past the target and back.
+    #[allow(clippy::needless_pass_by_value)]
+    #[must_use]
+    #[inline]
+    pub fn ease_towards(self, target: Self, t: f32, easing: impl crate::easing::Curve) -> Self {
+        let t = easing.apply(t);
+        if t == 0.0 {
+            self
+        } else if t == 1.0 {
+            target
+        } else {
+            Self::from_angle(crate::angle_lerp(self.angle(), target.angle(), t))
+        }
+    }
+
+    /// Interpolate towards `other` along the shortest arc, with `easing` applied to `t`.
+    ///
+    /// The angle and the length (scale) are interpolated separately,
+    /// so the result stays a pure rotation when both ends are.
+    #[allow(clippy::needless_pass_by_value)]
+    #[must_use]
+    #[inline]
+    pub fn ease_slerp(self, other: Self, t: f32, easing: impl crate::easing::Curve) -> Self {
+        let t = easing.apply(t);
+        let delta = (other * self.inverse()).angle();
+        crate::lerp(self.length()..=other.length(), t) * Self::from_angle(self.angle() + t * delta)
+    }
 }
 
-impl std::fmt::Debug for Rot2 {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+impl core::fmt::Debug for Rot2 {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         if let Some(precision) = f.precision() {
             write!(
                 f,
@@ -110,7 +145,7 @@ impl std::fmt::Debug for Rot2 {
     }
 }
 
-impl std::ops::Mul<Self> for Rot2 {
+impl core::ops::Mul<Self> for Rot2 {
     type Output = Self;
 
     #[inline]
@@ -127,7 +162,7 @@ impl std::ops::Mul<Self> for Rot2 {
 }
 
 /// Rotates (and maybe scales) the vector.
-impl std::ops::Mul<Vec2> for Rot2 {
+impl core::ops::Mul<Vec2> for Rot2 {
     type Output = Vec2;
 
     #[inline]
@@ -140,7 +175,7 @@ impl std::ops::Mul<Vec2> for Rot2 {
 }
 
 /// Scales the rotor.
-impl std::ops::Mul<Rot2> for f32 {
+impl core::ops::Mul<Rot2> for f32 {
     type Output = Rot2;
 
     #[inline]
@@ -153,7 +188,7 @@ impl std::ops::Mul<Rot2> for f32 {
 }
 
 /// Scales the rotor.
-impl std::ops::Mul<f32> for Rot2 {
+impl core::ops::Mul<f32> for Rot2 {
     type Output = Self;
 
     #[inline]
@@ -166,7 +201,7 @@ impl std::ops::Mul<f32> for Rot2 {
 }
 
 /// Scales the rotor.
-impl std::ops::Div<f32> for Rot2 {
+impl core::ops::Div<f32> for Rot2 {
     type Output = Self;
 
     #[inline]
@@ -186,7 +221,7 @@ mod test {
     #[test]
     fn test_rotation2() {
         {
-            let angle = std::f32::consts::TAU / 6.0;
+            let angle = core::f32::consts::TAU / 6.0;
             let rot = Rot2::from_angle(angle);
             assert!((rot.angle() - angle).abs() < 1e-5);
             assert!((rot * rot.inverse()).angle().abs() < 1e-5);
@@ -194,14 +229,14 @@ mod test {
         }
 
         {
-            let angle = std::f32::consts::TAU / 4.0;
+            let angle = core::f32::consts::TAU / 4.0;
             let rot = Rot2::from_angle(angle);
             assert!(((rot * vec2(1.0, 0.0)) - vec2(0.0, 1.0)).length() < 1e-5);
         }
 
         {
             // Test rotation and scaling
-            let angle = std::f32::consts::TAU / 4.0;
+            let angle = core::f32::consts::TAU / 4.0;
             let rot = 3.0 * Rot2::from_angle(angle);
             let rotated = rot * vec2(1.0, 0.0);
             let expected = vec2(0.0, 3.0);
@@ -215,4 +250,55 @@ mod test {
             assert!((undone.length() - 1.0).abs() < 1e-5,);
         }
     }
+
+    #[test]
+    fn test_ease_towards() {
+        use crate::easing;
+        use core::f32::consts::PI;
+
+        let a = Rot2::from_angle(-0.9 * PI);
+        let b = Rot2::from_angle(0.9 * PI);
+        assert_eq!(a.ease_towards(b, 0.0, easing::back_out), a);
+        assert_eq!(a.ease_towards(b, 1.0, easing::back_out), b);
+
+        let mid = a.ease_towards(b, 0.5, easing::linear);
+        assert!(
+            (mid.angle().abs() - PI).abs() < 1e-5,
+            "shortest arc: {mid:?}"
+        );
+
+        // back_out goes past the target (further in the negative direction), then returns:
+        let past = |rot: Rot2| (rot * b.inverse()).angle();
+        assert!(past(a.ease_towards(b, 0.7, easing::back_out)) < -1e-3);
+        assert!(
+            past(a.ease_towards(b, 0.7, easing::back_out))
+                < past(a.ease_towards(b, 0.95, easing::back_out))
+        );
+        assert!(past(a.ease_towards(b, 0.05, easing::back_out)) > 0.0);
+    }
+
+    #[test]
+    fn test_ease_slerp() {
+        use crate::easing;
+        use core::f32::consts::PI;
+
+        let close = |a: Rot2, angle: f32| (a * Rot2::from_angle(-angle)).angle().abs() < 1e-5;
+
+        let a = Rot2::from_angle(0.9 * PI);
+        let b = Rot2::from_angle(-0.9 * PI);
+        assert!(close(a.ease_slerp(b, 0.0, easing::linear), 0.9 * PI));
+        assert!(
+            close(a.ease_slerp(b, 0.5, easing::linear), PI),
+            "shortest arc"
+        );
+        assert!(close(a.ease_slerp(b, 1.0, easing::linear), -0.9 * PI));
+        assert!(close(a.ease_slerp(b, 0.5, easing::quadratic_in), 0.95 * PI));
+
+        let overshoot = a.ease_slerp(b, 0.8, easing::back_out);
+        assert!((overshoot.length() - 1.0).abs() < 1e-5);
+        assert!(close(
+            overshoot,
+            -0.9 * PI + 0.2 * PI * (easing::back_out(0.8) - 1.0)
+        ));
+    }
 }
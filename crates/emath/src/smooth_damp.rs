@@ -0,0 +1,162 @@
+use crate::Vec2;
+
+/// Move `current` towards `target` like a critically damped spring, never overshooting.
+///
+/// This is the same approach as Unity's `SmoothDamp`, useful for e.g. a camera or panel following a moving target.
+///
+/// * `velocity`: state that you need to keep between calls, start it at zero.
+/// * `smooth_time`: roughly the time it takes to reach the target, in seconds.
+/// * `max_speed`: the maximum speed, in units per second. Use [`f32::INFINITY`] for no limit.
+/// * `dt`: time since the last call, in seconds.
+///
+/// Unlike the functions in [`crate::easing`], this is stateful and does not need a fixed start time or duration,
+/// so the target can change every frame. It is frame-rate independent, and stays stable for large `dt`.
+///
+/// ```
+/// # use emath::smooth_damp;
+/// let (mut value, mut velocity) = (0.0, 0.0);
+/// for _ in 0..60 {
+///     value = smooth_damp(value, 10.0, &mut velocity, 0.1, f32::INFINITY, 1.0 / 60.0);
+/// }
+/// assert!((value - 10.0).abs() < 0.01);
+/// ```
+pub fn smooth_damp(
+    current: f32,
+    target: f32,
+    velocity: &mut f32,
+    smooth_time: f32,
+    max_speed: f32,
+    dt: f32,
+) -> f32 {
+    if dt <= 0.0 {
+        return current;
+    }
+
+    let smooth_time = smooth_time.max(1e-4);
+    let omega = 2.0 / smooth_time;
+    let decay = damping_decay(omega * dt);
+
+    // Limit how far we are from the target, which limits the speed:
+    let max_change = max_speed * smooth_time;
+    let change = (current - target).clamp(-max_change, max_change);
+    let clamped_target = current - change;
+
+    let temp = (*velocity + omega * change) * dt;
+    *velocity = (*velocity - omega * temp) * decay;
+    let mut output = clamped_target + (change + temp) * decay;
+
+    // Never overshoot:
+    if (target - current > 0.0) == (output > target) {
+        output = target;
+        *velocity = 0.0;
+    }
+
+    output
+}
+
+/// Like [`smooth_damp`], but for [`Vec2`].
+///
+/// `max_speed` limits the length of the velocity.
+pub fn smooth_damp_vec2(
+    current: Vec2,
+    target: Vec2,
+    velocity: &mut Vec2,
+    smooth_time: f32,
+    max_speed: f32,
+    dt: f32,
+) -> Vec2 {
+    if dt <= 0.0 {
+        return current;
+    }
+
+    let smooth_time = smooth_time.max(1e-4);
+    let omega = 2.0 / smooth_time;
+    let decay = damping_decay(omega * dt);
+
+    let max_change = max_speed * smooth_time;
+    let mut change = current - target;
+    if max_change < change.length() {
+        change = change.normalized() * max_change;
+    }
+    let clamped_target = current - change;
+
+    let temp = (*velocity + omega * change) * dt;
+    *velocity = (*velocity - omega * temp) * decay;
+    let mut output = clamped_target + (change + temp) * decay;
+
+    // Never overshoot:
+    if (target - current).dot(output - target) > 0.0 {
+        output = target;
+        *velocity = Vec2::ZERO;
+    }
+
+    output
+}
+
+/// Approximates `exp(-x)`, and stays positive and stable for large `x`.
+#[inline]
+fn damping_decay(x: f32) -> f32 {
+    1.0 / (1.0 + x + 0.48 * x * x + 0.235 * x * x * x)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::vec2;
+
+    #[test]
+    fn test_smooth_damp_converges_without_overshoot() {
+        let smooth_time = 0.3;
+        let dt = 1.0 / 60.0;
+        let (mut value, mut velocity) = (0.0, 0.0);
+        let mut time = 0.0;
+        while time < 4.0 * smooth_time {
+            let new_value = smooth_damp(value, 10.0, &mut velocity, smooth_time, f32::INFINITY, dt);
+            assert!(
+                value <= new_value && new_value <= 10.0,
+                "{value} -> {new_value}"
+            );
+            value = new_value;
+            time += dt;
+        }
+        assert!((value - 10.0).abs() < 0.1, "{value}");
+    }
+
+    #[test]
+    fn test_smooth_damp_max_speed() {
+        let (max_speed, dt) = (5.0, 0.01);
+        let (mut value, mut velocity) = (0.0, 0.0);
+        for _ in 0..100 {
+            let new_value = smooth_damp(value, 100.0, &mut velocity, 0.1, max_speed, dt);
+            let speed = (new_value - value) / dt;
+            assert!(speed <= max_speed * 1.01, "speed {speed}");
+            value = new_value;
+        }
+        assert!(4.0 < value, "still moves: {value}");
+    }
+
+    #[test]
+    fn test_smooth_damp_dt_spikes() {
+        let (mut value, mut velocity) = (0.0, 0.0);
+        for dt in [1.0 / 60.0, 0.5, 1.0 / 60.0, 0.5, 5.0] {
+            value = smooth_damp(value, -3.0, &mut velocity, 0.1, f32::INFINITY, dt);
+            assert!(value.is_finite() && velocity.is_finite());
+            assert!((-3.0..=0.0).contains(&value), "{value}");
+        }
+        assert_eq!(value, -3.0);
+        assert_eq!(smooth_damp(1.0, 2.0, &mut velocity, 0.1, 1.0, 0.0), 1.0);
+    }
+
+    #[test]
+    fn test_smooth_damp_vec2() {
+        let target = vec2(3.0, -4.0);
+        let (mut value, mut velocity) = (Vec2::ZERO, Vec2::ZERO);
+        for _ in 0..240 {
+            let new_value = smooth_damp_vec2(value, target, &mut velocity, 0.5, 2.0, 1.0 / 60.0);
+            assert!((new_value - value).length() * 60.0 <= 2.0 * 1.01);
+            assert!((target - new_value).length() <= (target - value).length());
+            value = new_value;
+        }
+        assert!((value - target).length() < 0.01, "{value:?}");
+    }
+}
@@ -0,0 +1,215 @@
+use alloc::vec::Vec;
+
+use crate::{
+    easing::{self, BoxedCurve, Curve as _},
+    lerp,
+};
+
+/// A value at a point in time, and how to get from there to the next keyframe.
+#[derive(Clone, Debug)]
+pub struct Keyframe {
+    /// In seconds.
+    pub time: f64,
+
+    pub value: f64,
+
+    /// Easing used for the segment from this keyframe to the next one.
+    pub easing_to_next: BoxedCurve,
+}
+
+/// Returned by [`TimelineBuilder::build`] for keyframe times that can't be ordered.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum KeyframeTimeError {
+    /// More than one keyframe at this time.
+    Duplicate(f64),
+
+    /// A keyframe at a NaN or infinite time.
+    NonFinite(f64),
+}
+
+impl core::fmt::Display for KeyframeTimeError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Self::Duplicate(time) => write!(f, "more than one keyframe at time {time}"),
+            Self::NonFinite(time) => write!(f, "keyframe at non-finite time {time}"),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for KeyframeTimeError {}
+
+/// Builds a [`Timeline`]. Created with [`Timeline::builder`].
+#[derive(Clone, Debug, Default)]
+#[must_use = "Call .build() to get the Timeline"]
+pub struct TimelineBuilder {
+    keyframes: Vec<Keyframe>,
+}
+
+impl TimelineBuilder {
+    /// Add a keyframe. Keyframes may be added in any order.
+    ///
+    /// The segment to the next keyframe uses [`easing::linear`] unless you call [`Self::with_easing`].
+    #[inline]
+    pub fn keyframe(mut self, time: f64, value: f64) -> Self {
+        self.keyframes.push(Keyframe {
+            time,
+            value,
+            easing_to_next: BoxedCurve::new(easing::linear),
+        });
+        self
+    }
+
+    /// Set the easing from the most recently added keyframe to the one following it in time.
+    ///
+    /// Does nothing if no keyframe has been added yet.
+    #[inline]
+    pub fn with_easing(mut self, easing: impl easing::Curve + Send + Sync + 'static) -> Self {
+        if let Some(last) = self.keyframes.last_mut() {
+            last.easing_to_next = BoxedCurve::new(easing);
+        }
+        self
+    }
+
+    /// Sort the keyframes by time.
+    ///
+    /// # Errors
+    /// If two keyframes have the same time, or a time is NaN or infinite.
+    pub fn build(self) -> Result<Timeline, KeyframeTimeError> {
+        let Self { mut keyframes } = self;
+        if let Some(keyframe) = keyframes.iter().find(|k| !k.time.is_finite()) {
+            return Err(KeyframeTimeError::NonFinite(keyframe.time));
+        }
+        keyframes.sort_by(|a, b| a.time.total_cmp(&b.time));
+        for pair in keyframes.windows(2) {
+            if pair[0].time == pair[1].time {
+                return Err(KeyframeTimeError::Duplicate(pair[0].time));
+            }
+        }
+        Ok(Timeline { keyframes })
+    }
+}
+
+/// A sequence of keyframes, evaluated with per-segment easing.
+///
+/// Useful for multi-stage animations, e.g. fade in, hold, fade out:
+///
+/// ```
+/// # use emath::{easing, Timeline};
+/// let opacity = Timeline::builder()
+///     .keyframe(0.0, 0.0)
+///     .with_easing(easing::cubic_out)
+///     .keyframe(0.5, 1.0)
+///     .keyframe(2.0, 1.0)
+///     .keyframe(2.5, 0.0)
+///     .build()
+///     .unwrap();
+/// assert_eq!(opacity.value_at(-1.0), 0.0);
+/// assert_eq!(opacity.value_at(1.0), 1.0);
+/// assert_eq!(opacity.value_at(2.25), 0.5);
+/// assert_eq!(opacity.value_at(3.0), 0.0);
+/// ```
+#[derive(Clone, Debug)]
+pub struct Timeline {
+    /// Sorted by finite times, no duplicate times.
+    keyframes: Vec<Keyframe>,
+}
+
+impl Timeline {
+    #[inline]
+    pub fn builder() -> TimelineBuilder {
+        TimelineBuilder::default()
+    }
+
+    /// Sorted by time.
+    #[inline]
+    pub fn keyframes(&self) -> &[Keyframe] {
+        &self.keyframes
+    }
+
+    /// Time of the first and last keyframe, or `None` if there are no keyframes.
+    pub fn time_range(&self) -> Option<(f64, f64)> {
+        Some((self.keyframes.first()?.time, self.keyframes.last()?.time))
+    }
+
+    /// The value at the given time.
+    ///
+    /// Before the first keyframe, or at a NaN `time`, this is the first value.
+    /// After the last keyframe it is the last value.
+    /// Returns `0.0` if there are no keyframes.
+    pub fn value_at(&self, time: f64) -> f64 {
+        let (Some(first), Some(last)) = (self.keyframes.first(), self.keyframes.last()) else {
+            return 0.0;
+        };
+        if time.is_nan() || time <= first.time {
+            return first.value;
+        }
+        if last.time <= time {
+            return last.value;
+        }
+
+        // Index of the first keyframe after `time`. Always in `1..len` here.
+        let next = self.keyframes.partition_point(|k| k.time <= time);
+        let (a, b) = (&self.keyframes[next - 1], &self.keyframes[next]);
+        let t = ((time - a.time) / (b.time - a.time)) as f32;
+        lerp(a.value..=b.value, a.easing_to_next.apply(t) as f64)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_timeline_with_hold() {
+        let timeline = Timeline::builder()
+            .keyframe(1.0, 0.0)
+            .with_easing(easing::quadratic_in)
+            .keyframe(2.0, 10.0)
+            .keyframe(3.0, 10.0) // hold
+            .keyframe(5.0, 0.0)
+            .build()
+            .unwrap();
+
+        assert_eq!(timeline.time_range(), Some((1.0, 5.0)));
+
+        assert_eq!(timeline.value_at(0.0), 0.0);
+        assert_eq!(timeline.value_at(1.0), 0.0);
+        assert_eq!(timeline.value_at(1.5), 2.5, "segment easing is applied");
+        assert_eq!(timeline.value_at(2.0), 10.0);
+        assert_eq!(timeline.value_at(2.5), 10.0);
+        assert_eq!(timeline.value_at(3.0), 10.0);
+        assert_eq!(timeline.value_at(4.0), 5.0, "default easing is linear");
+        assert_eq!(timeline.value_at(5.0), 0.0);
+        assert_eq!(timeline.value_at(99.0), 0.0);
+        assert_eq!(timeline.value_at(f64::NAN), 0.0, "first value");
+        assert_eq!(timeline.value_at(f64::INFINITY), 0.0);
+    }
+
+    #[test]
+    fn test_timeline_unordered_and_duplicates() {
+        let timeline = Timeline::builder()
+            .keyframe(1.0, 1.0)
+            .keyframe(0.0, 0.0)
+            .build()
+            .unwrap();
+        assert_eq!(timeline.value_at(0.5), 0.5);
+
+        let result = Timeline::builder()
+            .keyframe(0.0, 0.0)
+            .keyframe(1.0, 1.0)
+            .keyframe(1.0, 2.0)
+            .build();
+        assert_eq!(result.unwrap_err(), KeyframeTimeError::Duplicate(1.0));
+
+        for time in [f64::NAN, f64::INFINITY] {
+            let result = Timeline::builder()
+                .keyframe(0.0, 0.0)
+                .keyframe(time, 1.0)
+                .build();
+            assert!(matches!(result, Err(KeyframeTimeError::NonFinite(_))));
+        }
+
+        assert_eq!(Timeline::builder().build().unwrap().value_at(1.0), 0.0);
+    }
+}
@@ -0,0 +1,244 @@
+use core::ops::RangeInclusive;
+
+use crate::{
+    easing::{self, BoxedCurve, Curve as _},
+    Pos2, Rect, Vec2,
+};
+
+/// Something that can be linearly interpolated between two values.
+///
+/// Used by [`Tween`].
+pub trait Lerp: Copy {
+    /// Interpolate from `start` (at `t = 0`) to `end` (at `t = 1`).
+    ///
+    /// `t` outside of `[0, 1]` extrapolates.
+    fn lerp(start: Self, end: Self, t: f32) -> Self;
+}
+
+impl Lerp for f32 {
+    #[inline]
+    fn lerp(start: Self, end: Self, t: f32) -> Self {
+        crate::lerp(start..=end, t)
+    }
+}
+
+impl Lerp for f64 {
+    #[inline]
+    fn lerp(start: Self, end: Self, t: f32) -> Self {
+        crate::lerp(start..=end, t as Self)
+    }
+}
+
+impl Lerp for Vec2 {
+    #[inline]
+    fn lerp(start: Self, end: Self, t: f32) -> Self {
+        crate::lerp(start..=end, t)
+    }
+}
+
+impl Lerp for Pos2 {
+    #[inline]
+    fn lerp(start: Self, end: Self, t: f32) -> Self {
+        start.lerp(end, t)
+    }
+}
+
+impl Lerp for Rect {
+    #[inline]
+    fn lerp(start: Self, end: Self, t: f32) -> Self {
+        start.lerp_towards(&end, t)
+    }
+}
+
+/// Interpolate between the ends of `range`, with `easing` applied to `t` first.
+///
+/// `t` is normally in `[0, 1]`. It is passed to `easing` as-is, so clamp it first if needed,
+/// e.g. with [`easing::apply_clamped`].
+///
+/// ```
+/// # use emath::{ease_lerp, easing};
+/// assert_eq!(ease_lerp(10.0..=20.0, 0.5, easing::quadratic_in), 12.5);
+/// assert_eq!(ease_lerp(10.0_f64..=20.0, 1.0, easing::quadratic_in), 20.0);
+/// ```
+#[allow(clippy::needless_pass_by_value)]
+#[inline]
+pub fn ease_lerp<T: Lerp>(
+    range: impl Into<RangeInclusive<T>>,
+    t: f32,
+    easing: impl easing::Curve,
+) -> T {
+    let range = range.into();
+    T::lerp(*range.start(), *range.end(), easing.apply(t))
+}
+
+// ----------------------------------------------------------------------------
+
+/// Interpolate a value from `start` to `end` over `duration` seconds, using an easing function.
+///
+/// All times are in seconds, relative to whatever clock you pass in.
+///
+/// ```
+/// # use emath::{easing, Tween};
+/// let tween = Tween::new(10.0_f32, 20.0, 2.0).with_easing(easing::linear);
+/// assert_eq!(tween.value_at(0.0), 10.0);
+/// assert_eq!(tween.value_at(1.0), 15.0);
+/// assert_eq!(tween.value_at(5.0), 20.0);
+/// assert!(tween.is_finished(2.0));
+/// ```
+#[derive(Clone, Debug)]
+pub struct Tween<T> {
+    start: T,
+    end: T,
+
+    /// When the tween started, in seconds.
+    start_time: f64,
+
+    /// In seconds.
+    duration: f32,
+
+    easing: BoxedCurve,
+}
+
+impl<T: Lerp> Tween<T> {
+    /// A tween starting at time zero, using [`easing::cubic_in_out`].
+    pub fn new(start: T, end: T, duration: f32) -> Self {
+        Self {
+            start,
+            end,
+            start_time: 0.0,
+            duration,
+            easing: BoxedCurve::new(easing::cubic_in_out),
+        }
+    }
+
+    /// At what time (in seconds) the tween starts. Default: `0.0`.
+    #[inline]
+    pub fn starting_at(mut self, start_time: f64) -> Self {
+        self.start_time = start_time;
+        self
+    }
+
+    /// The easing to use, e.g. [`easing::quadratic_out`], or any other [`easing::Curve`].
+    #[inline]
+    pub fn with_easing(mut self, easing: impl easing::Curve + Send + Sync + 'static) -> Self {
+        self.easing = BoxedCurve::new(easing);
+        self
+    }
+
+    #[inline]
+    pub fn start(&self) -> T {
+        self.start
+    }
+
+    #[inline]
+    pub fn end(&self) -> T {
+        self.end
+    }
+
+    #[inline]
+    pub fn start_time(&self) -> f64 {
+        self.start_time
+    }
+
+    /// In seconds.
+    #[inline]
+    pub fn duration(&self) -> f32 {
+        self.duration
+    }
+
+    /// Linear progress in `[0, 1]` at the given time, before easing is applied.
+    pub fn progress(&self, time: f64) -> f32 {
+        if self.duration <= 0.0 {
+            1.0
+        } else {
+            ((time - self.start_time) as f32 / self.duration).clamp(0.0, 1.0)
+        }
+    }
+
+    /// The value at the given time.
+    ///
+    /// Before the start this is [`Self::start`], after the end it is [`Self::end`].
+    pub fn value_at(&self, time: f64) -> T {
+        let t = self.progress(time);
+        if t >= 1.0 {
+            self.end
+        } else {
+            T::lerp(self.start, self.end, self.easing.apply(t))
+        }
+    }
+
+    /// Has the tween reached its end at the given time?
+    pub fn is_finished(&self, time: f64) -> bool {
+        self.progress(time) >= 1.0
+    }
+
+    /// Redirect the tween towards a new end value.
+    ///
+    /// The tween restarts at `now` from its current value, so there is no jump.
+    /// The duration and easing are kept.
+    pub fn retarget(&mut self, new_end: T, now: f64) {
+        self.start = self.value_at(now);
+        self.end = new_end;
+        self.start_time = now;
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{pos2, vec2};
+
+    #[test]
+    fn test_ease_lerp() {
+        assert_eq!(ease_lerp(0.0..=8.0, 0.0, easing::cubic_in), 0.0);
+        assert_eq!(ease_lerp(0.0..=8.0, 0.5, easing::cubic_in), 1.0);
+        assert_eq!(ease_lerp(0.0..=8.0, 1.0, easing::cubic_in), 8.0);
+        assert_eq!(ease_lerp(0.0_f64..=8.0, 0.5, easing::cubic_in), 1.0);
+
+        let overshoot = ease_lerp(0.0..=8.0, 0.8, easing::back_out);
+        assert!(8.0 < overshoot, "back_out overshoots: {overshoot}");
+
+        let (a, b) = (vec2(0.0, 4.0), vec2(8.0, -4.0));
+        assert_eq!(a.ease_lerp(b, 0.0, easing::cubic_in), a);
+        assert_eq!(a.ease_lerp(b, 0.5, easing::cubic_in), vec2(1.0, 3.0));
+        assert_eq!(a.ease_lerp(b, 1.0, easing::cubic_in), b);
+        assert!(8.0 < a.ease_lerp(b, 0.8, easing::back_out).x);
+
+        let (a, b) = (a.to_pos2(), b.to_pos2());
+        assert_eq!(a.ease_lerp(b, 0.0, easing::cubic_in), a);
+        assert_eq!(a.ease_lerp(b, 0.5, easing::cubic_in), pos2(1.0, 3.0));
+        assert_eq!(a.ease_lerp(b, 1.0, easing::cubic_in), b);
+        assert!(a.ease_lerp(b, 0.8, easing::back_out).y < -4.0);
+    }
+
+    #[test]
+    fn test_tween_clamps() {
+        let tween = Tween::new(vec2(0.0, 0.0), vec2(10.0, -10.0), 1.0).starting_at(5.0);
+        assert_eq!(tween.value_at(0.0), vec2(0.0, 0.0));
+        assert_eq!(tween.value_at(5.5), vec2(5.0, -5.0));
+        assert_eq!(tween.value_at(100.0), vec2(10.0, -10.0));
+        assert!(!tween.is_finished(5.5));
+        assert!(tween.is_finished(6.0));
+    }
+
+    #[test]
+    fn test_tween_zero_duration() {
+        let tween = Tween::new(1.0_f64, 2.0, 0.0).starting_at(3.0);
+        assert!(tween.is_finished(0.0));
+        assert_eq!(tween.value_at(0.0), 2.0);
+        assert_eq!(tween.value_at(3.0), 2.0);
+    }
+
+    #[test]
+    fn test_tween_retarget() {
+        let mut tween = Tween::new(0.0_f32, 10.0, 2.0).with_easing(easing::quadratic_in);
+        let before = tween.value_at(1.0);
+        tween.retarget(-4.0, 1.0);
+
+        assert_eq!(tween.start(), before);
+        assert_eq!(tween.value_at(1.0), before, "no jump when retargeting");
+        assert_eq!(tween.start_time(), 1.0);
+        assert!(!tween.is_finished(2.5));
+        assert_eq!(tween.value_at(3.0), -4.0);
+    }
+}
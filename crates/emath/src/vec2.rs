@@ -1,5 +1,7 @@
-use std::fmt;
-use std::ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Sub, SubAssign};
+#[cfg(not(any(feature = "std", test)))]
+use crate::float::FloatExt as _;
+use core::fmt;
+use core::ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Sub, SubAssign};
 
 use crate::Vec2b;
 
@@ -307,9 +309,18 @@ impl Vec2 {
             y: self.y.clamp(min.y, max.y),
         }
     }
+
+    /// Interpolate towards `other` with `easing` applied to `t`, so that `0.0 => self, 1.0 => other`.
+    ///
+    /// Overshooting easings (e.g. [`crate::easing::back_out`]) extrapolate past `other`.
+    #[must_use]
+    #[inline]
+    pub fn ease_lerp(self, other: Self, t: f32, easing: impl crate::easing::Curve) -> Self {
+        crate::ease_lerp(self..=other, t, easing)
+    }
 }
 
-impl std::ops::Index<usize> for Vec2 {
+impl core::ops::Index<usize> for Vec2 {
     type Output = f32;
 
     #[inline(always)]
@@ -322,7 +333,7 @@ impl std::ops::Index<usize> for Vec2 {
     }
 }
 
-impl std::ops::IndexMut<usize> for Vec2 {
+impl core::ops::IndexMut<usize> for Vec2 {
     #[inline(always)]
     fn index_mut(&mut self, index: usize) -> &mut f32 {
         match index {
@@ -496,7 +507,7 @@ fn test_vec2() {
             assert!((left - right).abs() < 1e-6, "{} != {}", left, right);
         };
     }
-    use std::f32::consts::TAU;
+    use core::f32::consts::TAU;
 
     assert_eq!(Vec2::ZERO.angle(), 0.0);
     assert_eq!(Vec2::angled(0.0).angle(), 0.0);
@@ -56,7 +56,7 @@ cargo check --quiet -p egui_extras --no-default-features
 cargo check --quiet -p egui_glow --no-default-features
 cargo check --quiet -p egui-winit --no-default-features --features "wayland"
 cargo check --quiet -p egui-winit --no-default-features --features "x11"
-cargo check --quiet -p emath --no-default-features
+cargo check --quiet -p emath --no-default-features --features libm
 cargo check --quiet -p epaint --no-default-features --release
 cargo check --quiet -p epaint --no-default-features
 